@@ -1,5 +1,4 @@
 use std::env;
-use std::path::Path;
 
 fn main() {
     println!("cargo:rerun-if-changed=src/polymarket_core.cpp");
@@ -10,7 +9,7 @@ fn main() {
     
     // Compiler en objet
     let status = std::process::Command::new("g++")
-        .args(&[
+        .args([
             "-std=c++17",
             "-c",
             "-fPIC",
@@ -26,7 +25,7 @@ fn main() {
     
     // Créer la bibliothèque dynamique
     let status = std::process::Command::new("g++")
-        .args(&[
+        .args([
             "-shared",
             "-o", &format!("{}/libpolymarket_core.dylib", out_dir),
             &format!("{}/polymarket_core.o", out_dir),
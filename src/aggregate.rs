@@ -0,0 +1,113 @@
+// Agrégation des opportunités d'un marché en une décision nette (--aggregate-sources)
+// Chaque source vote pour sa direction, pondérée par sa pertinence pour le marché et la force
+// de son information: au plus un signal par marché et par cycle, au lieu d'un par source
+pub const AGREEMENT_HIGH: f64 = 0.8;   // Part du poids dans le sens retenu pour une confiance haute
+pub const AGREEMENT_MEDIUM: f64 = 0.6; // En dessous: confiance basse
+
+// Vote d'une source pour un marché
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceVote {
+    pub source: String,
+    pub positive: bool, // true = YES
+    pub strength: f64,  // Force de l'information (0-1)
+    pub weight: f64,    // Pertinence de la source pour le marché
+}
+
+// Décision nette d'un marché
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetDecision {
+    pub positive: bool,
+    pub strength: f64,       // Force nette: moyenne pondérée des forces signées, en valeur absolue
+    pub agreement: f64,      // Part du poids total dans le sens retenu (0.5-1)
+    pub agreeing: usize,     // Sources dans le sens retenu
+    pub dissenting: usize,   // Sources dans le sens opposé
+    pub lead_source: String, // Source la plus forte du sens retenu, qui porte le signal
+}
+
+impl NetDecision {
+    // Confiance selon l'accord: une source seule ne suffit pas pour une confiance haute
+    pub fn confidence(&self) -> &'static str {
+        if self.agreement >= AGREEMENT_HIGH && self.agreeing >= 2 {
+            "high"
+        } else if self.agreement >= AGREEMENT_MEDIUM {
+            "medium"
+        } else {
+            "low"
+        }
+    }
+}
+
+// Vote pondéré; None sans vote ou quand les deux sens pèsent exactement autant
+pub fn aggregate(votes: &[SourceVote]) -> Option<NetDecision> {
+    let score = |vote: &SourceVote| vote.weight.max(0.0) * vote.strength.clamp(0.0, 1.0);
+    let yes: f64 = votes.iter().filter(|v| v.positive).map(score).sum();
+    let no: f64 = votes.iter().filter(|v| !v.positive).map(score).sum();
+    let total = yes + no;
+    if total <= 0.0 || yes == no {
+        return None;
+    }
+    let positive = yes > no;
+    let total_weight: f64 = votes.iter().map(|v| v.weight.max(0.0)).sum();
+    let lead_source = votes.iter()
+        .filter(|v| v.positive == positive)
+        .fold(None::<&SourceVote>, |lead, vote| match lead {
+            Some(lead) if score(lead) >= score(vote) => Some(lead),
+            _ => Some(vote),
+        })?
+        .source.clone();
+    Some(NetDecision {
+        positive,
+        strength: ((yes - no).abs() / total_weight).clamp(0.0, 1.0),
+        agreement: yes.max(no) / total,
+        agreeing: votes.iter().filter(|v| v.positive == positive).count(),
+        dissenting: votes.iter().filter(|v| v.positive != positive).count(),
+        lead_source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+     // BBC et NewsAPI affirment YES, Gamma reste neutre
+
+    fn vote(source: &str, positive: bool, strength: f64, weight: f64) -> SourceVote {
+        SourceVote { source: source.to_string(), positive, strength, weight }
+    }
+
+    #[test]
+    fn three_opportunities_collapse_into_the_majority_direction() {
+        // Deux sources YES contre une source NO plus forte prise seule: la majorité pondérée l'emporte
+        let votes = [
+            vote("https://feeds.bbci.co.uk/news/rss.xml", true, 0.5, 0.8),
+            vote("https://newsapi.org/v2/everything", true, 0.6, 0.7),
+            vote("https://www.coindesk.com/rss", false, 0.7, 0.6),
+        ];
+        let decision = aggregate(&votes).expect("décision nette");
+        assert!(decision.positive);
+        assert_eq!((decision.agreeing, decision.dissenting), (2, 1));
+        assert_eq!(decision.lead_source, "https://newsapi.org/v2/everything");
+        // Force nette: (0.40 + 0.42 - 0.42) / 2.1, réduite par le désaccord
+        assert!((decision.strength - 0.40 / 2.1).abs() < 1e-9, "{}", decision.strength);
+        assert!((decision.agreement - 0.82 / 1.24).abs() < 1e-9, "{}", decision.agreement);
+        assert_eq!(decision.confidence(), "medium");
+
+        // Sources unanimes: confiance haute; une source seule plafonne à medium
+        let unanimous = aggregate(&votes[..2]).unwrap();
+        assert_eq!((unanimous.agreement, unanimous.confidence()), (1.0, "high"));
+        assert_eq!(aggregate(&votes[..1]).unwrap().confidence(), "medium");
+
+        // Le poids inverse la majorité en nombre: une source très pertinente l'emporte sur deux faibles
+        let outweighed = aggregate(&[
+            vote("a", true, 0.2, 0.3),
+            vote("b", true, 0.2, 0.3),
+            vote("c", false, 0.9, 0.9),
+        ]).unwrap();
+        assert!(!outweighed.positive);
+        assert_eq!(outweighed.confidence(), "medium");
+
+        // Poids égaux dans les deux sens, ou aucun vote: pas de décision
+        assert!(aggregate(&[vote("a", true, 0.5, 1.0), vote("b", false, 0.5, 1.0)]).is_none());
+        assert!(aggregate(&[]).is_none());
+    }
+}
@@ -0,0 +1,193 @@
+// Métriques de backtest ajustées au risque (Sharpe, Sortino, drawdown)
+// calculées à partir des rendements par trade
+use serde::Serialize;
+
+// Paramètres de calcul des ratios
+#[derive(Debug, Clone, Copy)]
+pub struct BacktestConfig {
+    pub initial_capital: f64,
+    pub risk_free_rate: f64,   // Taux sans risque annuel (ex: 0.04 = 4%)
+    pub periods_per_year: f64, // Nombre de périodes (trades) par an pour l'annualisation
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        Self {
+            initial_capital: 1.0,
+            risk_free_rate: 0.0,
+            periods_per_year: 252.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BacktestStats {
+    pub trades: usize,
+    pub returns: Vec<f64>,
+    pub equity_curve: Vec<f64>, // Capital après chaque trade (commence au capital initial)
+    pub total_return: f64,
+    pub mean_return: f64,
+    pub std_dev: f64,
+    pub sharpe_ratio: f64,
+    pub sortino_ratio: f64,
+    pub max_drawdown: f64,               // Fraction du pic perdue (0.25 = -25%)
+    pub max_drawdown_duration: usize,    // Nombre de périodes passées sous le pic
+    pub wins: usize,
+    pub losses: usize,
+    pub win_rate: f64,
+    pub avg_win: f64,
+    pub avg_loss: f64,
+    pub profit_factor: f64,
+}
+
+impl BacktestStats {
+    pub fn from_returns(returns: &[f64], config: &BacktestConfig) -> Self {
+        let n = returns.len();
+
+        // Courbe de capital composée
+        let mut equity_curve = Vec::with_capacity(n + 1);
+        let mut equity = config.initial_capital;
+        equity_curve.push(equity);
+        for r in returns {
+            equity *= 1.0 + r;
+            equity_curve.push(equity);
+        }
+        let total_return = if config.initial_capital > 0.0 {
+            equity / config.initial_capital - 1.0
+        } else {
+            0.0
+        };
+
+        let mean_return = if n > 0 { returns.iter().sum::<f64>() / n as f64 } else { 0.0 };
+
+        // Écart-type échantillon (n-1)
+        let std_dev = if n > 1 {
+            (returns.iter().map(|r| (r - mean_return).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        let rf_per_period = config.risk_free_rate / config.periods_per_year;
+        let annualization = config.periods_per_year.sqrt();
+        let excess_return = mean_return - rf_per_period;
+
+        let sharpe_ratio = if std_dev > 0.0 { excess_return / std_dev * annualization } else { 0.0 };
+
+        // Sortino: seules les déviations sous le taux sans risque pénalisent
+        let downside_dev = if n > 0 {
+            (returns.iter()
+                .map(|r| (r - rf_per_period).min(0.0).powi(2))
+                .sum::<f64>() / n as f64).sqrt()
+        } else {
+            0.0
+        };
+        let sortino_ratio = if downside_dev > 0.0 { excess_return / downside_dev * annualization } else { 0.0 };
+
+        let (max_drawdown, max_drawdown_duration) = max_drawdown(&equity_curve);
+
+        let wins: Vec<f64> = returns.iter().copied().filter(|r| *r > 0.0).collect();
+        let losses: Vec<f64> = returns.iter().copied().filter(|r| *r < 0.0).collect();
+        let gross_profit: f64 = wins.iter().sum();
+        let gross_loss: f64 = losses.iter().map(|r| r.abs()).sum();
+
+        Self {
+            trades: n,
+            returns: returns.to_vec(),
+            equity_curve,
+            total_return,
+            mean_return,
+            std_dev,
+            sharpe_ratio,
+            sortino_ratio,
+            max_drawdown,
+            max_drawdown_duration,
+            wins: wins.len(),
+            losses: losses.len(),
+            win_rate: if n > 0 { wins.len() as f64 / n as f64 } else { 0.0 },
+            avg_win: if wins.is_empty() { 0.0 } else { gross_profit / wins.len() as f64 },
+            avg_loss: if losses.is_empty() { 0.0 } else { -gross_loss / losses.len() as f64 },
+            profit_factor: if gross_loss > 0.0 { gross_profit / gross_loss } else { 0.0 },
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    pub fn print(&self) {
+        println!("\nSTATISTIQUES DE BACKTEST:");
+        println!("   • Trades: {} (gagnants: {}, perdants: {}, win rate: {:.1}%)",
+            self.trades, self.wins, self.losses, self.win_rate * 100.0);
+        println!("   • Rendement total: {:.2}%", self.total_return * 100.0);
+        println!("   • Gain moyen: {:.2}% | Perte moyenne: {:.2}% | Profit factor: {:.2}",
+            self.avg_win * 100.0, self.avg_loss * 100.0, self.profit_factor);
+        println!("   • Sharpe: {:.2} | Sortino: {:.2}", self.sharpe_ratio, self.sortino_ratio);
+        println!("   • Max drawdown: {:.2}% sur {} périodes", self.max_drawdown * 100.0, self.max_drawdown_duration);
+    }
+}
+
+// Drawdown maximal (fraction du pic) et plus longue durée passée sous un pic
+fn max_drawdown(equity_curve: &[f64]) -> (f64, usize) {
+    let mut peak = f64::MIN;
+    let mut max_dd = 0.0;
+    let mut duration = 0;
+    let mut max_duration = 0;
+
+    for &equity in equity_curve {
+        if equity >= peak {
+            peak = equity;
+            duration = 0;
+        } else {
+            duration += 1;
+            max_duration = max_duration.max(duration);
+            if peak > 0.0 {
+                max_dd = f64::max(max_dd, (peak - equity) / peak);
+            }
+        }
+    }
+
+    (max_dd, max_duration)
+}
+
+// Séries de rendements construites à la main, valeurs attendues calculées sur papier
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUARTERLY: BacktestConfig = BacktestConfig { initial_capital: 1.0, risk_free_rate: 0.0, periods_per_year: 4.0 };
+
+    #[test]
+    fn sharpe_of_a_known_series() {
+        // Moyenne 0.1, écart-type échantillon sqrt(0.04 / 3): Sharpe par période sqrt(3) / 2, annualisé x sqrt(4)
+        let stats = BacktestStats::from_returns(&[0.2, 0.0, 0.2, 0.0], &QUARTERLY);
+        assert!((stats.mean_return - 0.1).abs() < 1e-12);
+        assert!((stats.sharpe_ratio - 3f64.sqrt()).abs() < 1e-9, "{}", stats.sharpe_ratio);
+        assert!((stats.total_return - 0.44).abs() < 1e-12);
+        assert_eq!((stats.max_drawdown, stats.max_drawdown_duration), (0.0, 0));
+
+        // Taux sans risque de 40% par an = 0.1 par trimestre: aucun rendement excédentaire
+        let with_risk_free = BacktestConfig { risk_free_rate: 0.4, ..QUARTERLY };
+        assert!(BacktestStats::from_returns(&[0.2, 0.0, 0.2, 0.0], &with_risk_free).sharpe_ratio.abs() < 1e-9);
+    }
+
+    #[test]
+    fn max_drawdown_of_a_known_series() {
+        // Capital 1 -> 1.25 -> 1.0 -> 0.5 -> 1.0 -> 1.1: pic 1.25, creux 0.5, jamais revenu au pic
+        let stats = BacktestStats::from_returns(&[0.25, -0.2, -0.5, 1.0, 0.1], &QUARTERLY);
+        let expected_curve = [1.0, 1.25, 1.0, 0.5, 1.0, 1.1];
+        assert!(stats.equity_curve.iter().zip(expected_curve).all(|(a, b)| (a - b).abs() < 1e-12), "{:?}", stats.equity_curve);
+        assert!((stats.max_drawdown - 0.6).abs() < 1e-12, "{}", stats.max_drawdown);
+        assert_eq!(stats.max_drawdown_duration, 4);
+        assert!((stats.total_return - 0.1).abs() < 1e-12);
+        assert_eq!((stats.wins, stats.losses), (3, 2));
+        assert!((stats.win_rate - 0.6).abs() < 1e-12);
+        assert!((stats.profit_factor - 1.35 / 0.7).abs() < 1e-12);
+    }
+
+    #[test]
+    fn no_trades_no_ratios() {
+        let stats = BacktestStats::from_returns(&[], &BacktestConfig::default());
+        assert_eq!((stats.trades, stats.sharpe_ratio, stats.max_drawdown), (0, 0.0, 0.0));
+        assert_eq!(stats.equity_curve, vec![1.0]);
+    }
+}
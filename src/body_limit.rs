@@ -0,0 +1,129 @@
+// Lecture plafonnée du corps des réponses HTTP (sources de résolution et orderbooks)
+// Le corps est lu morceau par morceau: une réponse pathologique ou malveillante est
+// refusée dès que le plafond est dépassé, au lieu d'être chargée entièrement en mémoire.
+use reqwest::Response;
+use serde_json::Value;
+use std::fmt;
+
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 8 * 1024 * 1024;
+
+#[derive(Debug)]
+pub enum BodyError {
+    // `received` est la taille annoncée (Content-Length) ou les octets déjà lus
+    TooLarge { limit: usize, received: u64 },
+    Transport(reqwest::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for BodyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BodyError::TooLarge { limit, received } => {
+                write!(f, "réponse trop volumineuse: {} octets ou plus, plafond {} octets", received, limit)
+            }
+            BodyError::Transport(e) => write!(f, "lecture de la réponse: {}", e),
+            BodyError::Json(e) => write!(f, "réponse JSON invalide: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BodyError {}
+
+pub async fn read_capped(mut response: Response, limit: usize) -> Result<Vec<u8>, BodyError> {
+    // Taille annoncée: refus sans rien lire
+    if let Some(announced) = response.content_length() {
+        if announced > limit as u64 {
+            return Err(BodyError::TooLarge { limit, received: announced });
+        }
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(BodyError::Transport)? {
+        if body.len() + chunk.len() > limit {
+            return Err(BodyError::TooLarge { limit, received: (body.len() + chunk.len()) as u64 });
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+pub async fn read_text(response: Response, limit: usize) -> Result<String, BodyError> {
+    let body = read_capped(response, limit).await?;
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+pub async fn read_json(response: Response, limit: usize) -> Result<Value, BodyError> {
+    let body = read_capped(response, limit).await?;
+    serde_json::from_slice(&body).map_err(BodyError::Json)
+}
+
+// Plafond de taille des réponses de sources et d'orderbooks: un corps plus grand que
+// MAX_RESPONSE_BYTES est refusé avec une erreur dédiée, qu'il annonce sa taille ou non
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    const LIMIT: usize = 64 * 1024;
+
+    // Sert une seule réponse HTTP sur un port local et renvoie son URL
+    fn serve_once(body: Vec<u8>, announce_length: bool) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("port libre");
+        let url = format!("http://{}/book", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("connexion");
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+            let head = if announce_length {
+                format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len())
+            } else {
+                // Ni Content-Length ni chunked: le corps court jusqu'à la fermeture
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nConnection: close\r\n\r\n".to_string()
+            };
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(&body);
+        });
+        url
+    }
+
+    fn oversized_orderbook() -> Vec<u8> {
+        let level = r#"{"price":"0.50","size":"100"},"#;
+        let levels = level.repeat(LIMIT / level.len() + 100);
+        format!(r#"{{"bids":[{}{{"price":"0.49","size":"1"}}],"asks":[]}}"#, levels).into_bytes()
+    }
+
+    async fn fetch_json(url: &str) -> Result<serde_json::Value, BodyError> {
+        let response = reqwest::get(url).await.expect("requête");
+        read_json(response, LIMIT).await
+    }
+
+    #[tokio::test]
+    async fn response_within_the_cap_is_parsed() {
+        let url = serve_once(br#"{"bids":[{"price":"0.49","size":"10"}],"asks":[]}"#.to_vec(), true);
+        let book = fetch_json(&url).await.expect("livre sous le plafond");
+        assert_eq!(book["bids"][0]["size"], "10");
+    }
+
+    #[tokio::test]
+    async fn announced_oversized_response_is_rejected() {
+        let body = oversized_orderbook();
+        let announced = body.len() as u64;
+        let url = serve_once(body, true);
+        match fetch_json(&url).await {
+            Err(BodyError::TooLarge { limit, received }) => {
+                assert_eq!(limit, LIMIT);
+                assert_eq!(received, announced, "taille annoncée par Content-Length");
+            }
+            other => panic!("refus attendu, obtenu {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn streamed_oversized_response_is_rejected() {
+        let url = serve_once(oversized_orderbook(), false);
+        let error = fetch_json(&url).await.expect_err("corps au-delà du plafond");
+        assert!(matches!(error, BodyError::TooLarge { limit: LIMIT, .. }), "{:?}", error);
+        assert!(error.to_string().starts_with("réponse trop volumineuse"), "{}", error);
+    }
+}
@@ -0,0 +1,133 @@
+// Identité d'un livre renvoyé par le CLOB: le livre de /book?token_id=... indique son token
+// (asset_id) et son marché (market, condition id). Un id réutilisé ou mal aiguillé peut renvoyer
+// le livre d'un autre marché: il est refusé plutôt que tradé comme s'il était le bon.
+use serde_json::Value;
+use std::fmt;
+
+// Traitement d'un livre qui ne dit pas à quel token il appartient
+// Un livre qui annonce un autre token ou un autre marché est toujours refusé
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BookIdentityPolicy {
+    #[default]
+    Lenient, // Livre sans asset_id accepté (défaut)
+    Strict,  // Livre sans asset_id refusé
+}
+
+impl BookIdentityPolicy {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "lenient" => Ok(BookIdentityPolicy::Lenient),
+            "strict" => Ok(BookIdentityPolicy::Strict),
+            _ => Err(format!("--book-identity: politique inconnue '{}' (attendu: lenient, strict)", name)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BookIdentityPolicy::Lenient => "lenient",
+            BookIdentityPolicy::Strict => "strict",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookMismatch {
+    WrongToken { requested: String, returned: String },
+    WrongMarket { expected: String, returned: String },
+    Unidentified { requested: String },
+}
+
+impl fmt::Display for BookMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookMismatch::WrongToken { requested, returned } =>
+                write!(f, "livre du token {} renvoyé pour le token {}", returned, requested),
+            BookMismatch::WrongMarket { expected, returned } =>
+                write!(f, "livre du marché {} renvoyé pour le marché {}", returned, expected),
+            BookMismatch::Unidentified { requested } =>
+                write!(f, "livre sans asset_id pour le token {} (--book-identity strict)", requested),
+        }
+    }
+}
+
+impl std::error::Error for BookMismatch {}
+
+// Texte d'un identifiant, chaîne ou nombre; None si absent ou vide
+fn id_field(book: &Value, key: &str) -> Option<String> {
+    match &book[key] {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+// Vérifier qu'un livre appartient au token demandé et, si connu, à son marché (condition id)
+pub fn verify_book(book: &Value, token_id: &str, condition_id: Option<&str>, policy: BookIdentityPolicy) -> Result<(), BookMismatch> {
+    match id_field(book, "asset_id") {
+        Some(returned) if returned != token_id => {
+            return Err(BookMismatch::WrongToken { requested: token_id.to_string(), returned });
+        }
+        None if policy == BookIdentityPolicy::Strict => {
+            return Err(BookMismatch::Unidentified { requested: token_id.to_string() });
+        }
+        _ => {}
+    }
+    let expected = condition_id.filter(|id| !id.is_empty());
+    if let (Some(expected), Some(returned)) = (expected, id_field(book, "market")) {
+        if !returned.eq_ignore_ascii_case(expected) {
+            return Err(BookMismatch::WrongMarket { expected: expected.to_string(), returned });
+        }
+    }
+    Ok(())
+}
+
+// Livre CLOB renvoyé pour un autre token ou un autre marché que celui demandé: refusé
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TOKEN: &str = "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+    const CONDITION: &str = "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917";
+
+    fn book(asset_id: serde_json::Value, market: serde_json::Value) -> serde_json::Value {
+        json!({
+            "market": market,
+            "asset_id": asset_id,
+            "bids": [{"price": "0.48", "size": "100"}],
+            "asks": [{"price": "0.52", "size": "100"}],
+            "hash": "0xabc"
+        })
+    }
+
+    #[test]
+    fn a_book_for_another_token_is_rejected() {
+        let policy = BookIdentityPolicy::Lenient;
+
+        // Livre du token demandé, sur le bon marché
+        assert_eq!(verify_book(&book(json!(TOKEN), json!(CONDITION)), TOKEN, Some(CONDITION), policy), Ok(()));
+
+        // Autre token: refusé, quelle que soit la politique
+        for policy in [BookIdentityPolicy::Lenient, BookIdentityPolicy::Strict] {
+            let mismatch = verify_book(&book(json!("52114319501245915516055106046884209969926127482827954674443846427813813222426"), json!(CONDITION)),
+                                       TOKEN, Some(CONDITION), policy).unwrap_err();
+            assert!(matches!(&mismatch, BookMismatch::WrongToken { requested, .. } if requested == TOKEN), "{:?}", mismatch);
+            assert!(mismatch.to_string().contains("renvoyé pour le token"), "{}", mismatch);
+        }
+
+        // Bon token annoncé mais autre marché: refusé; marché inconnu du bot: seul le token compte
+        let other_market = book(json!(TOKEN), json!("0x1111"));
+        assert!(matches!(verify_book(&other_market, TOKEN, Some(CONDITION), policy), Err(BookMismatch::WrongMarket { .. })));
+        assert_eq!(verify_book(&other_market, TOKEN, None, policy), Ok(()));
+        assert_eq!(verify_book(&book(json!(TOKEN), json!(CONDITION.to_uppercase().replace("0X", "0x"))), TOKEN, Some(CONDITION), policy), Ok(()));
+
+        // Livre muet sur son token: accepté en lenient, refusé en strict
+        let unidentified = book(serde_json::Value::Null, serde_json::Value::Null);
+        assert_eq!(verify_book(&unidentified, TOKEN, Some(CONDITION), BookIdentityPolicy::Lenient), Ok(()));
+        assert!(matches!(verify_book(&unidentified, TOKEN, Some(CONDITION), BookIdentityPolicy::Strict), Err(BookMismatch::Unidentified { .. })));
+
+        assert_eq!(BookIdentityPolicy::parse("STRICT"), Ok(BookIdentityPolicy::Strict));
+        assert!(BookIdentityPolicy::parse("off").is_err());
+    }
+}
@@ -0,0 +1,416 @@
+// Options de ligne de commande du bot
+// Parsing volontairement minimal: flags longs uniquement, valeurs séparées par un espace
+
+use crate::book_identity::BookIdentityPolicy;
+use crate::feed::DEFAULT_NEWS_MAX_AGE_SECS;
+use crate::fees::FeeSchedule;
+use crate::log_sink;
+use crate::runtime_limit;
+use crate::signal_sink::SignalSink;
+use crate::venue::PriceRounding;
+use std::time::Duration;
+
+// Domaines de marché supportés par le pipeline
+pub const KNOWN_DOMAINS: &[&str] = &["politics", "crypto", "economy"];
+
+// Profil de timeouts du client CLOB
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeoutProfile {
+    Aggressive, // Serveur colocalisé / très basse latence
+    #[default]
+    Balanced,   // Connexion ordinaire (défaut)
+    Safe,       // Connexion lente ou instable
+}
+
+impl TimeoutProfile {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "aggressive" => Ok(TimeoutProfile::Aggressive),
+            "balanced" => Ok(TimeoutProfile::Balanced),
+            "safe" => Ok(TimeoutProfile::Safe),
+            _ => Err(format!("--timeout-profile: profil inconnu '{}' (attendu: aggressive, balanced, safe)", name)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeoutProfile::Aggressive => "aggressive",
+            TimeoutProfile::Balanced => "balanced",
+            TimeoutProfile::Safe => "safe",
+        }
+    }
+}
+
+// Traitement des marchés sans source de résolution identifiable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingSourcePolicy {
+    Require, // Marché ignoré dès la détection
+    Allow,   // Traité comme les autres (défaut)
+    Flag,    // Traité, mais la confiance de ses signaux est abaissée d'un cran
+}
+
+impl MissingSourcePolicy {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "require" => Ok(MissingSourcePolicy::Require),
+            "allow" => Ok(MissingSourcePolicy::Allow),
+            "flag" => Ok(MissingSourcePolicy::Flag),
+            _ => Err(format!("--missing-source: politique inconnue '{}' (attendu: require, allow, flag)", name)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MissingSourcePolicy::Require => "require",
+            MissingSourcePolicy::Allow => "allow",
+            MissingSourcePolicy::Flag => "flag",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CliOptions {
+    pub only_domains: Vec<String>, // --only-domain <domaine> (répétable), vide = tous
+    pub ignore_markets: Vec<String>,     // --ignore-markets <id,...> (répétable): marchés exclus de tout le pipeline
+    pub denylist: Option<String>,        // --denylist <fichier>: denylist persistante, None = market_denylist.txt
+    pub signal_sink: Option<SignalSink>, // --signal-sink <stdout|file|http>, None = exécution normale
+    pub log_sink: Option<String>,        // --log-sink <s3://bucket/prefix>: copie périodique des journaux, None = local seulement
+    pub observe_only: bool,              // --observe-only: détection et signaux journalisés, ni mise ni exécution
+    pub shadow_real: bool,               // --shadow-real: chaîne du mode réel, ordres signés journalisés au lieu d'être envoyés
+    pub fixture: Option<String>,         // --fixture <fichier>: cycle hors-ligne, mode simulation forcé
+    pub seed: Option<u64>,               // --seed <n>: aléa reproductible
+    pub once: bool,                      // --once: un seul cycle puis arrêt
+    pub cycles: Option<u64>,             // --cycles <n>: n cycles puis arrêt, None = sans fin
+    pub max_runtime: Option<Duration>,   // --max-runtime <durée>: arrêt après le cycle en cours une fois la durée écoulée
+    pub signals_out: Option<String>,     // --signals-out <fichier>: instantané JSON des signaux après le cycle
+    pub report_json: Option<String>,     // --report-json <fichier>: rapport JSON du cycle (avec --once)
+    pub strict_oracle: bool,             // --strict-oracle: seule la source de résolution du marché déclenche un signal
+    pub missing_source: MissingSourcePolicy, // --missing-source <require|allow|flag>: marchés sans source de résolution
+    pub cheapest_route: bool,            // --cheapest-route: acheter la vue ou vendre le token opposé, au moins cher
+    pub queue_model: bool,               // --queue-model: ordres papier passifs, remplis selon la file et le flux
+    pub passive_deadline: Option<f64>,   // --passive-deadline <secs>: reste non rempli repris ou annulé à l'échéance
+    pub spread_roi: bool,                // --spread-roi: ROI payé au ask, sortie au bid (net du spread complet)
+    pub price_rounding: PriceRounding,   // --price-rounding <mode>: arrondi au tick des prix d'ordre
+    pub book_identity: BookIdentityPolicy, // --book-identity <lenient|strict>: livre CLOB sans asset_id accepté ou refusé
+    pub verify_roi: bool,                // --verify-roi: ROI C++ recalculé en Rust, divergence journalisée
+    pub max_analyze: Option<usize>,      // --max-analyze <n>: marchés analysés par cycle, None = tous
+    pub min_sources: usize,              // --min-sources <n>: sources distinctes d'accord sur la direction pour un signal
+    pub aggregate_sources: bool,         // --aggregate-sources: une décision nette par marché au lieu d'un signal par source
+    pub simulate_news: Option<String>,   // --simulate-news <fichier>: titres synthétiques injectés dans les sources
+    pub direction_rules: Option<String>, // --direction-rules <fichier>: règles information → sens, None = règles intégrées
+    pub kill_file: Option<String>,       // --kill-file <fichier>: trading suspendu tant que le fichier existe
+    pub hash_sources: bool,              // --hash-sources: corps de source inchangé (empreinte) non reclassé
+    pub dump_sources: Option<String>,    // --dump-sources <répertoire>: résultat de chaque source, par cycle
+    pub snapshot_dir: Option<String>,    // --snapshot-dir <répertoire>: instantané JSON de chaque cycle
+    pub diff_cycles: Option<(String, String)>, // --diff-cycles <a> <b>: comparer deux instantanés puis quitter
+    pub news_max_age_secs: f64,          // --news-max-age <secs>: éléments datés plus anciens ignorés, 0 = sans limite
+    pub replay_ws: Option<String>,       // --replay-ws <fichier>: rejouer un flux WS enregistré puis quitter
+    pub record_ws: Option<String>,       // --record-ws <fichier>: enregistrer le flux WS des tokens du cycle puis quitter
+    pub replay_speed: f64,               // --replay-speed <x>: 1 = cadence enregistrée, 0 = sans attente
+    pub health_port: Option<u16>,        // --health-port <port>: servir /healthz et /readyz
+    pub fee_tier: FeeSchedule,           // --fee-tier <zero|standard|custom:m:t>: barème maker/taker
+    pub timeout_profile: TimeoutProfile, // --timeout-profile <p>, --aggressive-timeout, --safe-timeout
+    pub mode: Option<String>,            // --mode <simulation|real>, None = choix interactif
+}
+
+impl Default for CliOptions {
+    fn default() -> Self {
+        CliOptions {
+            only_domains: Vec::new(),
+            ignore_markets: Vec::new(),
+            denylist: None,
+            signal_sink: None,
+            log_sink: None,
+            observe_only: false,
+            shadow_real: false,
+            fixture: None,
+            seed: None,
+            once: false,
+            cycles: None,
+            max_runtime: None,
+            signals_out: None,
+            report_json: None,
+            strict_oracle: false,
+            missing_source: MissingSourcePolicy::Allow,
+            cheapest_route: false,
+            queue_model: false,
+            passive_deadline: None,
+            spread_roi: false,
+            price_rounding: PriceRounding::default(),
+            book_identity: BookIdentityPolicy::default(),
+            verify_roi: false,
+            max_analyze: None,
+            min_sources: 1,
+            aggregate_sources: false,
+            simulate_news: None,
+            direction_rules: None,
+            kill_file: None,
+            hash_sources: false,
+            dump_sources: None,
+            snapshot_dir: None,
+            diff_cycles: None,
+            news_max_age_secs: DEFAULT_NEWS_MAX_AGE_SECS,
+            replay_ws: None,
+            record_ws: None,
+            replay_speed: 1.0,
+            health_port: None,
+            fee_tier: FeeSchedule::default(),
+            timeout_profile: TimeoutProfile::Balanced,
+            mode: None,
+        }
+    }
+}
+
+impl CliOptions {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = CliOptions::default();
+        let mut args = args.into_iter();
+        let mut sink_kind: Option<String> = None;
+        let mut sink_path: Option<String> = None;
+        let mut sink_url: Option<String> = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--only-domain" => {
+                    let domain = next_value(&mut args, &arg)?.to_lowercase();
+                    if !KNOWN_DOMAINS.contains(&domain.as_str()) {
+                        return Err(format!("--only-domain: domaine inconnu '{}' (attendu: {})", domain, KNOWN_DOMAINS.join(", ")));
+                    }
+                    if !options.only_domains.contains(&domain) {
+                        options.only_domains.push(domain);
+                    }
+                }
+                "--ignore-markets" => {
+                    let value = next_value(&mut args, &arg)?;
+                    for market_id in value.split(',').map(str::trim).filter(|id| !id.is_empty()) {
+                        if !options.ignore_markets.iter().any(|id| id == market_id) {
+                            options.ignore_markets.push(market_id.to_string());
+                        }
+                    }
+                }
+                "--denylist" => options.denylist = Some(next_value(&mut args, &arg)?),
+                "--signal-sink" => sink_kind = Some(next_value(&mut args, &arg)?.to_lowercase()),
+                "--signal-sink-path" => sink_path = Some(next_value(&mut args, &arg)?),
+                "--signal-sink-url" => sink_url = Some(next_value(&mut args, &arg)?),
+                "--log-sink" => {
+                    let url = next_value(&mut args, &arg)?;
+                    log_sink::parse_s3_url(&url)?;
+                    options.log_sink = Some(url);
+                }
+                "--fixture" => options.fixture = Some(next_value(&mut args, &arg)?),
+                "--seed" => {
+                    let value = next_value(&mut args, &arg)?;
+                    let seed = value.parse::<u64>()
+                        .map_err(|_| format!("--seed: entier positif attendu, reçu '{}'", value))?;
+                    options.seed = Some(seed);
+                }
+                "--once" => options.once = true,
+                "--observe-only" => options.observe_only = true,
+                "--shadow-real" => options.shadow_real = true,
+                "--cycles" => {
+                    let value = next_value(&mut args, &arg)?;
+                    let cycles = value.parse::<u64>().ok().filter(|n| *n > 0)
+                        .ok_or_else(|| format!("--cycles: entier strictement positif attendu, reçu '{}'", value))?;
+                    options.cycles = Some(cycles);
+                }
+                "--max-runtime" => options.max_runtime = Some(runtime_limit::parse_duration(&next_value(&mut args, &arg)?)?),
+                "--signals-out" => options.signals_out = Some(next_value(&mut args, &arg)?),
+                "--report-json" => options.report_json = Some(next_value(&mut args, &arg)?),
+                "--strict-oracle" => options.strict_oracle = true,
+                "--missing-source" => options.missing_source = MissingSourcePolicy::parse(&next_value(&mut args, &arg)?)?,
+                "--cheapest-route" => options.cheapest_route = true,
+                "--queue-model" => options.queue_model = true,
+                "--passive-deadline" => {
+                    let value = next_value(&mut args, &arg)?;
+                    options.passive_deadline = Some(value.parse::<f64>().ok().filter(|secs| secs.is_finite() && *secs >= 0.0)
+                        .ok_or_else(|| format!("--passive-deadline: nombre de secondes positif attendu, reçu '{}'", value))?);
+                }
+                "--spread-roi" => options.spread_roi = true,
+                "--price-rounding" => options.price_rounding = PriceRounding::parse(&next_value(&mut args, &arg)?)?,
+                "--book-identity" => options.book_identity = BookIdentityPolicy::parse(&next_value(&mut args, &arg)?)?,
+                "--verify-roi" => options.verify_roi = true,
+                "--timeout-profile" => options.timeout_profile = TimeoutProfile::parse(&next_value(&mut args, &arg)?)?,
+                "--aggressive-timeout" => options.timeout_profile = TimeoutProfile::Aggressive,
+                "--safe-timeout" => options.timeout_profile = TimeoutProfile::Safe,
+                "--mode" => {
+                    let mode = next_value(&mut args, &arg)?.to_lowercase();
+                    if mode != "simulation" && mode != "real" {
+                        return Err(format!("--mode: mode inconnu '{}' (attendu: simulation, real)", mode));
+                    }
+                    options.mode = Some(mode);
+                }
+                "--fee-tier" => options.fee_tier = FeeSchedule::parse(&next_value(&mut args, &arg)?)?,
+                "--health-port" => {
+                    let value = next_value(&mut args, &arg)?;
+                    let port = value.parse::<u16>()
+                        .map_err(|_| format!("--health-port: port attendu, reçu '{}'", value))?;
+                    options.health_port = Some(port);
+                }
+                "--replay-ws" => options.replay_ws = Some(next_value(&mut args, &arg)?),
+                "--record-ws" => options.record_ws = Some(next_value(&mut args, &arg)?),
+                "--replay-speed" => {
+                    let value = next_value(&mut args, &arg)?;
+                    let speed = value.parse::<f64>().ok().filter(|x| x.is_finite() && *x >= 0.0)
+                        .ok_or_else(|| format!("--replay-speed: nombre positif attendu, reçu '{}'", value))?;
+                    options.replay_speed = speed;
+                }
+                "--max-analyze" => {
+                    let value = next_value(&mut args, &arg)?;
+                    let max = value.parse::<usize>().ok().filter(|n| *n > 0)
+                        .ok_or_else(|| format!("--max-analyze: entier strictement positif attendu, reçu '{}'", value))?;
+                    options.max_analyze = Some(max);
+                }
+                "--min-sources" => {
+                    let value = next_value(&mut args, &arg)?;
+                    options.min_sources = value.parse::<usize>().ok().filter(|n| *n > 0)
+                        .ok_or_else(|| format!("--min-sources: entier strictement positif attendu, reçu '{}'", value))?;
+                }
+                "--aggregate-sources" => options.aggregate_sources = true,
+                "--simulate-news" => options.simulate_news = Some(next_value(&mut args, &arg)?),
+                "--direction-rules" => options.direction_rules = Some(next_value(&mut args, &arg)?),
+                "--kill-file" => options.kill_file = Some(next_value(&mut args, &arg)?),
+                "--hash-sources" => options.hash_sources = true,
+                "--dump-sources" => options.dump_sources = Some(next_value(&mut args, &arg)?),
+                "--snapshot-dir" => options.snapshot_dir = Some(next_value(&mut args, &arg)?),
+                "--diff-cycles" => {
+                    let before = next_value(&mut args, &arg)?;
+                    let after = next_value(&mut args, &arg)?;
+                    options.diff_cycles = Some((before, after));
+                }
+                "--news-max-age" => {
+                    let value = next_value(&mut args, &arg)?;
+                    let max_age = value.parse::<f64>().ok().filter(|x| x.is_finite() && *x >= 0.0)
+                        .ok_or_else(|| format!("--news-max-age: nombre de secondes positif attendu, reçu '{}'", value))?;
+                    options.news_max_age_secs = max_age;
+                }
+                "--help" | "-h" => return Err(usage()),
+                _ => return Err(format!("Option inconnue: {}\n{}", arg, usage())),
+            }
+        }
+
+        match sink_kind {
+            Some(kind) => {
+                options.signal_sink = Some(SignalSink::from_options(&kind, sink_path.as_deref(), sink_url.as_deref())?);
+            }
+            None if sink_path.is_some() || sink_url.is_some() => {
+                return Err("--signal-sink-path/--signal-sink-url nécessitent --signal-sink".to_string());
+            }
+            None => {}
+        }
+
+        // L'observation ne passe aucun ordre: ni intention émise, ni mode réel
+        if options.observe_only && options.signal_sink.is_some() {
+            return Err("--observe-only et --signal-sink sont incompatibles".to_string());
+        }
+        if options.observe_only && options.mode.as_deref() == Some("real") {
+            return Err("--observe-only et --mode real sont incompatibles".to_string());
+        }
+
+        // Le shadow-real remplace l'exécution: ni autre destination des signaux, ni choix de mode
+        if options.shadow_real && (options.observe_only || options.signal_sink.is_some()) {
+            return Err("--shadow-real est incompatible avec --observe-only et --signal-sink".to_string());
+        }
+        if options.shadow_real && options.mode.is_some() {
+            return Err("--shadow-real et --mode sont incompatibles".to_string());
+        }
+
+        // L'échéance ne concerne que les ordres passifs du modèle de file
+        if options.passive_deadline.is_some() && !options.queue_model {
+            return Err("--passive-deadline nécessite --queue-model".to_string());
+        }
+
+        // Le rapport décrit un cycle unique: son code de sortie n'a de sens qu'avec --once
+        if options.report_json.is_some() && !options.once {
+            return Err("--report-json nécessite --once".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+fn next_value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{} attend une valeur", flag))
+}
+
+pub fn usage() -> String {
+    [
+        "Usage: polymarket-bot [OPTIONS]",
+        "  --only-domain <domaine>   Limiter le bot à un domaine (politics, crypto, economy), répétable",
+        "  --ignore-markets <ids>    Exclure ces marchés (identifiants séparés par des virgules) de tout le pipeline, répétable",
+        "  --denylist <fichier>      Denylist persistante de marchés, complétée automatiquement (défaut: market_denylist.txt)",
+        "  --signal-sink <cible>     Émettre les signaux BUY/SELL sans trader: stdout, file, http",
+        "  --signal-sink-path <fichier>  Fichier JSON Lines pour --signal-sink file (défaut: order_intents.jsonl)",
+        "  --signal-sink-url <url>   Endpoint POST pour --signal-sink http",
+        "  --log-sink <url>          Copier périodiquement les journaux vers s3://bucket/prefix (best-effort)",
+        "  --observe-only            Détecter et journaliser opportunités et signaux (ROI), sans mise ni exécution",
+        "  --shadow-real             Mode réel jusqu'à la requête: ordres signés (EIP-712) et en-têtes L2 journalisés dans shadow_orders.jsonl, jamais envoyés",
+        "  --fixture <fichier>       Rejouer marchés, sources et orderbooks depuis un fichier JSON (simulation, hors-ligne)",
+        "  --seed <n>                Graine de l'aléa pour des cycles reproductibles",
+        "  --once                    Exécuter un seul cycle puis s'arrêter",
+        "  --cycles <n>              Exécuter n cycles puis s'arrêter",
+        "  --max-runtime <durée>     S'arrêter une fois la durée écoulée (ex: 10m, 1h30m), après le cycle en cours",
+        "  --signals-out <fichier>   Écrire les signaux du cycle en JSON (stable, sans horodatages)",
+        "  --report-json <fichier>   Avec --once: rapport JSON du cycle, code de sortie 1 si le cycle a échoué",
+        "  --strict-oracle           Ne trader que sur la source de résolution nommée par le marché",
+        "  --missing-source <p>      Marchés sans source de résolution identifiable: require (ignorés), allow (défaut), flag (confiance abaissée)",
+        "  --cheapest-route          Exprimer la vue par la voie la moins chère: achat du token ou vente du token opposé",
+        "  --queue-model             Simulation: ordres passifs au meilleur bid, remplis selon la file devant eux et le flux observé; le reste non rempli reste ouvert (GTC) d'un cycle à l'autre",
+        "  --passive-deadline <secs> Avec --queue-model: reste non rempli d'un ordre passif remplacé au prix marketable après secs secondes si l'edge net tient (PASSIVE_MIN_EDGE), annulé sinon",
+        "  --spread-roi              ROI calculé au ask payé et à la sortie au bid, net du spread complet (au lieu du prix seul)",
+        "  --price-rounding <mode>   Arrondi au tick des prix d'ordre: marketable (défaut), passive, nearest",
+        "  --book-identity <p>       Livre CLOB sans asset_id: lenient (accepté, défaut) ou strict (refusé); un livre d'un autre token est toujours refusé",
+        "  --verify-roi              Recalculer en Rust chaque ROI du core C++ et signaler les écarts (ROI_VERIFY_TOLERANCE)",
+        "  --max-analyze <n>         Analyser seulement les n marchés au meilleur pré-score par cycle",
+        "  --min-sources <n>         Signal seulement si n sources distinctes indiquent la même direction (défaut: 1)",
+        "  --aggregate-sources       Combiner les sources d'un marché en une décision nette (vote pondéré): au plus un signal par marché",
+        "  --direction-rules <fich.> Règles information → sens (JSON: market_pattern, source_pattern, keyword, direction, weight), remplacent les règles intégrées",
+        "  --kill-file <fichier>     Arrêt d'urgence: tant que le fichier existe, ordres ouverts annulés et décisions forcées en MONITOR (contrôlé à chaque cycle et entre les phases)",
+        "  --hash-sources            Empreinte du corps de chaque source: identique au cycle précédent, sa classification est reprise au lieu d'être refaite",
+        "  --simulate-news <fichier> Injecter des titres synthétiques (JSON Lines: cycle, source, headline, timestamp)",
+        "  --dump-sources <rép>      Écrire à chaque cycle un JSON par source (statut, taille, mots-clés, éléments reconnus)",
+        "  --snapshot-dir <rép>      Écrire à chaque cycle un instantané (prix, sources, opportunités, signaux): <rép>/cycle-<n>.json",
+        "  --diff-cycles <a> <b>     Comparer deux instantanés (numéros de cycle dans --snapshot-dir, défaut cycle_snapshots, ou fichiers) puis quitter",
+        "  --news-max-age <secs>     Ignorer les éléments publiés il y a plus de secs secondes (défaut: 900, 0 = sans limite)",
+        "  --timeout-profile <p>     Timeouts du client CLOB: aggressive (100ms), balanced (2s, défaut), safe (10s)",
+        "  --aggressive-timeout      Raccourci pour --timeout-profile aggressive (serveur colocalisé)",
+        "  --safe-timeout            Raccourci pour --timeout-profile safe",
+        "  --mode <mode>             simulation ou real (real exige aussi POLYBOT_CONFIRM_REAL=yes)",
+        "  --fee-tier <barème>       Frais: zero, standard (taker 2%, défaut) ou custom:<maker_bps>:<taker_bps>",
+        "  --health-port <port>      Servir /healthz (vivant) et /readyz (prêt) sur ce port",
+        "  --replay-ws <fichier>     Rejouer un flux WS orderbook enregistré (JSON Lines) et afficher les livres",
+        "  --replay-speed <x>        Cadence du rejeu: 1 = enregistrée (défaut), 10 = 10x plus vite, 0 = sans attente",
+        "  --record-ws <fichier>     Enregistrer le flux WS orderbook des tokens du cycle pour --replay-ws (Ctrl-C ou --max-runtime)",
+    ].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliOptions, String> {
+        CliOptions::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn only_domain_is_repeatable_case_insensitive_and_deduplicated() {
+        let options = parse(&["--only-domain", "Crypto", "--only-domain", "politics", "--only-domain", "crypto"]).unwrap();
+        assert_eq!(options.only_domains, vec!["crypto", "politics"]);
+        assert!(parse(&[]).unwrap().only_domains.is_empty());
+    }
+
+    #[test]
+    fn only_domain_rejects_an_unknown_domain_or_a_missing_value() {
+        let error = parse(&["--only-domain", "sports"]).unwrap_err();
+        assert!(error.contains("domaine inconnu 'sports'"), "{}", error);
+        assert!(parse(&["--only-domain"]).is_err());
+    }
+
+    #[test]
+    fn timeout_profile_defaults_to_balanced_and_follows_the_last_flag() {
+        assert_eq!(parse(&[]).unwrap().timeout_profile, TimeoutProfile::Balanced);
+        assert_eq!(parse(&["--aggressive-timeout"]).unwrap().timeout_profile, TimeoutProfile::Aggressive);
+        assert_eq!(parse(&["--safe-timeout"]).unwrap().timeout_profile, TimeoutProfile::Safe);
+        assert_eq!(parse(&["--safe-timeout", "--timeout-profile", "balanced"]).unwrap().timeout_profile, TimeoutProfile::Balanced);
+        assert!(parse(&["--timeout-profile", "fast"]).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+// Réponses d'erreur du CLOB Polymarket
+// Le CLOB renvoie un corps JSON ({"error": "..."}, {"errorMsg": "..."}, {"code": ..., "message": ...});
+// une page HTML (Cloudflare, proxy) signifie que la requête n'a jamais atteint le CLOB
+use serde_json::Value;
+use std::fmt;
+
+// Famille d'erreur, déduite du message (le CLOB ne fournit pas toujours de code)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClobErrorKind {
+    InsufficientBalance,    // Solde ou allowance insuffisant
+    InvalidOrder,           // Ordre mal formé, taille sous le minimum, signature...
+    PriceOutOfRange,        // Prix hors tick / hors [0, 1]
+    TemporarilyUnavailable, // Surcharge, maintenance, limite de débit
+    Other,
+}
+
+// Conduite à tenir après un échec d'ordre
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClobReaction {
+    Retry,        // Réessayer le même ordre après une courte attente
+    SkipOrder,    // Abandonner cet ordre, continuer avec les suivants
+    StopTrading,  // Plus aucun ordre ce cycle
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClobError {
+    pub status: u16,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl ClobError {
+    pub fn kind(&self) -> ClobErrorKind {
+        let text = format!("{} {}", self.code.as_deref().unwrap_or(""), self.message).to_lowercase();
+        let has = |needles: &[&str]| needles.iter().any(|needle| text.contains(needle));
+
+        if has(&["not enough balance", "insufficient balance", "insufficient_balance", "allowance", "insufficient funds"]) {
+            ClobErrorKind::InsufficientBalance
+        } else if has(&["price out of range", "invalid price", "tick size", "price_out_of_range"]) {
+            ClobErrorKind::PriceOutOfRange
+        } else if self.status == 429 || self.status >= 500
+            || has(&["temporarily unavailable", "try again", "rate limit", "too many requests", "maintenance", "overloaded"]) {
+            ClobErrorKind::TemporarilyUnavailable
+        } else if has(&["invalid order", "invalid_order", "order size", "minimum", "invalid signature", "bad request"]) {
+            ClobErrorKind::InvalidOrder
+        } else {
+            ClobErrorKind::Other
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClobFailure {
+    Api(ClobError),             // Erreur structurée renvoyée par le CLOB
+    Blocked { status: u16 },    // Corps non JSON: Cloudflare, proxy, page d'erreur HTML
+}
+
+impl ClobFailure {
+    pub fn parse(status: u16, body: &str) -> Self {
+        let Ok(json) = serde_json::from_str::<Value>(body.trim()) else {
+            return ClobFailure::Blocked { status };
+        };
+        let text = |keys: &[&str]| keys.iter().find_map(|key| match &json[*key] {
+            Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
+
+        ClobFailure::Api(ClobError {
+            status,
+            code: text(&["code", "errorCode", "error_code"]),
+            message: text(&["error", "errorMsg", "message", "msg", "detail"]).unwrap_or_else(|| json.to_string()),
+        })
+    }
+
+    pub fn reaction(&self) -> ClobReaction {
+        match self {
+            ClobFailure::Blocked { .. } => ClobReaction::StopTrading,
+            ClobFailure::Api(error) => match error.kind() {
+                ClobErrorKind::InsufficientBalance => ClobReaction::StopTrading,
+                ClobErrorKind::TemporarilyUnavailable => ClobReaction::Retry,
+                ClobErrorKind::InvalidOrder | ClobErrorKind::PriceOutOfRange | ClobErrorKind::Other => ClobReaction::SkipOrder,
+            },
+        }
+    }
+}
+
+impl fmt::Display for ClobFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClobFailure::Api(error) => write!(f, "CLOB {} {:?} (code {}): {}",
+                error.status, error.kind(), error.code.as_deref().unwrap_or("-"), error.message),
+            ClobFailure::Blocked { status } => write!(f, "requête bloquée avant le CLOB (status {}, réponse non JSON)", status),
+        }
+    }
+}
+
+impl std::error::Error for ClobFailure {}
+
+// Réponses d'erreur du CLOB: chaque corps type est classé dans la bonne famille, avec la réaction
+// attendue (arrêter, réessayer, abandonner l'ordre).
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind_of(failure: &ClobFailure) -> ClobErrorKind {
+        match failure {
+            ClobFailure::Api(error) => error.kind(),
+            ClobFailure::Blocked { status } => panic!("réponse JSON classée comme bloquée (status {})", status),
+        }
+    }
+
+    #[test]
+    fn insufficient_balance_stops_trading() {
+        let failure = ClobFailure::parse(400, r#"{"error":"not enough balance / allowance"}"#);
+        assert_eq!(kind_of(&failure), ClobErrorKind::InsufficientBalance);
+        assert_eq!(failure.reaction(), ClobReaction::StopTrading);
+    }
+
+    #[test]
+    fn invalid_order_is_skipped() {
+        let failure = ClobFailure::parse(400, r#"{"errorMsg":"invalid order: size lower than the minimum"}"#);
+        assert_eq!(kind_of(&failure), ClobErrorKind::InvalidOrder);
+        assert_eq!(failure.reaction(), ClobReaction::SkipOrder);
+    }
+
+    #[test]
+    fn price_out_of_range_is_skipped() {
+        let failure = ClobFailure::parse(400, r#"{"code":"PRICE_OUT_OF_RANGE","message":"price must be between 0.01 and 0.99"}"#);
+        let ClobFailure::Api(error) = &failure else { panic!("erreur structurée attendue") };
+        assert_eq!(error.code.as_deref(), Some("PRICE_OUT_OF_RANGE"));
+        assert_eq!(error.kind(), ClobErrorKind::PriceOutOfRange);
+        assert_eq!(failure.reaction(), ClobReaction::SkipOrder);
+    }
+
+    #[test]
+    fn temporarily_unavailable_is_retried() {
+        let failure = ClobFailure::parse(503, r#"{"error":"service temporarily unavailable, try again later"}"#);
+        assert_eq!(kind_of(&failure), ClobErrorKind::TemporarilyUnavailable);
+        assert_eq!(failure.reaction(), ClobReaction::Retry);
+
+        // Limite de débit sans message explicite: le status suffit
+        let rate_limited = ClobFailure::parse(429, r#"{"error":"slow down"}"#);
+        assert_eq!(rate_limited.reaction(), ClobReaction::Retry);
+    }
+
+    #[test]
+    fn html_body_is_blocked() {
+        let body = "<!DOCTYPE html><html><head><title>Attention Required! | Cloudflare</title></head><body>Sorry, you have been blocked</body></html>";
+        let failure = ClobFailure::parse(403, body);
+        assert_eq!(failure, ClobFailure::Blocked { status: 403 });
+        assert_eq!(failure.reaction(), ClobReaction::StopTrading);
+    }
+
+    #[test]
+    fn unknown_json_keeps_the_raw_body() {
+        let failure = ClobFailure::parse(400, r#"{"foo":"bar"}"#);
+        let ClobFailure::Api(error) = &failure else { panic!("erreur structurée attendue") };
+        assert_eq!(error.kind(), ClobErrorKind::Other);
+        assert!(error.message.contains("foo"), "message: {}", error.message);
+        assert_eq!(failure.reaction(), ClobReaction::SkipOrder);
+    }
+}
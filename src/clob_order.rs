@@ -0,0 +1,451 @@
+// Ordres CLOB Polymarket signés (EIP-712) et en-têtes d'authentification L2
+// Requête POST /order du mode réel; --shadow-real construit la même et s'arrête avant l'envoi
+// Référence: contrats CTF Exchange sur Polygon, clients officiels py-clob-client / clob-client
+use alloy_primitives::{address, keccak256, Address, B256, U256};
+use hmac::{Hmac, Mac};
+use k256::ecdsa::{SigningKey, VerifyingKey};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::env;
+
+pub const CLOB_CHAIN_ID: u64 = 137; // Polygon
+pub const CTF_EXCHANGE: Address = address!("4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+pub const NEG_RISK_CTF_EXCHANGE: Address = address!("C5d563A36AE78145C45a50134d48A1215220f80a");
+pub const ORDER_PATH: &str = "/order";
+pub const CANCEL_ALL_PATH: &str = "/cancel-all"; // Annule tous les ordres ouverts du compte
+pub const BALANCE_ALLOWANCE_PATH: &str = "/balance-allowance"; // Solde et allowance du compte (?asset_type=COLLATERAL)
+pub const SHADOW_ORDERS_FILE: &str = "shadow_orders.jsonl"; // Une requête JSON par ligne
+// Le CLOB refuse une expiration GTD à moins d'une minute: marge ajoutée au TTL de l'intention
+pub const GTD_SECURITY_MARGIN_SECS: u64 = 60;
+
+const DOMAIN_NAME: &str = "Polymarket CTF Exchange";
+const DOMAIN_VERSION: &str = "1";
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+const ORDER_TYPE: &str = "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,uint256 feeRateBps,uint8 side,uint8 signatureType)";
+const AMOUNT_DECIMALS: f64 = 1e6; // USDC et tokens conditionnels: 6 décimales
+
+pub type Headers = Vec<(&'static str, String)>; // En-têtes HTTP d'une requête CLOB authentifiée
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClobSide {
+    Buy,
+    Sell,
+}
+
+impl ClobSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClobSide::Buy => "BUY",
+            ClobSide::Sell => "SELL",
+        }
+    }
+
+    fn code(&self) -> u8 {
+        match self {
+            ClobSide::Buy => 0,
+            ClobSide::Sell => 1,
+        }
+    }
+}
+
+// Type de signature du contrat: EOA si le signataire détient les fonds, proxy Polymarket sinon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureType {
+    Eoa,
+    PolyProxy,
+}
+
+impl SignatureType {
+    fn code(&self) -> u8 {
+        match self {
+            SignatureType::Eoa => 0,
+            SignatureType::PolyProxy => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClobOrder {
+    pub salt: u64,
+    pub maker: Address,   // Détenteur des fonds (WALLET_ADDRESS)
+    pub signer: Address,  // Adresse de la clé privée
+    pub taker: Address,   // Zéro: ordre public
+    pub token_id: U256,
+    pub maker_amount: u64, // BUY: USDC payés, SELL: tokens vendus (6 décimales)
+    pub taker_amount: u64, // BUY: tokens reçus, SELL: USDC reçus (6 décimales)
+    pub expiration: u64,   // Secondes Unix, 0 = sans expiration
+    pub nonce: u64,
+    pub fee_rate_bps: u64,
+    pub side: ClobSide,
+    pub signature_type: SignatureType,
+    pub neg_risk: bool,    // Marché neg-risk: autre contrat vérificateur
+}
+
+impl ClobOrder {
+    // Ordre limite pour `size_usdc` USDC au prix `price` du token
+    // Comme les clients officiels: quantité de tokens tronquée à 2 décimales, montant USDC à 4
+    #[allow(clippy::too_many_arguments)]
+    pub fn limit(token_id: &str, side: ClobSide, price: f64, size_usdc: f64, maker: Address, signer: Address,
+                 fee_rate_bps: u64, expiration: u64, salt: u64, neg_risk: bool) -> Result<Self, String> {
+        let token_id = U256::from_str_radix(token_id, 10)
+            .map_err(|_| format!("token CLOB '{}' non numérique", token_id))?;
+        if !(price > 0.0 && price < 1.0) {
+            return Err(format!("prix {} hors de ]0, 1[", price));
+        }
+        let shares = truncate(size_usdc / price, 2);
+        let usdc = truncate(shares * price, 4);
+        if shares <= 0.0 || usdc <= 0.0 {
+            return Err(format!("taille {:.4} USDC trop faible au prix {}", size_usdc, price));
+        }
+        let (maker_amount, taker_amount) = match side {
+            ClobSide::Buy => (to_units(usdc), to_units(shares)),
+            ClobSide::Sell => (to_units(shares), to_units(usdc)),
+        };
+        Ok(ClobOrder {
+            salt,
+            maker,
+            signer,
+            taker: Address::ZERO,
+            token_id,
+            maker_amount,
+            taker_amount,
+            expiration,
+            nonce: 0,
+            fee_rate_bps,
+            side,
+            signature_type: if maker == signer { SignatureType::Eoa } else { SignatureType::PolyProxy },
+            neg_risk,
+        })
+    }
+
+    pub fn verifying_contract(&self) -> Address {
+        if self.neg_risk { NEG_RISK_CTF_EXCHANGE } else { CTF_EXCHANGE }
+    }
+
+    fn struct_hash(&self) -> B256 {
+        let mut encoded = Vec::with_capacity(13 * 32);
+        encoded.extend_from_slice(keccak256(ORDER_TYPE).as_slice());
+        for word in [
+            U256::from(self.salt),
+            address_word(self.maker),
+            address_word(self.signer),
+            address_word(self.taker),
+            self.token_id,
+            U256::from(self.maker_amount),
+            U256::from(self.taker_amount),
+            U256::from(self.expiration),
+            U256::from(self.nonce),
+            U256::from(self.fee_rate_bps),
+            U256::from(self.side.code()),
+            U256::from(self.signature_type.code()),
+        ] {
+            encoded.extend_from_slice(&word.to_be_bytes::<32>());
+        }
+        keccak256(&encoded)
+    }
+
+    fn domain_separator(&self) -> B256 {
+        let mut encoded = Vec::with_capacity(5 * 32);
+        encoded.extend_from_slice(keccak256(DOMAIN_TYPE).as_slice());
+        encoded.extend_from_slice(keccak256(DOMAIN_NAME).as_slice());
+        encoded.extend_from_slice(keccak256(DOMAIN_VERSION).as_slice());
+        encoded.extend_from_slice(&U256::from(CLOB_CHAIN_ID).to_be_bytes::<32>());
+        encoded.extend_from_slice(&address_word(self.verifying_contract()).to_be_bytes::<32>());
+        keccak256(&encoded)
+    }
+
+    // Condensat EIP-712 signé: keccak256(0x1901 ‖ domaine ‖ ordre)
+    pub fn signing_digest(&self) -> B256 {
+        let mut encoded = Vec::with_capacity(2 + 2 * 32);
+        encoded.extend_from_slice(&[0x19, 0x01]);
+        encoded.extend_from_slice(self.domain_separator().as_slice());
+        encoded.extend_from_slice(self.struct_hash().as_slice());
+        keccak256(&encoded)
+    }
+
+    // Signature r ‖ s ‖ v (v = 27 ou 28), en hexadécimal préfixé 0x
+    pub fn sign(&self, key: &SigningKey) -> Result<String, String> {
+        let (signature, recovery_id) = key.sign_prehash_recoverable(self.signing_digest().as_slice())
+            .map_err(|e| format!("signature EIP-712 impossible: {}", e))?;
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(27 + recovery_id.to_byte());
+        Ok(format!("0x{}", hex(&bytes)))
+    }
+
+    // Corps JSON de POST /order, au format des clients officiels
+    pub fn payload(&self, signature: &str, owner: &str, order_type: &str) -> Value {
+        json!({
+            "order": {
+                "salt": self.salt,
+                "maker": self.maker.to_checksum(None),
+                "signer": self.signer.to_checksum(None),
+                "taker": self.taker.to_checksum(None),
+                "tokenId": self.token_id.to_string(),
+                "makerAmount": self.maker_amount.to_string(),
+                "takerAmount": self.taker_amount.to_string(),
+                "expiration": self.expiration.to_string(),
+                "nonce": self.nonce.to_string(),
+                "feeRateBps": self.fee_rate_bps.to_string(),
+                "side": self.side.as_str(),
+                "signatureType": self.signature_type.code(),
+                "signature": signature,
+            },
+            "owner": owner,
+            "orderType": order_type,
+        })
+    }
+}
+
+// Clé privée hexadécimale (0x facultatif) et adresse qui en dérive
+pub fn signer_key(private_key: &str) -> Result<(SigningKey, Address), String> {
+    let trimmed = private_key.trim();
+    let raw = trimmed.strip_prefix("0x").unwrap_or(trimmed);
+    let bytes = unhex(raw).filter(|bytes| bytes.len() == 32)
+        .ok_or_else(|| "PRIVATE_KEY doit faire 32 octets hexadécimaux".to_string())?;
+    let key = SigningKey::from_slice(&bytes).map_err(|_| "PRIVATE_KEY n'est pas une clé secp256k1 valide".to_string())?;
+    let address = address_of(key.verifying_key());
+    Ok((key, address))
+}
+
+// Adresse Ethereum d'une clé publique: 20 derniers octets du keccak256 du point non compressé
+pub fn address_of(key: &VerifyingKey) -> Address {
+    let point = key.to_encoded_point(false);
+    Address::from_slice(&keccak256(&point.as_bytes()[1..])[12..])
+}
+
+// Identifiants API du CLOB (niveau L2), dérivés une fois de la clé et fournis par l'environnement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiCredentials {
+    pub key: String,
+    pub secret: String, // Base64 URL-safe
+    pub passphrase: String,
+}
+
+impl ApiCredentials {
+    // POLY_API_KEY, POLY_API_SECRET, POLY_PASSPHRASE; None si l'un manque
+    pub fn from_env() -> Option<Self> {
+        let read = |name: &str| env::var(name).ok().filter(|value| !value.trim().is_empty());
+        Some(ApiCredentials {
+            key: read("POLY_API_KEY")?,
+            secret: read("POLY_API_SECRET")?,
+            passphrase: read("POLY_PASSPHRASE")?,
+        })
+    }
+}
+
+// Clé de signature et identifiants L2 du compte: de quoi construire les requêtes authentifiées
+pub struct OrderSigner {
+    key: SigningKey,
+    pub signer: Address,
+    pub maker: Address, // WALLET_ADDRESS si les fonds sont sur un proxy, sinon le signataire
+    credentials: ApiCredentials,
+}
+
+impl OrderSigner {
+    pub fn new(private_key: &str, wallet: Option<Address>, credentials: Option<ApiCredentials>) -> Result<Self, String> {
+        let (key, signer) = signer_key(private_key)?;
+        let credentials = credentials
+            .ok_or_else(|| "identifiants API CLOB incomplets (POLY_API_KEY, POLY_API_SECRET, POLY_PASSPHRASE)".to_string())?;
+        Ok(OrderSigner { key, signer, maker: wallet.unwrap_or(signer), credentials })
+    }
+
+    // Corps signé et en-têtes L2 de la requête POST /order, horodatés à `timestamp`
+    pub fn order_request(&self, order: &ClobOrder, order_type: &str, timestamp: i64)
+        -> Result<(Value, Headers), String> {
+        let signature = order.sign(&self.key)?;
+        let body = order.payload(&signature, &self.credentials.key, order_type);
+        let headers = l2_headers(&self.credentials, self.signer, timestamp, "POST", ORDER_PATH, &body.to_string())?;
+        Ok((body, headers))
+    }
+
+    // En-têtes L2 d'une requête sans corps (annulations)
+    pub fn headers(&self, timestamp: i64, method: &str, path: &str) -> Result<Headers, String> {
+        l2_headers(&self.credentials, self.signer, timestamp, method, path, "")
+    }
+}
+
+// En-têtes L2: HMAC-SHA256 de timestamp ‖ méthode ‖ chemin ‖ corps, clé = secret décodé
+pub fn l2_headers(credentials: &ApiCredentials, address: Address, timestamp: i64, method: &str, path: &str, body: &str)
+    -> Result<Headers, String> {
+    let secret = base64_decode(&credentials.secret).ok_or_else(|| "POLY_API_SECRET n'est pas du base64".to_string())?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(&secret).expect("HMAC accepte toute taille de clé");
+    mac.update(format!("{}{}{}{}", timestamp, method, path, body).as_bytes());
+    let signature = base64_url_encode(&mac.finalize().into_bytes());
+    Ok(vec![
+        ("POLY_ADDRESS", address.to_checksum(None)),
+        ("POLY_SIGNATURE", signature),
+        ("POLY_TIMESTAMP", timestamp.to_string()),
+        ("POLY_API_KEY", credentials.key.clone()),
+        ("POLY_PASSPHRASE", credentials.passphrase.clone()),
+    ])
+}
+
+// Solde USDC d'une réponse /balance-allowance: unités à 6 décimales, en chaîne ou en nombre
+pub fn collateral_balance(response: &Value) -> Result<f64, String> {
+    let units = match &response["balance"] {
+        Value::String(raw) => raw.trim().parse::<f64>().ok(),
+        Value::Number(n) => n.as_f64(),
+        _ => None,
+    };
+    units.filter(|units| units.is_finite() && *units >= 0.0)
+        .map(|units| units / AMOUNT_DECIMALS)
+        .ok_or_else(|| format!("solde USDC illisible: {}", response))
+}
+
+fn truncate(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    // Tolérance d'arrondi flottant: 0.29999999 reste 0.30
+    ((value * factor) + 1e-9).floor() / factor
+}
+
+fn to_units(amount: f64) -> u64 {
+    (amount * AMOUNT_DECIMALS).round() as u64
+}
+
+fn address_word(address: Address) -> U256 {
+    U256::from_be_slice(address.as_slice())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn unhex(raw: &str) -> Option<Vec<u8>> {
+    if !raw.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..raw.len()).step_by(2).map(|i| u8::from_str_radix(raw.get(i..i + 2)?, 16).ok()).collect()
+}
+
+const BASE64_URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// Base64 URL-safe avec remplissage, comme le secret et la signature L2 du CLOB
+pub fn base64_url_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let block = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_URL_ALPHABET[(block >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+// Décodage base64 standard ou URL-safe, remplissage facultatif
+pub fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bits = 0u32;
+    let mut count = 0;
+    let mut decoded = Vec::with_capacity(encoded.len() * 3 / 4);
+    for c in encoded.trim().trim_end_matches('=').bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+        bits = (bits << 6) | value as u32;
+        count += 6;
+        if count >= 8 {
+            count -= 8;
+            decoded.push((bits >> count) as u8);
+        }
+    }
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, B256};
+    use hmac::{Hmac, Mac};
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+    use sha2::Sha256;
+
+    // Clé de test bien connue (compte #0 des nœuds de développement), jamais financée
+    const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    const ADDRESS: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+    const API_SECRET: &str = "c2hhZG93LXJlYWwtdGVzdC1zZWNyZXQ=";
+
+    // Adresse qui a signé `digest`, d'après une signature r ‖ s ‖ v en hexadécimal
+    fn recover(digest: B256, signature: &str) -> Address {
+        let raw = signature.strip_prefix("0x").expect("signature préfixée 0x");
+        assert_eq!(raw.len(), 130, "65 octets attendus: {}", signature);
+        let bytes: Vec<u8> = (0..raw.len()).step_by(2).map(|i| u8::from_str_radix(&raw[i..i + 2], 16).unwrap()).collect();
+        assert!(bytes[64] == 27 || bytes[64] == 28, "v = {}", bytes[64]);
+        let signature = Signature::from_slice(&bytes[..64]).expect("r ‖ s");
+        let recovery_id = RecoveryId::from_byte(bytes[64] - 27).unwrap();
+        address_of(&VerifyingKey::recover_from_prehash(digest.as_slice(), &signature, recovery_id).expect("récupération"))
+    }
+
+    #[test]
+    fn orders_are_signed_by_the_configured_key() {
+        let (key, signer) = signer_key(PRIVATE_KEY).unwrap();
+        assert_eq!(signer.to_checksum(None), ADDRESS);
+        assert!(signer_key("0x1234").is_err());
+
+        // BUY 10 USDC à 0.40: 25 tokens pour 10 USDC, montants en 6 décimales
+        let buy = ClobOrder::limit("1234567890123456789012345678901234567890", ClobSide::Buy, 0.40, 10.0,
+                                   signer, signer, 0, 1_900_000_000, 42, false).unwrap();
+        assert_eq!((buy.maker_amount, buy.taker_amount), (10_000_000, 25_000_000));
+        let signature = buy.sign(&key).unwrap();
+        assert_eq!(recover(buy.signing_digest(), &signature), signer);
+
+        // SELL: tokens donnés, USDC reçus; un marché neg-risk change le contrat du domaine
+        let sell = ClobOrder::limit("77", ClobSide::Sell, 0.25, 5.0, signer, signer, 200, 0, 7, true).unwrap();
+        assert_eq!((sell.maker_amount, sell.taker_amount), (20_000_000, 5_000_000));
+        let neg_risk_digest = sell.signing_digest();
+        assert_ne!(neg_risk_digest, ClobOrder { neg_risk: false, ..sell.clone() }.signing_digest());
+        assert_eq!(recover(neg_risk_digest, &sell.sign(&key).unwrap()), signer);
+
+        let payload = buy.payload(&signature, "api-key", "GTD");
+        assert_eq!(payload["owner"], "api-key");
+        assert_eq!(payload["orderType"], "GTD");
+        assert_eq!(payload["order"]["side"], "BUY");
+        assert_eq!(payload["order"]["tokenId"], "1234567890123456789012345678901234567890");
+        assert_eq!(payload["order"]["makerAmount"], "10000000");
+        assert_eq!(payload["order"]["signatureType"], 0);
+        assert_eq!(payload["order"]["signature"], signature);
+
+        // Token non numérique ou prix hors de ]0, 1[: pas d'ordre
+        assert!(ClobOrder::limit("fx-yes", ClobSide::Buy, 0.40, 10.0, signer, signer, 0, 0, 1, false).is_err());
+        assert!(ClobOrder::limit("1", ClobSide::Buy, 1.0, 10.0, signer, signer, 0, 0, 1, false).is_err());
+    }
+
+    #[test]
+    fn the_collateral_balance_is_read_in_usdc_units() {
+        assert_eq!(collateral_balance(&serde_json::json!({"balance": "1234500000", "allowance": "0"})), Ok(1234.5));
+        assert_eq!(collateral_balance(&serde_json::json!({"balance": 2500000})), Ok(2.5));
+        assert!(collateral_balance(&serde_json::json!({"balance": "-1"})).is_err());
+        assert!(collateral_balance(&serde_json::json!({"error": "Unauthorized"})).is_err());
+    }
+
+    #[test]
+    fn l2_headers_carry_an_hmac_of_the_request() {
+        let bytes: Vec<u8> = (0u8..=255).collect();
+        assert_eq!(base64_decode(&base64_url_encode(&bytes)).unwrap(), bytes);
+        assert_eq!(base64_url_encode(b"ab"), "YWI=");
+
+        let credentials = ApiCredentials { key: "api-key".into(), secret: API_SECRET.into(), passphrase: "pass".into() };
+        let signer: Address = ADDRESS.parse().unwrap();
+        let headers = l2_headers(&credentials, signer, 1_700_000_000, "POST", "/order", "{\"a\":1}").unwrap();
+        let header = |name: &str| headers.iter().find(|(n, _)| *n == name).map(|(_, v)| v.clone()).unwrap();
+        assert_eq!(header("POLY_ADDRESS"), ADDRESS);
+        assert_eq!(header("POLY_TIMESTAMP"), "1700000000");
+        assert_eq!(header("POLY_API_KEY"), "api-key");
+        assert_eq!(header("POLY_PASSPHRASE"), "pass");
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"shadow-real-test-secret").unwrap();
+        mac.update(b"1700000000POST/order{\"a\":1}");
+        assert_eq!(header("POLY_SIGNATURE"), base64_url_encode(&mac.finalize().into_bytes()));
+    }
+}
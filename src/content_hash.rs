@@ -0,0 +1,102 @@
+// Empreinte du contenu des sources (--hash-sources)
+// Sans ETag ni Last-Modified, seule une empreinte du corps dit qu'une source n'a pas changé:
+// un corps identique au cycle précédent n'est pas reclassé (mots-clés, éléments du flux), le
+// résultat précédent est repris et seule la durée du fetch est actualisée par l'appelant.
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+// Empreinte du corps et de ce qui conditionne sa classification (type de contenu, mots-clés)
+pub fn content_hash(content: &str, content_type: Option<&str>, keywords: &[String]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    content_type.hash(&mut hasher);
+    keywords.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Debug)]
+pub struct ContentCache<T> {
+    entries: HashMap<String, (u64, T)>, // url -> (empreinte, dernière classification)
+    hits: u64,
+    misses: u64,
+}
+
+impl<T: Clone> ContentCache<T> {
+    pub fn new() -> Self {
+        Self { entries: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    // Classification précédente de l'URL si son empreinte n'a pas changé
+    pub fn lookup(&mut self, url: &str, hash: u64) -> Option<T> {
+        match self.entries.get(url) {
+            Some((cached_hash, result)) if *cached_hash == hash => {
+                self.hits += 1;
+                Some(result.clone())
+            }
+            _ => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    // Mémoriser la classification d'un corps, en remplacement de la précédente
+    pub fn store(&mut self, url: &str, hash: u64, result: T) {
+        self.entries.insert(url.to_string(), (hash, result));
+    }
+
+    // (classifications reprises, corps classés)
+    pub fn stats(&self) -> (u64, u64) {
+        (self.hits, self.misses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://feeds.example.com/rss";
+
+    #[test]
+    fn an_unchanged_body_skips_reclassification_and_a_changed_one_reruns_it() {
+        let keywords = vec!["approval".to_string(), "etf".to_string()];
+        let mut cache = ContentCache::new();
+        let mut classifications = 0;
+        let mut classify = |cache: &mut ContentCache<String>, body: &str| {
+            let hash = content_hash(body, Some("application/rss+xml"), &keywords);
+            if let Some(result) = cache.lookup(URL, hash) {
+                return result;
+            }
+            classifications += 1;
+            let result = format!("classé: {}", body);
+            cache.store(URL, hash, result.clone());
+            result
+        };
+
+        assert_eq!(classify(&mut cache, "<rss>ETF approval</rss>"), "classé: <rss>ETF approval</rss>");
+        // Cycle suivant, même corps: résultat repris sans reclassification
+        assert_eq!(classify(&mut cache, "<rss>ETF approval</rss>"), "classé: <rss>ETF approval</rss>");
+        // Corps modifié: reclassé, puis repris à son tour
+        assert_eq!(classify(&mut cache, "<rss>ETF rejected</rss>"), "classé: <rss>ETF rejected</rss>");
+        assert_eq!(classify(&mut cache, "<rss>ETF rejected</rss>"), "classé: <rss>ETF rejected</rss>");
+        assert_eq!(classifications, 2);
+        assert_eq!(cache.stats(), (2, 2));
+    }
+
+    #[test]
+    fn the_hash_covers_what_drives_classification() {
+        let keywords = vec!["approval".to_string()];
+        let hash = content_hash("body", Some("application/json"), &keywords);
+        assert_eq!(hash, content_hash("body", Some("application/json"), &keywords));
+        assert_ne!(hash, content_hash("body ", Some("application/json"), &keywords));
+        assert_ne!(hash, content_hash("body", Some("text/html"), &keywords));
+        assert_ne!(hash, content_hash("body", Some("application/json"), &["rejection".to_string()]));
+
+        // Même empreinte sous une autre URL: pas de résultat repris d'une source à l'autre
+        let mut cache = ContentCache::new();
+        cache.store(URL, hash, 1);
+        assert_eq!(cache.lookup("https://other.example.com/rss", hash), None);
+        assert_eq!(cache.lookup(URL, hash), Some(1));
+    }
+}
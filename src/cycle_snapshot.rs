@@ -0,0 +1,166 @@
+// Instantanés de cycle (--snapshot-dir) et leur comparaison (--diff-cycles)
+// Un instantané résume ce qui a piloté les décisions d'un cycle: prix des marchés, statut des
+// sources, opportunités et action de chaque signal. Comparer deux cycles répond à "qu'est-ce qui
+// a changé pour que le bot se comporte autrement" sans relire les journaux.
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+// Écart de prix en dessous duquel un marché est considéré inchangé
+pub const PRICE_EPSILON: f64 = 1e-9;
+// Répertoire lu par --diff-cycles sans --snapshot-dir
+pub const DEFAULT_SNAPSHOT_DIR: &str = "cycle_snapshots";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct OpportunityRef {
+    pub market_id: String,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CycleSnapshot {
+    pub cycle: u64,
+    pub cycle_id: String,
+    pub markets: BTreeMap<String, f64>,      // Id du marché -> prix (probabilité)
+    pub sources: BTreeMap<String, String>,   // URL -> statut
+    pub opportunities: BTreeSet<OpportunityRef>,
+    pub signals: BTreeMap<String, String>,   // "<marché> <- <source>" -> action
+}
+
+impl CycleSnapshot {
+    pub fn signal_key(market_id: &str, source: &str) -> String {
+        format!("{} <- {}", market_id, source)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| format!("Instantané {}: {}", path.display(), e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Instantané {}: JSON invalide: {}", path.display(), e))
+    }
+
+    // <dir>/cycle-<n>.json, remplacé au cycle de même numéro d'un run suivant
+    pub fn write(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = snapshot_path(dir, self.cycle);
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(path)
+    }
+}
+
+pub fn snapshot_path(dir: &Path, cycle: u64) -> PathBuf {
+    dir.join(format!("cycle-{}.json", cycle))
+}
+
+// Argument de --diff-cycles: numéro de cycle dans `dir` ou chemin d'un instantané
+pub fn resolve(dir: &Path, arg: &str) -> PathBuf {
+    match arg.parse::<u64>() {
+        Ok(cycle) => snapshot_path(dir, cycle),
+        Err(_) => PathBuf::from(arg),
+    }
+}
+
+// Valeur avant / après; None = absent de l'instantané
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change<T> {
+    pub key: String,
+    pub before: Option<T>,
+    pub after: Option<T>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotDiff {
+    pub prices: Vec<Change<f64>>,
+    pub sources: Vec<Change<String>>,
+    pub opportunities_added: Vec<OpportunityRef>,
+    pub opportunities_removed: Vec<OpportunityRef>,
+    pub signals: Vec<Change<String>>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.prices.is_empty() && self.sources.is_empty() && self.opportunities_added.is_empty()
+            && self.opportunities_removed.is_empty() && self.signals.is_empty()
+    }
+
+    // Une ligne par différence, regroupées par section
+    pub fn render(&self) -> Vec<String> {
+        let show = |value: Option<String>| value.unwrap_or_else(|| "absent".to_string());
+        let mut lines = Vec::new();
+        for change in &self.prices {
+            lines.push(format!("[PRIX] {}: {} -> {}", change.key,
+                show(change.before.map(|p| format!("{:.4}", p))), show(change.after.map(|p| format!("{:.4}", p)))));
+        }
+        for change in &self.sources {
+            lines.push(format!("[SOURCE] {}: {} -> {}", change.key, show(change.before.clone()), show(change.after.clone())));
+        }
+        for opportunity in &self.opportunities_added {
+            lines.push(format!("[OPPORTUNITÉ] + {} <- {}", opportunity.market_id, opportunity.source));
+        }
+        for opportunity in &self.opportunities_removed {
+            lines.push(format!("[OPPORTUNITÉ] - {} <- {}", opportunity.market_id, opportunity.source));
+        }
+        for change in &self.signals {
+            lines.push(format!("[SIGNAL] {}: {} -> {}", change.key, show(change.before.clone()), show(change.after.clone())));
+        }
+        lines
+    }
+}
+
+// Clés des deux tables, changements seulement (ordre des clés)
+fn map_changes<T: Clone>(a: &BTreeMap<String, T>, b: &BTreeMap<String, T>, same: impl Fn(&T, &T) -> bool) -> Vec<Change<T>> {
+    let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+    keys.into_iter().filter_map(|key| {
+        let (before, after) = (a.get(key), b.get(key));
+        let unchanged = matches!((before, after), (Some(x), Some(y)) if same(x, y));
+        (!unchanged).then(|| Change { key: key.clone(), before: before.cloned(), after: after.cloned() })
+    }).collect()
+}
+
+pub fn diff_snapshots(a: &CycleSnapshot, b: &CycleSnapshot) -> SnapshotDiff {
+    SnapshotDiff {
+        prices: map_changes(&a.markets, &b.markets, |x, y| (x - y).abs() <= PRICE_EPSILON),
+        sources: map_changes(&a.sources, &b.sources, |x, y| x == y),
+        opportunities_added: b.opportunities.difference(&a.opportunities).cloned().collect(),
+        opportunities_removed: a.opportunities.difference(&b.opportunities).cloned().collect(),
+        signals: map_changes(&a.signals, &b.signals, |x, y| x == y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(cycle: u64, prices: &[(&str, f64)], signals: &[(&str, &str, &str)]) -> CycleSnapshot {
+        CycleSnapshot {
+            cycle,
+            cycle_id: format!("18f2a-{}", cycle),
+            markets: prices.iter().map(|(id, price)| (id.to_string(), *price)).collect(),
+            sources: [("https://www.sec.gov/news/pressreleases.rss".to_string(), "success".to_string())].into_iter().collect(),
+            opportunities: [OpportunityRef { market_id: "fx-crypto-1".to_string(), source: "https://www.sec.gov/news/pressreleases.rss".to_string() }]
+                .into_iter().collect(),
+            signals: signals.iter()
+                .map(|(market_id, source, action)| (CycleSnapshot::signal_key(market_id, source), action.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn reports_exactly_the_changed_price_and_the_new_signal() {
+        let sec = "https://www.sec.gov/news/pressreleases.rss";
+        let before = snapshot(1, &[("fx-crypto-1", 0.35), ("fx-politics-1", 0.42)], &[("fx-crypto-1", sec, "BUY")]);
+        let after = snapshot(2, &[("fx-crypto-1", 0.35), ("fx-politics-1", 0.47)], &[("fx-crypto-1", sec, "BUY"), ("fx-politics-1", sec, "SELL")]);
+
+        let diff = diff_snapshots(&before, &after);
+        assert_eq!(diff.prices, vec![Change { key: "fx-politics-1".to_string(), before: Some(0.42), after: Some(0.47) }]);
+        assert_eq!(diff.signals, vec![Change {
+            key: CycleSnapshot::signal_key("fx-politics-1", sec),
+            before: None,
+            after: Some("SELL".to_string()),
+        }]);
+        assert!(diff.sources.is_empty() && diff.opportunities_added.is_empty() && diff.opportunities_removed.is_empty());
+        assert_eq!(diff.render(), vec![
+            "[PRIX] fx-politics-1: 0.4200 -> 0.4700".to_string(),
+            format!("[SIGNAL] fx-politics-1 <- {}: absent -> SELL", sec),
+        ]);
+        assert!(diff_snapshots(&after, &after).is_empty());
+    }
+}
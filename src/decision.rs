@@ -0,0 +1,103 @@
+// Politique de décision de trading, appliquée après le calcul du ROI attendu
+// Remplace les seuils codés en dur dans le core C++ (make_trading_decision_hft):
+// les valeurs par défaut sont les mêmes, mais restent visibles et réglables.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Buy,
+    Sell,
+    Monitor,
+}
+
+impl Decision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Decision::Buy => "BUY",
+            Decision::Sell => "SELL",
+            Decision::Monitor => "MONITOR",
+        }
+    }
+
+    // Décision lue dans l'action d'un signal, casse ignorée ("BUY", "buy"); None hors BUY/SELL/MONITOR
+    pub fn parse(action: &str) -> Option<Decision> {
+        [Decision::Buy, Decision::Sell, Decision::Monitor].into_iter()
+            .find(|decision| decision.as_str().eq_ignore_ascii_case(action.trim()))
+    }
+
+    // BUY et SELL passent un ordre, MONITOR non
+    pub fn is_trade(&self) -> bool {
+        *self != Decision::Monitor
+    }
+}
+
+// Seuils stricts: BUY si ROI > buy_min_roi et confiance > buy_min_confidence,
+// sinon SELL avec les seuils sell_*, sinon MONITOR (confiance = score de pertinence 0-1)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecisionPolicy {
+    pub buy_min_roi: f64,
+    pub buy_min_confidence: f64,
+    pub sell_min_roi: f64,
+    pub sell_min_confidence: f64,
+}
+
+impl Default for DecisionPolicy {
+    fn default() -> Self {
+        Self { buy_min_roi: 0.02, buy_min_confidence: 0.4, sell_min_roi: 0.015, sell_min_confidence: 0.35 }
+    }
+}
+
+impl DecisionPolicy {
+    pub fn decide(&self, roi: f64, confidence: f64) -> Decision {
+        if roi > self.buy_min_roi && confidence > self.buy_min_confidence {
+            Decision::Buy
+        } else if roi > self.sell_min_roi && confidence > self.sell_min_confidence {
+            Decision::Sell
+        } else {
+            Decision::Monitor
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        format!("BUY si ROI > {:.1}% et confiance > {:.2} | SELL si ROI > {:.1}% et confiance > {:.2}",
+                self.buy_min_roi * 100.0, self.buy_min_confidence, self.sell_min_roi * 100.0, self.sell_min_confidence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_thresholds_match_the_former_core_decision() {
+        let policy = DecisionPolicy::default();
+        assert_eq!(policy.decide(0.03, 0.5), Decision::Buy);
+        assert_eq!(policy.decide(0.018, 0.5), Decision::Sell);
+        assert_eq!(policy.decide(0.03, 0.38), Decision::Sell);
+        assert_eq!(policy.decide(0.01, 0.9), Decision::Monitor);
+        assert_eq!(policy.decide(0.02, 0.9), Decision::Sell, "seuil BUY strict");
+    }
+
+    #[test]
+    fn buy_threshold_decides_between_buy_and_monitor() {
+        let roi = 0.012;
+        let confidence = 0.8;
+        let default = DecisionPolicy::default();
+        assert_eq!(default.decide(roi, confidence), Decision::Monitor);
+
+        let eager = DecisionPolicy { buy_min_roi: 0.01, ..default };
+        assert_eq!(eager.decide(roi, confidence), Decision::Buy);
+
+        let strict = DecisionPolicy { buy_min_roi: 0.5, sell_min_roi: 0.5, ..default };
+        assert_eq!(strict.decide(0.3, confidence), Decision::Monitor);
+    }
+
+    #[test]
+    fn actions_parse_whatever_their_case() {
+        assert_eq!(Decision::parse("BUY"), Some(Decision::Buy));
+        assert_eq!(Decision::parse("sell"), Some(Decision::Sell));
+        assert_eq!(Decision::parse("Monitor"), Some(Decision::Monitor));
+        assert_eq!(Decision::parse("ignore"), None);
+        assert!(Decision::parse("buy").is_some_and(|decision| decision.is_trade()));
+        assert!(!Decision::Monitor.is_trade());
+    }
+}
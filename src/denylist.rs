@@ -0,0 +1,134 @@
+// Marchés toujours ignorés: --ignore-markets et fichier de denylist persistant
+// Veto explicite par marché, distinct des filtres de domaine: un marché refusé est retiré de tout
+// le pipeline dès la récupération des marchés. Un marché qui déclenche les garde-fous (prix
+// aberrant, données absentes, impact de l'ordre) `trip_limit` fois est ajouté au fichier.
+// Format du fichier: un identifiant par ligne, raison facultative après '#'.
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_DENYLIST_FILE: &str = "market_denylist.txt";
+
+#[derive(Debug, Default)]
+pub struct Denylist {
+    entries: BTreeMap<String, String>, // market_id -> raison
+    ignored: HashSet<String>,          // --ignore-markets: valable pour cette exécution, jamais écrit
+    path: Option<PathBuf>,             // Fichier persistant, None = en mémoire seulement
+    trip_limit: u32,                   // Déclenchements avant ajout automatique, 0 = jamais
+    trips: HashMap<String, u32>,
+    announced: HashSet<String>,
+}
+
+// Lignes vides et commentaires ignorés; "id # raison"
+pub fn parse(content: &str) -> BTreeMap<String, String> {
+    content.lines()
+        .filter_map(|line| {
+            let (id, reason) = line.split_once('#').unwrap_or((line, ""));
+            let id = id.trim();
+            (!id.is_empty()).then(|| (id.to_string(), reason.trim().to_string()))
+        })
+        .collect()
+}
+
+impl Denylist {
+    pub fn new(trip_limit: u32) -> Self {
+        Denylist { trip_limit, ..Default::default() }
+    }
+
+    // Charger le fichier persistant (absent = denylist vide, créée au premier ajout)
+    pub fn load(path: &Path, trip_limit: u32) -> Result<Self, String> {
+        let entries = match std::fs::read_to_string(path) {
+            Ok(content) => parse(&content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(format!("Denylist {}: {}", path.display(), e)),
+        };
+        Ok(Denylist { entries, path: Some(path.to_path_buf()), trip_limit, ..Default::default() })
+    }
+
+    // Veto pour cette exécution seulement (--ignore-markets)
+    pub fn ignore(&mut self, market_id: &str) {
+        if !self.entries.contains_key(market_id) {
+            self.entries.insert(market_id.to_string(), "--ignore-markets".to_string());
+            self.ignored.insert(market_id.to_string());
+        }
+    }
+
+    pub fn is_denied(&self, market_id: &str) -> bool {
+        self.entries.contains_key(market_id)
+    }
+
+    pub fn reason(&self, market_id: &str) -> Option<&str> {
+        self.entries.get(market_id).map(String::as_str)
+    }
+
+    // Vrai la première fois seulement: chaque marché refusé n'est journalisé qu'une fois
+    pub fn announce(&mut self, market_id: &str) -> bool {
+        self.announced.insert(market_id.to_string())
+    }
+
+    // Compter un déclenchement de garde-fou, vrai quand le marché vient d'être ajouté
+    pub fn record_trip(&mut self, market_id: &str, guard: &str) -> bool {
+        if self.trip_limit == 0 || self.is_denied(market_id) {
+            return false;
+        }
+        let trips = self.trips.entry(market_id.to_string()).or_insert(0);
+        *trips += 1;
+        if *trips < self.trip_limit {
+            return false;
+        }
+        let reason = format!("auto: garde-fou {} déclenché {} fois", guard, trips);
+        self.entries.insert(market_id.to_string(), reason);
+        true
+    }
+
+    pub fn trips(&self, market_id: &str) -> u32 {
+        self.trips.get(market_id).copied().unwrap_or(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Réécrire le fichier persistant (sans les marchés de --ignore-markets)
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut content = String::from("# Marchés toujours ignorés par le bot: un identifiant par ligne, raison après '#'\n");
+        for (market_id, reason) in self.entries.iter().filter(|(id, _)| !self.ignored.contains(*id)) {
+            if reason.is_empty() {
+                content.push_str(&format!("{}\n", market_id));
+            } else {
+                content.push_str(&format!("{} # {}\n", market_id, reason));
+            }
+        }
+        std::fs::write(path, content).map_err(|e| format!("Écriture de la denylist {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trips_below_the_limit_do_not_deny() {
+        let mut denylist = Denylist::new(3);
+        assert!(!denylist.record_trip("m", "prix"));
+        assert!(!denylist.record_trip("m", "impact"));
+        assert!(!denylist.is_denied("m"));
+        assert!(denylist.record_trip("m", "prix"), "troisième déclenchement: ajout");
+        assert!(denylist.is_denied("m"));
+        assert!(!denylist.record_trip("m", "prix"), "déjà refusé");
+
+        let mut disabled = Denylist::new(0);
+        assert!((0..10).all(|_| !disabled.record_trip("m", "prix")), "GUARD_TRIP_LIMIT=0: jamais d'ajout");
+
+        let parsed = parse("# commentaire\n\nfx-1 # manipulé\n  fx-2  \n");
+        assert_eq!(parsed.get("fx-1").map(String::as_str), Some("manipulé"));
+        assert_eq!(parsed.get("fx-2").map(String::as_str), Some(""));
+        assert_eq!(parsed.len(), 2);
+    }
+}
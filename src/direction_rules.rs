@@ -0,0 +1,128 @@
+// Règles information → sens du trade (--direction-rules)
+// Une règle dit si l'information d'une source favorise le YES (positive) ou le NO (negative)
+// d'un marché: motif sur l'id du marché, motif sur l'URL de la source, mot-clé facultatif.
+// Les règles par défaut reprennent les règles historiques; un fichier JSON les remplace.
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleDirection {
+    Positive, // Les annonces de la source favorisent YES
+    Negative, // Elles favorisent NO
+}
+
+// Motifs insensibles à la casse: "a|b" = a ou b, "a+b" = a et b, "*" = tout
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DirectionRule {
+    pub market_pattern: String, // Motif sur l'id du marché
+    pub source_pattern: String, // Motif sur l'URL de la source
+    #[serde(default)]
+    pub keyword: String,        // Mot-clé que la source doit contenir, vide = sans condition
+    pub direction: RuleDirection,
+    #[serde(default = "default_weight")]
+    pub weight: f64,            // Entre règles applicables, la plus lourde l'emporte (la première à égalité)
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+impl DirectionRule {
+    fn new(market_pattern: &str, source_pattern: &str, direction: RuleDirection) -> Self {
+        DirectionRule {
+            market_pattern: market_pattern.to_string(),
+            source_pattern: source_pattern.to_string(),
+            keyword: String::new(),
+            direction,
+            weight: default_weight(),
+        }
+    }
+
+    // Règle applicable à ce marché, cette source et ces mots-clés trouvés
+    pub fn matches(&self, market_id: &str, source_url: &str, found_keywords: &[(String, String)]) -> bool {
+        pattern_matches(&self.market_pattern, market_id)
+            && pattern_matches(&self.source_pattern, source_url)
+            && (self.keyword.trim().is_empty()
+                || found_keywords.iter().any(|(keyword, _)| keyword.eq_ignore_ascii_case(self.keyword.trim())))
+    }
+}
+
+fn pattern_matches(pattern: &str, text: &str) -> bool {
+    let text = text.to_lowercase();
+    pattern.split('|').any(|alternative| {
+        let alternative = alternative.trim();
+        alternative == "*" || alternative.split('+').all(|term| text.contains(&term.trim().to_lowercase()))
+    })
+}
+
+// Règles historiques, codées en dur avant --direction-rules
+pub fn default_rules() -> Vec<DirectionRule> {
+    use RuleDirection::{Negative, Positive};
+    vec![
+        // Politique: les sources politiques annoncent généralement en faveur du YES
+        DirectionRule::new("trump|election", "newsapi|polymarket", Positive),
+        // Crypto: la SEC annonce les approbations d'ETF, Polymarket reflète une probabilité basse
+        DirectionRule::new("etf+approved", "sec.gov", Positive),
+        DirectionRule::new("etf+approved", "polymarket", Negative),
+        // Économie: les annonces de la Fed vont contre une hausse et pour une baisse des taux
+        DirectionRule::new("fed+raise", "federalreserve|fred", Negative),
+        DirectionRule::new("fed+cut", "federalreserve|fred", Positive),
+    ]
+}
+
+// Sens retenu pour une source sur un marché (Some(true) = YES), None sans règle applicable
+pub fn evaluate(rules: &[DirectionRule], market_id: &str, source_url: &str, found_keywords: &[(String, String)]) -> Option<bool> {
+    rules.iter()
+        .filter(|rule| rule.matches(market_id, source_url, found_keywords))
+        .fold(None::<&DirectionRule>, |best, rule| match best {
+            Some(best) if best.weight >= rule.weight => Some(best),
+            _ => Some(rule),
+        })
+        .map(|rule| rule.direction == RuleDirection::Positive)
+}
+
+// Lire un fichier de règles: tableau JSON d'objets
+// {"market_pattern": "...", "source_pattern": "...", "keyword": "...", "direction": "positive|negative", "weight": 1.0}
+pub fn load(path: &str) -> Result<Vec<DirectionRule>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Lecture des règles de direction {}: {}", path, e))?;
+    let rules: Vec<DirectionRule> = serde_json::from_str(&content)
+        .map_err(|e| format!("{} invalide: {}", path, e))?;
+    for (index, rule) in rules.iter().enumerate() {
+        if rule.market_pattern.trim().is_empty() || rule.source_pattern.trim().is_empty() {
+            return Err(format!("{}: règle {}: motifs vides (\"*\" pour tout accepter)", path, index + 1));
+        }
+        if !rule.weight.is_finite() || rule.weight < 0.0 {
+            return Err(format!("{}: règle {}: poids invalide {}", path, index + 1, rule.weight));
+        }
+    }
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKET: &str = "fx-politics-1";
+
+    const NEWSAPI: &str = "https://newsapi.org/v2/everything?domains=whitehouse.gov,reuters.com,bbc.com&apiKey=";
+
+    fn keywords(names: &[&str]) -> Vec<(String, String)> {
+        names.iter().map(|name| (name.to_string(), "affirmed".to_string())).collect()
+    }
+
+    #[test]
+    fn default_rules_reproduce_the_builtin_directions() {
+        let rules = default_rules();
+        let none = keywords(&[]);
+        assert_eq!(evaluate(&rules, "trump-election-2024", NEWSAPI, &none), Some(true));
+        assert_eq!(evaluate(&rules, "eth-etf-approved", "https://www.sec.gov/news/pressreleases.rss", &none), Some(true));
+        assert_eq!(evaluate(&rules, "eth-etf-approved", "https://gamma-api.polymarket.com/markets", &none), Some(false));
+        assert_eq!(evaluate(&rules, "FED-RAISE-march", "https://www.federalreserve.gov/feeds/press_all.xml", &none), Some(false));
+        assert_eq!(evaluate(&rules, "fed-cut-march", "https://api.stlouisfed.org/fred/series", &none), Some(true));
+        // Un seul des deux termes "etf+approved": pas de règle
+        assert_eq!(evaluate(&rules, "eth-etf-listed", "https://www.sec.gov/news/pressreleases.rss", &none), None);
+        assert_eq!(evaluate(&rules, MARKET, NEWSAPI, &none), None);
+    }
+}
@@ -0,0 +1,105 @@
+// Écart entre le remplissage prévu par le modèle papier et le remplissage réel
+// En mode réel, chaque ordre est aussi rejoué sur le livre connu au moment du signal:
+// un écart de prix ou de taille au-delà des seuils indique un modèle mal calibré
+// ou un changement côté venue (frais, tick, profondeur).
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DivergenceThresholds {
+    pub price_bps: f64,     // Écart de prix moyen toléré, en points de base
+    pub size_fraction: f64, // Écart de taille rempli toléré, en fraction de la taille prévue
+}
+
+impl Default for DivergenceThresholds {
+    fn default() -> Self {
+        Self { price_bps: 100.0, size_fraction: 0.2 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillDivergence {
+    pub expected_price: Option<f64>, // Prix moyen du remplissage papier (None si rien rempli)
+    pub actual_price: Option<f64>,
+    pub expected_size: f64,          // Taille remplie, mêmes unités des deux côtés (USDC)
+    pub actual_size: f64,
+}
+
+impl FillDivergence {
+    pub fn new(expected_price: Option<f64>, actual_price: Option<f64>, expected_size: f64, actual_size: f64) -> Self {
+        Self { expected_price, actual_price, expected_size, actual_size }
+    }
+
+    // Écart de prix absolu en points de base du prix prévu
+    pub fn price_gap_bps(&self) -> Option<f64> {
+        let (expected, actual) = (self.expected_price?, self.actual_price?);
+        (expected > 0.0).then(|| (actual - expected).abs() / expected * 10_000.0)
+    }
+
+    // Écart de taille relatif à la taille prévue
+    pub fn size_gap_fraction(&self) -> Option<f64> {
+        (self.expected_size > 0.0).then(|| (self.actual_size - self.expected_size).abs() / self.expected_size)
+    }
+
+    // Dépassements de seuil, vide si le réel suit le modèle papier
+    pub fn breaches(&self, thresholds: &DivergenceThresholds) -> Vec<String> {
+        let mut breaches = Vec::new();
+        if let Some(gap) = self.price_gap_bps().filter(|gap| *gap > thresholds.price_bps) {
+            breaches.push(format!("prix {:.0} bps > {:.0} bps", gap, thresholds.price_bps));
+        }
+        if let Some(gap) = self.size_gap_fraction().filter(|gap| *gap > thresholds.size_fraction) {
+            breaches.push(format!("taille {:.0}% > {:.0}%", gap * 100.0, thresholds.size_fraction * 100.0));
+        }
+        breaches
+    }
+
+    pub fn describe(&self) -> String {
+        let price = |p: Option<f64>| p.map(|p| format!("{:.4}", p)).unwrap_or_else(|| "-".to_string());
+        format!("prix papier {} / réel {} | taille papier {:.4} / réelle {:.4}",
+                price(self.expected_price), price(self.actual_price), self.expected_size, self.actual_size)
+    }
+}
+
+// Moniteur papier vs réel: un remplissage réel qui s'écarte du remplissage papier au-delà des
+// seuils (100 bps sur le prix, 20% sur la taille par défaut) déclenche une alerte.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_matching_the_paper_model_raises_no_warning() {
+        // 0.500 prévu, 0.502 obtenu (40 bps), taille 98% de la prévision
+        let divergence = FillDivergence::new(Some(0.500), Some(0.502), 10.0, 9.8);
+        assert!(divergence.breaches(&DivergenceThresholds::default()).is_empty(), "{}", divergence.describe());
+    }
+
+    #[test]
+    fn price_beyond_threshold_emits_a_divergence_warning() {
+        // 0.500 prévu, 0.520 obtenu: 400 bps
+        let divergence = FillDivergence::new(Some(0.500), Some(0.520), 10.0, 10.0);
+        let breaches = divergence.breaches(&DivergenceThresholds::default());
+        assert_eq!(breaches.len(), 1, "{:?}", breaches);
+        assert!(breaches[0].starts_with("prix 400 bps"), "{:?}", breaches);
+    }
+
+    #[test]
+    fn partial_fill_beyond_threshold_emits_a_divergence_warning() {
+        // Le livre papier remplissait 10 USDC, le CLOB n'en a rempli que 6
+        let divergence = FillDivergence::new(Some(0.500), Some(0.500), 10.0, 6.0);
+        let breaches = divergence.breaches(&DivergenceThresholds::default());
+        assert_eq!(breaches, vec!["taille 40% > 20%".to_string()]);
+    }
+
+    #[test]
+    fn unfilled_real_order_is_a_size_divergence() {
+        let divergence = FillDivergence::new(Some(0.500), None, 10.0, 0.0);
+        let breaches = divergence.breaches(&DivergenceThresholds::default());
+        assert_eq!(breaches.len(), 1, "{:?}", breaches);
+        assert!(breaches[0].starts_with("taille 100%"), "{:?}", breaches);
+    }
+
+    #[test]
+    fn thresholds_are_configurable() {
+        let divergence = FillDivergence::new(Some(0.500), Some(0.520), 10.0, 10.0);
+        let loose = DivergenceThresholds { price_bps: 500.0, size_fraction: 0.2 };
+        assert!(divergence.breaches(&loose).is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+// Livre de repli de la simulation, quand le livre réel est indisponible
+// Déterministe et dérivé de la probabilité du marché: centré sur elle, spread d'un marché liquide
+// typique, profondeur décroissante en s'éloignant du meilleur prix. Jamais utilisé en mode réel,
+// où un livre indisponible fait ignorer le marché.
+
+pub const HALF_SPREAD: f64 = 0.01; // Spread de 2 cents autour de la probabilité
+pub const LEVEL_STEP: f64 = 0.01;  // Écart entre deux niveaux successifs
+pub const TOP_SIZE: f64 = 100.0;   // Tokens au meilleur niveau
+pub const DEPTH_DECAY: f64 = 0.6;  // Taille d'un niveau rapportée au précédent
+
+pub type Levels = Vec<(f64, f64)>; // (prix, taille), meilleurs prix en premier
+
+const MIN_PRICE: f64 = 0.01;
+const MAX_PRICE: f64 = 0.99;
+
+// (bids, asks) sur au plus `depth` niveaux par côté, meilleurs prix en premier
+// Les niveaux hors de [0.01, 0.99] sont omis plutôt que rabattus sur la borne
+pub fn fallback_book(probability: f64, depth: usize) -> (Levels, Levels) {
+    let mut bids = Vec::with_capacity(depth);
+    let mut asks = Vec::with_capacity(depth);
+    if !(probability > 0.0 && probability < 1.0) {
+        return (bids, asks);
+    }
+
+    for level in 0..depth {
+        let offset = HALF_SPREAD + LEVEL_STEP * level as f64;
+        let size = TOP_SIZE * DEPTH_DECAY.powi(level as i32);
+        let (bid, ask) = (probability - offset, probability + offset);
+        if bid >= MIN_PRICE - 1e-12 {
+            bids.push((bid, size));
+        }
+        if ask <= MAX_PRICE + 1e-12 {
+            asks.push((ask, size));
+        }
+    }
+
+    (bids, asks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+     // Livres fx-eth-yes / fx-eth-no retirés de la fixture
+
+    #[test]
+    fn the_fallback_book_is_centered_on_the_market_price() {
+        let (bids, asks) = fallback_book(0.62, 3);
+        assert_eq!((bids.len(), asks.len()), (3, 3));
+        let (best_bid, best_ask) = (bids[0].0, asks[0].0);
+        assert!(((best_bid + best_ask) / 2.0 - 0.62).abs() < 1e-12, "{:?} / {:?}", bids, asks);
+        assert!((best_ask - best_bid - 2.0 * HALF_SPREAD).abs() < 1e-12);
+
+        // Prix qui s'éloignent, profondeur qui décroît, symétrique des deux côtés
+        for side in [&bids, &asks] {
+            for pair in side.windows(2) {
+                assert!((pair[1].0 - 0.62).abs() > (pair[0].0 - 0.62).abs());
+                assert!((pair[1].1 - pair[0].1 * DEPTH_DECAY).abs() < 1e-9);
+            }
+        }
+        assert_eq!(bids.iter().map(|l| l.1).collect::<Vec<_>>(), asks.iter().map(|l| l.1).collect::<Vec<_>>());
+
+        // Déterministe, et sans niveau hors de ]0, 1[ près des bornes
+        assert_eq!(fallback_book(0.62, 3), (bids, asks));
+        let (bids, asks) = fallback_book(0.975, 3);
+        assert_eq!(asks.len(), 1);
+        assert!(bids.iter().chain(&asks).all(|(price, _)| *price >= 0.01 && *price <= 0.99));
+        assert_eq!(fallback_book(1.0, 3), (vec![], vec![]));
+    }
+}
@@ -0,0 +1,186 @@
+// Découpage d'une réponse de source en éléments (items RSS, entrées Atom, articles JSON)
+// pour savoir quel titre précis a déclenché une opportunité
+use regex::Regex;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+// Fenêtre de fraîcheur par défaut (--news-max-age): au-delà, un élément daté est ignoré
+pub const DEFAULT_NEWS_MAX_AGE_SECS: f64 = 900.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedItem {
+    pub title: String,
+    pub published_at: Option<String>,
+    pub link: Option<String>,
+    pub summary: String, // Description / contenu, utilisé seulement pour l'appariement
+}
+
+impl FeedItem {
+    // Texte de l'élément en minuscules (titre + résumé) pour l'appariement des mots-clés
+    pub fn search_text(&self) -> String {
+        format!("{}\n{}", self.title, self.summary).to_lowercase()
+    }
+
+    // Date de publication en secondes Unix: RFC 2822 (RSS), RFC 3339 (Atom, JSON) ou date seule
+    pub fn published_timestamp(&self) -> Option<f64> {
+        let date = self.published_at.as_deref()?.trim();
+        chrono::DateTime::parse_from_rfc2822(date)
+            .or_else(|_| chrono::DateTime::parse_from_rfc3339(date))
+            .map(|date| date.timestamp() as f64)
+            .ok()
+            .or_else(|| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+                .and_then(|day| day.and_hms_opt(0, 0, 0))
+                .map(|day| day.and_utc().timestamp() as f64))
+    }
+
+    // Âge de l'élément en secondes, None sans date lisible
+    pub fn age_secs(&self, now: f64) -> Option<f64> {
+        self.published_timestamp().map(|published| (now - published).max(0.0))
+    }
+
+    // Forme courte pour les logs: « titre » (date) lien
+    pub fn describe(&self) -> String {
+        let mut text = format!("« {} »", self.title);
+        if let Some(published_at) = &self.published_at {
+            text.push_str(&format!(" ({})", published_at));
+        }
+        if let Some(link) = &self.link {
+            text.push_str(&format!(" {}", link));
+        }
+        text
+    }
+}
+
+// Éléments d'une réponse: JSON selon le Content-Type, sinon RSS/Atom
+pub fn parse_items(content: &str, content_type: Option<&str>) -> Vec<FeedItem> {
+    let is_json = content_type.is_some_and(|ct| ct.to_lowercase().contains("json"));
+    if is_json {
+        if let Ok(json) = serde_json::from_str::<Value>(content) {
+            let mut items = Vec::new();
+            collect_json_items(&json, &mut items);
+            return items;
+        }
+    }
+    parse_xml_items(content)
+}
+
+fn xml_patterns() -> &'static (Regex, Regex) {
+    static PATTERNS: OnceLock<(Regex, Regex)> = OnceLock::new();
+    PATTERNS.get_or_init(|| (
+        Regex::new(r"(?is)<(item|entry)\b[^>]*>(.*?)</(?:item|entry)>").unwrap(),
+        Regex::new(r#"(?is)<link\b[^>]*\bhref\s*=\s*["']([^"']+)["']"#).unwrap(),
+    ))
+}
+
+fn parse_xml_items(content: &str) -> Vec<FeedItem> {
+    let (item_pattern, href_pattern) = xml_patterns();
+    item_pattern.captures_iter(content)
+        .filter_map(|captures| {
+            let body = captures.get(2)?.as_str();
+            let title = xml_field(body, &["title"])?;
+            Some(FeedItem {
+                title,
+                published_at: xml_field(body, &["pubDate", "published", "updated", "dc:date"]),
+                // RSS: <link>url</link>, Atom: <link href="url"/>
+                link: xml_field(body, &["link"])
+                    .or_else(|| href_pattern.captures(body).map(|c| c[1].trim().to_string())),
+                summary: xml_field(body, &["description", "summary", "content"]).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+// Texte du premier champ présent parmi `tags`, CDATA et entités courantes décodés
+fn xml_field(body: &str, tags: &[&str]) -> Option<String> {
+    tags.iter().find_map(|tag| {
+        let pattern = Regex::new(&format!(r"(?is)<{0}\b[^>]*>(.*?)</{0}>", regex::escape(tag))).ok()?;
+        let raw = pattern.captures(body)?.get(1)?.as_str();
+        let text = decode_entities(raw.trim().trim_start_matches("<![CDATA[").trim_end_matches("]]>").trim());
+        if text.is_empty() { None } else { Some(text) }
+    })
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+// Tout objet JSON avec un titre texte est un élément (articles NewsAPI, listes d'annonces...)
+fn collect_json_items(value: &Value, items: &mut Vec<FeedItem>) {
+    match value {
+        Value::Array(values) => values.iter().for_each(|v| collect_json_items(v, items)),
+        Value::Object(object) => {
+            let text = |keys: &[&str]| keys.iter()
+                .find_map(|key| object.get(*key).and_then(Value::as_str))
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty());
+            match text(&["title", "headline"]) {
+                Some(title) => items.push(FeedItem {
+                    title,
+                    published_at: text(&["publishedAt", "published_at", "pubDate", "date", "updated"]),
+                    link: text(&["url", "link"]),
+                    summary: text(&["description", "summary", "content"]).unwrap_or_default(),
+                }),
+                None => object.values().for_each(|v| collect_json_items(v, items)),
+            }
+        }
+        _ => {}
+    }
+}
+
+// Éléments sans date lisible ou publiés depuis moins de max_age_secs (0 = pas de limite),
+// et nombre d'éléments écartés
+pub fn recent_items(items: Vec<FeedItem>, now: f64, max_age_secs: f64) -> (Vec<FeedItem>, usize) {
+    if max_age_secs <= 0.0 {
+        return (items, 0);
+    }
+    let total = items.len();
+    let recent: Vec<FeedItem> = items.into_iter()
+        .filter(|item| item.age_secs(now).is_none_or(|age| age <= max_age_secs))
+        .collect();
+    let dropped = total - recent.len();
+    (recent, dropped)
+}
+
+// Score d'un élément pour un marché: mots-clés trouvés dans la source (poids 2)
+// puis mots significatifs de la question, 0 si rien ne correspond
+fn match_score(item: &FeedItem, keywords: &[String], question_words: &[String]) -> usize {
+    let text = item.search_text();
+    let keyword_hits = keywords.iter().filter(|k| text.contains(&k.to_lowercase())).count();
+    let question_hits = question_words.iter().filter(|w| text.contains(w.as_str())).count();
+    keyword_hits * 2 + question_hits
+}
+
+fn question_words(question: &str) -> Vec<String> {
+    question.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.len() > 3)
+        .map(str::to_string)
+        .collect()
+}
+
+// Élément le plus proche d'un marché, None si aucun élément ne correspond
+pub fn best_matching_item<'a>(items: &'a [FeedItem], keywords: &[String], question: &str) -> Option<&'a FeedItem> {
+    let question_words = question_words(question);
+    let mut best: Option<(usize, &FeedItem)> = None;
+    for item in items {
+        let score = match_score(item, keywords, &question_words);
+        if score > 0 && best.is_none_or(|(best_score, _)| score > best_score) {
+            best = Some((score, item));
+        }
+    }
+    best.map(|(_, item)| item)
+}
+
+// Âge du plus récent élément daté qui correspond au marché
+pub fn freshest_match_age(items: &[FeedItem], keywords: &[String], question: &str, now: f64) -> Option<f64> {
+    let question_words = question_words(question);
+    items.iter()
+        .filter(|item| match_score(item, keywords, &question_words) > 0)
+        .filter_map(|item| item.age_secs(now))
+        .min_by(|a, b| a.total_cmp(b))
+}
@@ -0,0 +1,53 @@
+// Barèmes de frais Polymarket (maker/taker)
+// Le bot traverse le livre au best ask: ses ordres paient le taux taker.
+// Comme le reste du modèle de ROI, les frais s'appliquent sur le profit net.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeeSchedule {
+    pub maker_bps: f64,
+    pub taker_bps: f64,
+}
+
+impl FeeSchedule {
+    // Aucun frais (périodes promotionnelles de Polymarket)
+    pub const ZERO: FeeSchedule = FeeSchedule { maker_bps: 0.0, taker_bps: 0.0 };
+    // Maker gratuit, taker 2%
+    pub const STANDARD: FeeSchedule = FeeSchedule { maker_bps: 0.0, taker_bps: 200.0 };
+
+    // "zero", "standard" ou "custom:<maker_bps>:<taker_bps>"
+    pub fn parse(tier: &str) -> Result<Self, String> {
+        match tier.to_lowercase().as_str() {
+            "zero" => Ok(Self::ZERO),
+            "standard" => Ok(Self::STANDARD),
+            custom if custom.starts_with("custom:") => {
+                let rates: Vec<&str> = custom["custom:".len()..].split(':').collect();
+                let bps = |value: &str| {
+                    value.trim().parse::<f64>().ok().filter(|bps| bps.is_finite() && (0.0..=10_000.0).contains(bps))
+                };
+                match rates.as_slice() {
+                    [maker, taker] => match (bps(maker), bps(taker)) {
+                        (Some(maker_bps), Some(taker_bps)) => Ok(FeeSchedule { maker_bps, taker_bps }),
+                        _ => Err(format!("--fee-tier: taux en points de base (0-10000) attendus, reçu '{}'", tier)),
+                    },
+                    _ => Err(format!("--fee-tier: format custom:<maker_bps>:<taker_bps> attendu, reçu '{}'", tier)),
+                }
+            }
+            _ => Err(format!("--fee-tier: barème inconnu '{}' (attendu: zero, standard, custom:<maker_bps>:<taker_bps>)", tier)),
+        }
+    }
+
+    // Taux appliqué aux ordres qui prennent la liquidité (fraction du profit)
+    pub fn taker_fee(&self) -> f64 {
+        self.taker_bps / 10_000.0
+    }
+
+    pub fn describe(&self) -> String {
+        format!("maker {:.0} bps / taker {:.0} bps", self.maker_bps, self.taker_bps)
+    }
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
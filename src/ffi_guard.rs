@@ -0,0 +1,71 @@
+// Validation des f64 renvoyés par le core C++
+// Sur des entrées dégénérées, le core peut renvoyer NaN ou ±inf: la valeur ne doit jamais
+// atteindre les signaux, le dimensionnement ou le solde. Elle est remplacée par une valeur sûre.
+
+// Valeurs sûres: ROI nul (aucun signal d'achat), taille de position nulle (aucun ordre)
+pub const SAFE_ROI: f64 = 0.0;
+pub const SAFE_POSITION_SIZE: f64 = 0.0;
+
+// Résultat validé: la valeur utilisable, et l'avertissement à journaliser si elle a été remplacée
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checked {
+    pub value: f64,
+    pub warning: Option<String>,
+}
+
+pub fn finite_or(function: &str, value: f64, default: f64) -> Checked {
+    if value.is_finite() {
+        Checked { value, warning: None }
+    } else {
+        Checked {
+            value: default,
+            warning: Some(format!("{} a renvoyé {} - remplacé par {}", function, value, default)),
+        }
+    }
+}
+
+// Appel d'une fonction du core (ou de son équivalent Rust) suivi de la validation
+pub fn call_checked(function: &str, default: f64, call: impl FnOnce() -> f64) -> Checked {
+    finite_or(function, call(), default)
+}
+
+// Une valeur non finie (NaN, ±inf) est remplacée par une valeur sûre avant d'atteindre les signaux
+// ou le solde, et signalée par un avertissement.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Équivalent Rust du core: ROI « calculé » sur des entrées dégénérées (prix nul), 0/0
+    fn degenerate_roi(current_price: f64) -> f64 {
+        let payout = 1.0 - current_price;
+        (payout - (1.0 - current_price)) / current_price
+    }
+
+    #[test]
+    fn nan_from_the_core_is_sanitized() {
+        let checked = call_checked("calculate_roi_hft_cached", SAFE_ROI, || degenerate_roi(0.0));
+        assert_eq!(checked.value, SAFE_ROI);
+        let warning = checked.warning.expect("avertissement attendu");
+        assert!(warning.contains("calculate_roi_hft_cached a renvoyé NaN"), "{}", warning);
+
+        // Une mise dérivée du résultat validé reste un nombre: le solde n'est pas empoisonné
+        let balance = 100.0 - checked.value * 10.0;
+        assert_eq!(balance, 100.0);
+    }
+
+    #[test]
+    fn infinite_sizes_fall_back_to_zero() {
+        for value in [f64::INFINITY, f64::NEG_INFINITY] {
+            let checked = call_checked("calculate_position_size_hft", SAFE_POSITION_SIZE, || value);
+            assert_eq!(checked.value, 0.0);
+            assert!(checked.warning.is_some());
+        }
+    }
+
+    #[test]
+    fn finite_results_pass_through_unchanged() {
+        let checked = finite_or("calculate_roi_hft_cached", 0.042, SAFE_ROI);
+        assert_eq!(checked, Checked { value: 0.042, warning: None });
+        assert_eq!(finite_or("calculate_position_size_hft", -1.5, 0.0).value, -1.5);
+    }
+}
@@ -0,0 +1,127 @@
+// Données hors-ligne pour rejouer un cycle sans réseau (--fixture)
+// Marchés, contenus des sources et orderbooks sont lus depuis un fichier JSON,
+// ce qui rend un cycle de simulation reproductible (avec --seed)
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixtureMarket {
+    pub id: String,
+    pub question: String,
+    #[serde(default)]
+    pub description: String,
+    pub domain: String,
+    pub probability: f64,
+    #[serde(default)]
+    pub resolution_source: String,
+    #[serde(default)]
+    pub clob_token_ids: Vec<String>,
+    #[serde(default)]
+    pub liquidity: f64,
+    #[serde(default)]
+    pub end_date: Option<String>,
+    #[serde(default)]
+    pub condition_id: String,
+    #[serde(default, alias = "eventId")]
+    pub event_id: String,
+    #[serde(default = "order_book_by_default", alias = "enableOrderBook")]
+    pub enable_order_book: bool,
+    #[serde(default)]
+    pub outcomes: Vec<FixtureOutcome>, // Vide: marché binaire YES/NO au prix `probability`
+}
+
+// Issue d'un marché catégoriel, avec son token CLOB s'il est connu
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixtureOutcome {
+    pub name: String,
+    pub price: f64,
+    #[serde(default)]
+    pub token_id: String,
+}
+
+fn order_book_by_default() -> bool {
+    true
+}
+
+// Réponse d'une source de résolution telle que renvoyée par le serveur
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixtureSource {
+    #[serde(default)]
+    pub content_type: Option<String>,
+    pub body: String,
+    #[serde(default)]
+    pub latency_ms: u64, // Latence réseau simulée avant la réponse
+}
+
+// Niveaux (prix, taille), meilleurs prix en premier
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixtureBook {
+    #[serde(default)]
+    pub bids: Vec<(f64, f64)>,
+    #[serde(default)]
+    pub asks: Vec<(f64, f64)>,
+}
+
+// Position clôturée avant le cycle: PnL réalisé et ancienneté de la clôture
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixtureClose {
+    pub market_id: String,
+    pub pnl: f64,
+    #[serde(default)]
+    pub closed_secs_ago: f64,
+}
+
+// Position ouverte avant le cycle: token détenu ("YES" / "NO"), taille et prix d'entrée
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixturePosition {
+    pub market_id: String,
+    pub token_side: String,
+    pub shares: f64,
+    pub entry_price: f64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Fixture {
+    #[serde(default)]
+    pub markets: Vec<FixtureMarket>,
+    #[serde(default)]
+    pub sources: HashMap<String, FixtureSource>, // URL -> réponse
+    #[serde(default)]
+    pub orderbooks: HashMap<String, FixtureBook>, // token id ou market id -> livre
+    #[serde(default)]
+    pub markets_latency_ms: u64, // Latence simulée de la récupération des marchés
+    #[serde(default)]
+    pub closed_positions: Vec<FixtureClose>, // Clôtures rejouées au chargement
+    #[serde(default)]
+    pub market_metadata: HashMap<String, serde_json::Value>, // condition id -> réponse CLOB /markets
+    #[serde(default)]
+    pub source_last_success_secs_ago: HashMap<String, f64>, // URL -> ancienneté du dernier succès avant le cycle
+    #[serde(default)]
+    pub open_positions: Vec<FixturePosition>, // Positions détenues au démarrage
+    #[serde(default)]
+    pub market_resolutions: HashMap<String, serde_json::Value>, // market id -> statut Gamma /markets/{id}
+    #[serde(default)]
+    pub trade_flow: HashMap<String, f64>, // market id -> flux d'échanges observé (taille par seconde)
+    #[serde(default)]
+    pub source_failures: HashMap<String, u32>, // URL -> tentatives en échec avant la réponse, à chaque cycle
+}
+
+impl Fixture {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Lecture de la fixture {}: {}", path, e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Fixture {} invalide: {}", path, e))
+    }
+
+    pub fn markets_latency(&self) -> Option<Duration> {
+        (self.markets_latency_ms > 0).then(|| Duration::from_millis(self.markets_latency_ms))
+    }
+
+    pub fn source_latency(&self, url: &str) -> Option<Duration> {
+        self.sources.get(url)
+            .filter(|source| source.latency_ms > 0)
+            .map(|source| Duration::from_millis(source.latency_ms))
+    }
+}
@@ -0,0 +1,124 @@
+// Observations FRED (FEDFUNDS) et fair value des marchés de taux de la Fed
+// La probabilité juste de "la Fed baissera-t-elle ses taux" dépend de la dernière observation
+// du taux directeur et de sa tendance, pas de la présence de mots-clés: une baisse observée
+// rapproche les marchés "cut" du YES et éloigne les marchés "raise", à proportion de l'écart.
+use serde_json::Value;
+
+// Variation (points de pourcentage) qui porte l'ajustement à son maximum
+pub const FULL_DELTA_POINTS: f64 = 0.5;
+// Part maximale de la distance à l'issue comblée par la tendance du taux
+pub const MAX_PULL: f64 = 0.6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation {
+    pub date: String, // AAAA-MM-JJ
+    pub value: f64,   // Taux en %
+}
+
+// Sens de la question d'un marché de taux
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateQuestion {
+    Cut,
+    Raise,
+}
+
+// Réponse JSON de /fred/series/observations, triée par date; valeurs manquantes (".") ignorées
+pub fn parse_fred_observations(content: &str) -> Vec<Observation> {
+    let Ok(json) = serde_json::from_str::<Value>(content) else {
+        return Vec::new();
+    };
+    let mut observations: Vec<Observation> = json.get("observations")
+        .and_then(Value::as_array)
+        .map(|entries| entries.iter().filter_map(|entry| {
+            let date = entry.get("date")?.as_str()?.to_string();
+            let value = entry.get("value")?.as_str()?.trim().parse::<f64>().ok().filter(|v| v.is_finite())?;
+            Some(Observation { date, value })
+        }).collect())
+        .unwrap_or_default();
+    observations.sort_by(|a, b| a.date.cmp(&b.date));
+    observations
+}
+
+// Dernière observation et sa variation par rapport à la précédente
+pub fn latest_delta(observations: &[Observation]) -> Option<(f64, f64)> {
+    match observations {
+        [.., previous, latest] => Some((latest.value, latest.value - previous.value)),
+        _ => None,
+    }
+}
+
+// "cut" / "lower" / "decrease" ou "raise" / "hike" / "increase", None si ambigu ou sans rapport
+pub fn rate_question(question: &str) -> Option<RateQuestion> {
+    const CUT: &[&str] = &["cut", "cuts", "lower", "lowers", "decrease", "decreases", "reduce", "reduces"];
+    const RAISE: &[&str] = &["raise", "raises", "hike", "hikes", "increase", "increases"];
+    let lower = question.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_ascii_alphanumeric()).collect();
+    let cut = words.iter().any(|word| CUT.contains(word));
+    let raise = words.iter().any(|word| RAISE.contains(word));
+    match (cut, raise) {
+        (true, false) => Some(RateQuestion::Cut),
+        (false, true) => Some(RateQuestion::Raise),
+        _ => None,
+    }
+}
+
+// Fair value d'un marché de taux: la cote (prior) est tirée vers YES quand la dernière variation
+// va dans le sens de la question, vers NO sinon, d'autant plus que la variation est grande
+pub fn rate_fair_value(prior: f64, question: &str, observations: &[Observation]) -> Option<f64> {
+    let direction = rate_question(question)?;
+    let (_, delta) = latest_delta(observations)?;
+    let favouring = match direction {
+        RateQuestion::Cut => -delta,
+        RateQuestion::Raise => delta,
+    };
+    let pull = (favouring.abs() / FULL_DELTA_POINTS).min(1.0) * MAX_PULL;
+    let outcome = if favouring > 0.0 { 1.0 } else { 0.0 };
+    Some((prior + pull * (outcome - prior)).clamp(0.01, 0.99))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUT_MARKET: &str = "Will the Fed cut rates at the next FOMC meeting?";
+
+    // Réponse FRED: observations non triées, une valeur manquante
+    fn fred_body(values: &[(&str, &str)]) -> String {
+        let observations: Vec<serde_json::Value> = values.iter()
+            .map(|(date, value)| serde_json::json!({"realtime_start": "2024-07-01", "date": date, "value": value}))
+            .collect();
+        serde_json::json!({"units": "lin", "count": observations.len(), "observations": observations}).to_string()
+    }
+
+    #[test]
+    fn parses_observations_in_date_order() {
+        let observations = parse_fred_observations(&fred_body(&[("2024-06-01", "5.08"), ("2024-04-01", "."), ("2024-05-01", "5.33")]));
+        assert_eq!(observations, vec![
+            Observation { date: "2024-05-01".to_string(), value: 5.33 },
+            Observation { date: "2024-06-01".to_string(), value: 5.08 },
+        ]);
+        let (latest, delta) = latest_delta(&observations).unwrap();
+        assert_eq!(latest, 5.08);
+        assert!((delta + 0.25).abs() < 1e-12);
+        assert!(parse_fred_observations("<rss/>").is_empty());
+        assert!(latest_delta(&observations[..1]).is_none(), "une seule observation: pas de tendance");
+    }
+
+    #[test]
+    fn an_observed_cut_favours_cut_markets_and_penalises_raise_markets() {
+        let cut = parse_fred_observations(&fred_body(&[("2024-05-01", "5.33"), ("2024-06-01", "5.08")]));
+        let deep_cut = parse_fred_observations(&fred_body(&[("2024-05-01", "5.33"), ("2024-06-01", "4.83")]));
+        let flat = parse_fred_observations(&fred_body(&[("2024-05-01", "5.33"), ("2024-06-01", "5.33")]));
+
+        let cut_market = rate_fair_value(0.40, CUT_MARKET, &cut).unwrap();
+        assert!(cut_market > 0.40, "baisse observée: marché 'cut' plus probable ({})", cut_market);
+        assert!(rate_fair_value(0.40, CUT_MARKET, &deep_cut).unwrap() > cut_market, "ajustement proportionnel à l'écart");
+        assert_eq!(rate_fair_value(0.40, CUT_MARKET, &flat), Some(0.40), "taux stable: cote inchangée");
+
+        let raise_market = rate_fair_value(0.40, "Will the Fed raise interest rates in June?", &cut).unwrap();
+        assert!(raise_market < 0.40, "baisse observée: marché 'raise' moins probable ({})", raise_market);
+
+        assert_eq!(rate_question("Will the Fed hold rates steady?"), None);
+        assert!(rate_fair_value(0.40, "Will the Fed hold rates steady?", &cut).is_none());
+    }
+}
@@ -0,0 +1,49 @@
+// Ordre de financement des signaux quand le capital ne suffit pas à tous
+// Edge net décroissant, puis id de marché à égalité, puis ordre de génération: les meilleurs
+// trades passent en premier, et l'ordre ne dépend ni de l'itération d'une HashMap ni de l'ordre
+// des opportunités. Un edge non fini passe en dernier.
+
+// (index du signal, edge net, marché) -> index des signaux dans l'ordre où ils sont financés
+pub fn funding_order<'a>(candidates: impl IntoIterator<Item = (usize, f64, &'a str)>) -> Vec<usize> {
+    let mut candidates: Vec<(usize, f64, &str)> = candidates.into_iter()
+        .map(|(index, edge, market_id)| (index, if edge.is_finite() { edge } else { f64::NEG_INFINITY }, market_id))
+        .collect();
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.2.cmp(b.2)).then(a.0.cmp(&b.0)));
+    candidates.into_iter().map(|(index, _, _)| index).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Boucle d'exécution réduite à sa règle de capital: un signal est financé si le solde couvre sa mise
+    fn funded(signals: &[(f64, &str, f64)], balance: f64) -> Vec<usize> {
+        let mut available = balance;
+        let mut executed = Vec::new();
+        for index in funding_order(signals.iter().enumerate().map(|(index, (edge, market_id, _))| (index, *edge, *market_id))) {
+            let stake = signals[index].2;
+            if available >= stake {
+                available -= stake;
+                executed.push(index);
+            }
+        }
+        executed
+    }
+
+    #[test]
+    fn with_capital_for_one_of_two_signals_the_higher_net_edge_is_executed() {
+        // Signal au meilleur edge généré en second: il passe quand même en premier
+        let signals = [(0.02, "mkt-a", 60.0), (0.05, "mkt-b", 60.0)];
+        assert_eq!(funded(&signals, 80.0), vec![1]);
+        let reversed = [signals[1], signals[0]];
+        assert_eq!(funded(&reversed, 80.0), vec![0]);
+        // Capital pour les deux: les deux passent, meilleur edge d'abord
+        assert_eq!(funding_order([(0, 0.02, "mkt-a"), (1, 0.05, "mkt-b")]), vec![1, 0]);
+    }
+
+    #[test]
+    fn ties_are_broken_by_market_id_and_non_finite_edges_go_last() {
+        let order = funding_order([(0, 0.03, "mkt-c"), (1, f64::NAN, "mkt-a"), (2, 0.03, "mkt-a"), (3, 0.03, "mkt-a"), (4, 0.04, "mkt-z")]);
+        assert_eq!(order, vec![4, 2, 3, 0, 1]);
+    }
+}
@@ -0,0 +1,300 @@
+// Formes successives de la réponse Gamma /markets
+// Selon la version de l'API, la liste des marchés arrive nue, sous `data` ou sous `markets`.
+// Chaque forme connue est essayée dans l'ordre: un changement d'API ne vide pas la liste.
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+// Pagination de /markets (limit/offset): taille de page et plafond de marchés lus par cycle
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+pub const DEFAULT_MAX_MARKETS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GammaShape {
+    Markets,   // {"markets": [...]}
+    Data,      // {"data": [...]}
+    BareArray, // [...]
+}
+
+impl GammaShape {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GammaShape::Markets => "{markets: [...]}",
+            GammaShape::Data => "{data: [...]}",
+            GammaShape::BareArray => "tableau nu",
+        }
+    }
+}
+
+// Liste des marchés et forme reconnue, erreur si aucune forme connue ne correspond
+pub fn market_list(response: &Value) -> Result<(GammaShape, &Vec<Value>), String> {
+    if let Some(markets) = response.get("markets").and_then(|v| v.as_array()) {
+        return Ok((GammaShape::Markets, markets));
+    }
+    if let Some(markets) = response.get("data").and_then(|v| v.as_array()) {
+        return Ok((GammaShape::Data, markets));
+    }
+    if let Some(markets) = response.as_array() {
+        return Ok((GammaShape::BareArray, markets));
+    }
+
+    let keys = response.as_object()
+        .map(|object| object.keys().cloned().collect::<Vec<_>>().join(", "))
+        .unwrap_or_default();
+    Err(format!("aucune liste de marchés reconnue (markets, data ou tableau nu), clés: [{}]", keys))
+}
+
+// Échelle d'un prix Gamma: selon le champ, fraction (0-1) ou pourcentage / cents (0-100)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceScale {
+    Fraction, // 0.62
+    Percent,  // 62
+}
+
+// Prix ramené à [0, 1], avec l'échelle détectée à la lecture
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Price {
+    pub value: f64,
+    pub scale: PriceScale,
+}
+
+// Au-dessus de 1, un prix est en pourcentage; 1 et en dessous, une fraction
+// (1 vaut donc 100% et non 1%). Hors de [0, 100], ou non fini: rejeté.
+pub fn normalize_price(raw: f64) -> Result<Price, String> {
+    if !raw.is_finite() || !(0.0..=100.0).contains(&raw) {
+        return Err(format!("prix {} hors échelle (attendu 0-1 ou 0-100)", raw));
+    }
+    if raw > 1.0 {
+        Ok(Price { value: raw / 100.0, scale: PriceScale::Percent })
+    } else {
+        Ok(Price { value: raw, scale: PriceScale::Fraction })
+    }
+}
+
+fn price_value(raw: &Value, key: &str) -> Result<Price, String> {
+    let number = raw.as_f64().or_else(|| raw.as_str().and_then(|s| s.trim().parse().ok()));
+    match number {
+        Some(number) => normalize_price(number).map_err(|e| format!("{}: {}", key, e)),
+        None => Err(format!("{}: valeur non numérique {}", key, raw)),
+    }
+}
+
+// Issues d'un marché: noms (`outcomes`) et prix normalisés (`outcomePrices`), chaque liste en
+// tableau ou en chaîne JSON encodée. None si les prix sont absents; erreur si une liste est
+// illisible, si un prix l'est, ou si les deux listes n'ont pas la même longueur.
+// Sans noms, un marché à deux prix est lu comme Yes / No.
+pub fn outcome_prices(market: &Value) -> Option<Result<Vec<(String, Price)>, String>> {
+    let prices = list_field(market, "outcomePrices")?;
+    Some(outcome_list(market, prices))
+}
+
+fn outcome_list(market: &Value, prices: Result<Vec<Value>, String>) -> Result<Vec<(String, Price)>, String> {
+    let prices = prices?;
+    let names = match list_field(market, "outcomes") {
+        Some(names) => names?,
+        None if prices.len() == 2 => vec![Value::from("Yes"), Value::from("No")],
+        None => return Err(format!("outcomePrices: {} prix sans noms d'issues", prices.len())),
+    };
+    if names.len() != prices.len() {
+        return Err(format!("outcomes: {} noms pour {} prix", names.len(), prices.len()));
+    }
+    names.iter().zip(&prices).map(|(name, raw)| {
+        let name = name.as_str().ok_or_else(|| format!("outcomes: nom non textuel {}", name))?;
+        let number = raw.as_f64().or_else(|| raw.as_str().and_then(|s| s.trim().parse().ok()))
+            .ok_or_else(|| format!("outcomePrices: valeur non numérique {}", raw))?;
+        let price = normalize_price(number).map_err(|e| format!("outcomePrices: {}", e))?;
+        Ok((name.to_string(), price))
+    }).collect()
+}
+
+// Liste Gamma en tableau ou en chaîne JSON encodée ("[\"Yes\", \"No\"]")
+fn list_field(market: &Value, key: &str) -> Option<Result<Vec<Value>, String>> {
+    Some(list_value(market.get(key)?, key))
+}
+
+fn list_value(raw: &Value, key: &str) -> Result<Vec<Value>, String> {
+    match raw {
+        Value::Array(values) => Ok(values.clone()),
+        Value::String(encoded) => serde_json::from_str(encoded).map_err(|e| format!("{}: liste illisible ({})", key, e)),
+        other => Err(format!("{}: liste attendue, reçu {}", key, other)),
+    }
+}
+
+// Désérialisation d'un marché Gamma (champs de Market): chaque champ accepte les variantes
+// rencontrées dans les réponses (nombre ou chaîne, tableau ou chaîne JSON encodée)
+
+// created_at en RFC 3339, quelle que soit la forme reçue (RFC 3339, "AAAA-MM-JJ HH:MM:SS" en UTC,
+// secondes Unix), pour que l'ancienneté du marché (is_new) puisse en être déduite; une date
+// illisible est gardée telle quelle et le marché n'est jamais considéré comme nouveau
+pub fn created_at<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let raw = Value::deserialize(deserializer)?;
+    Ok(match &raw {
+        Value::String(text) => normalize_timestamp(text).unwrap_or_else(|| text.clone()),
+        Value::Number(seconds) => seconds.as_i64()
+            .and_then(|seconds| chrono::DateTime::from_timestamp(seconds, 0))
+            .map(|date| date.to_rfc3339())
+            .unwrap_or_default(),
+        _ => String::new(),
+    })
+}
+
+fn normalize_timestamp(text: &str) -> Option<String> {
+    if let Ok(date) = chrono::DateTime::parse_from_rfc3339(text) {
+        return Some(date.with_timezone(&chrono::Utc).to_rfc3339());
+    }
+    chrono::NaiveDateTime::parse_from_str(text.trim_end_matches("+00"), "%Y-%m-%d %H:%M:%S%.f").ok()
+        .map(|naive| naive.and_utc().to_rfc3339())
+}
+
+// Prix ramené à [0, 1] (nombre ou chaîne, fraction ou pourcentage); null: prix inconnu
+pub fn price<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    let raw = Value::deserialize(deserializer)?;
+    if raw.is_null() {
+        return Ok(unknown_price());
+    }
+    price_value(&raw, "probability").map(|price| price.value).map_err(serde::de::Error::custom)
+}
+
+// Prix absent de la réponse: déduit ensuite de la première issue
+pub fn unknown_price() -> f64 {
+    f64::NAN
+}
+
+// Nombre ou chaîne numérique (liquidity), 0 si illisible
+pub fn number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    let raw = Value::deserialize(deserializer)?;
+    Ok(raw.as_f64().or_else(|| raw.as_str().and_then(|s| s.trim().parse().ok())).unwrap_or(0.0))
+}
+
+// clobTokenIds en tableau ou en chaîne JSON encodée
+pub fn string_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let raw = Value::deserialize(deserializer)?;
+    let values = list_value(&raw, "clobTokenIds").unwrap_or_default();
+    Ok(values.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+}
+
+// Événement du marché: premier élément de "events" (id en chaîne ou en nombre), ou eventId
+pub fn event_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let raw = Value::deserialize(deserializer)?;
+    let id = match &raw {
+        Value::Array(events) => events.first().and_then(|event| event.get("id")).cloned().unwrap_or(Value::Null),
+        other => other.clone(),
+    };
+    Ok(match id {
+        Value::String(id) => id,
+        Value::Number(id) => id.to_string(),
+        _ => String::new(),
+    })
+}
+
+// enableOrderBook absent: livre supposé actif
+pub fn enabled_by_default() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn load_json(relative: &str) -> serde_json::Value {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(relative);
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("lecture de la fixture")).expect("fixture JSON")
+    }
+
+    #[test]
+    fn every_known_shape_yields_the_same_markets() {
+        let cases = [
+            ("tests/fixtures/gamma_markets_markets.json", GammaShape::Markets),
+            ("tests/fixtures/gamma_markets_data.json", GammaShape::Data),
+            ("tests/fixtures/gamma_markets_array.json", GammaShape::BareArray),
+        ];
+        let responses: Vec<serde_json::Value> = cases.iter().map(|(path, _)| load_json(path)).collect();
+
+        let (_, reference) = market_list(&responses[0]).expect("forme {markets}");
+        assert_eq!(reference.len(), 3);
+        for ((path, expected_shape), response) in cases.iter().zip(&responses) {
+            let (shape, markets) = market_list(response).unwrap_or_else(|e| panic!("{}: {}", path, e));
+            assert_eq!(shape, *expected_shape, "{}", path);
+            assert_eq!(markets, reference, "{}: marchés différents", path);
+        }
+    }
+
+    #[test]
+    fn markets_key_wins_over_data() {
+        let response = serde_json::json!({ "markets": [{ "id": "m" }], "data": [{ "id": "d" }] });
+        let (shape, markets) = market_list(&response).expect("forme connue");
+        assert_eq!(shape, GammaShape::Markets);
+        assert_eq!(markets[0]["id"], "m");
+    }
+
+    #[test]
+    fn unknown_shape_is_reported_with_its_keys() {
+        let error = market_list(&serde_json::json!({ "results": [], "markets": "none" })).expect_err("forme inconnue");
+        assert!(error.contains("clés: [markets, results]"), "{}", error);
+    }
+
+    fn names_and_prices(market: &serde_json::Value) -> Vec<(String, f64)> {
+        outcome_prices(market).expect("prix d'issues").expect("issues lisibles")
+            .into_iter().map(|(name, price)| (name, price.value)).collect()
+    }
+
+    #[test]
+    fn gamma_outcomes_are_read_as_arrays_or_encoded_strings() {
+        // Cinq candidats, listes encodées en chaînes JSON comme dans la réponse Gamma
+        let election = serde_json::json!({
+            "outcomes": "[\"Alice\", \"Bob\", \"Carol\", \"Dave\", \"Eve\"]",
+            "outcomePrices": "[\"0.40\", \"0.25\", \"0.20\", \"0.10\", \"0.05\"]",
+        });
+        assert_eq!(names_and_prices(&election), vec![
+            ("Alice".to_string(), 0.40), ("Bob".to_string(), 0.25), ("Carol".to_string(), 0.20),
+            ("Dave".to_string(), 0.10), ("Eve".to_string(), 0.05),
+        ]);
+
+        // Tableaux natifs, prix en pourcentage ramenés à [0, 1]
+        let percent = serde_json::json!({"outcomes": ["Up", "Flat", "Down"], "outcomePrices": [55, 30, 15]});
+        assert_eq!(names_and_prices(&percent), vec![("Up".to_string(), 0.55), ("Flat".to_string(), 0.30), ("Down".to_string(), 0.15)]);
+
+        // Binaire sans noms: Yes puis No
+        let binary = serde_json::json!({"outcomePrices": "[\"0.42\", \"0.58\"]"});
+        assert_eq!(names_and_prices(&binary), vec![("Yes".to_string(), 0.42), ("No".to_string(), 0.58)]);
+    }
+
+    #[test]
+    fn inconsistent_outcome_lists_are_rejected() {
+        assert!(outcome_prices(&serde_json::json!({"probability": 0.4})).is_none());
+        for invalid in [
+            serde_json::json!({"outcomes": ["A", "B", "C"], "outcomePrices": ["0.5", "0.5"]}),
+            serde_json::json!({"outcomePrices": ["0.2", "0.3", "0.5"]}),
+            serde_json::json!({"outcomes": ["A", "B"], "outcomePrices": ["0.5", "abc"]}),
+            serde_json::json!({"outcomes": ["A", "B"], "outcomePrices": "[0.5, "}),
+            serde_json::json!({"outcomes": ["A", "B"], "outcomePrices": ["0.5", "150"]}),
+        ] {
+            assert!(outcome_prices(&invalid).expect("prix présents").is_err(), "{} accepté", invalid);
+        }
+    }
+
+    #[test]
+    fn both_scales_normalize_to_the_same_price() {
+        let fraction = normalize_price(0.62).unwrap();
+        let percent = normalize_price(62.0).unwrap();
+        assert_eq!(fraction.value, percent.value);
+        assert_eq!((fraction.scale, percent.scale), (PriceScale::Fraction, PriceScale::Percent));
+        assert_eq!(normalize_price(99.5).unwrap().value, 0.995);
+
+        // 1 est une fraction (100%), 0 reste 0; hors échelle ou non fini: rejeté
+        assert_eq!(normalize_price(1.0).unwrap().scale, PriceScale::Fraction);
+        assert_eq!(normalize_price(0.0).unwrap().value, 0.0);
+        for raw in [150.0, -0.1, f64::NAN, f64::INFINITY] {
+            assert!(normalize_price(raw).is_err(), "{}", raw);
+        }
+
+        // Marchés Gamma: nombre ou chaîne, fraction ou pourcentage
+        let markets = load_json("tests/fixtures/gamma_markets_array.json");
+        let reference = outcome_prices(&markets[0]).unwrap().unwrap()[0].1.value;
+        assert_eq!(reference, 0.42);
+        for quoted in [serde_json::json!(42), serde_json::json!("42"), serde_json::json!("0.42")] {
+            assert_eq!(price_value(&quoted, "probability").unwrap().value, reference, "{}", quoted);
+        }
+        assert!(price_value(&serde_json::json!("n/a"), "probability").is_err());
+    }
+}
@@ -0,0 +1,94 @@
+// Endpoints de santé pour l'orchestration de conteneurs
+// - /healthz: le processus répond (liveness)
+// - /readyz: core C++ initialisé, au moins une récupération de marchés réussie,
+//   au moins une source joignable, et moins de MAX_FAILED_CYCLES cycles en échec total
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+// Cycles consécutifs sans marché ni source avant de se déclarer non prêt
+pub const MAX_FAILED_CYCLES: u32 = 3;
+
+#[derive(Debug, Default)]
+pub struct HealthState {
+    core_ready: AtomicBool,
+    markets_fetched: AtomicBool,
+    source_reachable: AtomicBool,
+    consecutive_failed_cycles: AtomicU32,
+}
+
+impl HealthState {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn set_core_ready(&self, ready: bool) {
+        self.core_ready.store(ready, Ordering::SeqCst);
+    }
+
+    // Bilan d'un cycle: un cycle sans marché récupéré ni source joignable est un échec total
+    pub fn record_cycle(&self, markets_fetched: bool, reachable_sources: usize) {
+        if markets_fetched {
+            self.markets_fetched.store(true, Ordering::SeqCst);
+        }
+        if reachable_sources > 0 {
+            self.source_reachable.store(true, Ordering::SeqCst);
+        }
+        if !markets_fetched && reachable_sources == 0 {
+            self.consecutive_failed_cycles.fetch_add(1, Ordering::SeqCst);
+        } else {
+            self.consecutive_failed_cycles.store(0, Ordering::SeqCst);
+        }
+    }
+
+    // Raison de non-disponibilité, None si prêt
+    pub fn not_ready_reason(&self) -> Option<String> {
+        let failed_cycles = self.consecutive_failed_cycles.load(Ordering::SeqCst);
+        if !self.core_ready.load(Ordering::SeqCst) {
+            Some("core C++ non initialisé".to_string())
+        } else if !self.markets_fetched.load(Ordering::SeqCst) {
+            Some("aucune récupération de marchés réussie".to_string())
+        } else if !self.source_reachable.load(Ordering::SeqCst) {
+            Some("aucune source joignable".to_string())
+        } else if failed_cycles >= MAX_FAILED_CYCLES {
+            Some(format!("{} cycles consécutifs en échec", failed_cycles))
+        } else {
+            None
+        }
+    }
+}
+
+// Servir /healthz et /readyz jusqu'à l'arrêt du processus
+pub async fn serve(listener: TcpListener, state: Arc<HealthState>) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else { continue };
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            let _ = respond(stream, &state).await;
+        });
+    }
+}
+
+async fn respond(mut stream: TcpStream, state: &HealthState) -> std::io::Result<()> {
+    let mut buffer = [0u8; 1024];
+    let read = stream.read(&mut buffer).await?;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status, body) = match path {
+        "/healthz" => ("200 OK", "ok".to_string()),
+        "/readyz" => match state.not_ready_reason() {
+            None => ("200 OK", "ready".to_string()),
+            Some(reason) => ("503 Service Unavailable", format!("not ready: {}", reason)),
+        },
+        _ => ("404 Not Found", "not found".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, body.len(), body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
@@ -0,0 +1,119 @@
+// Nouvelles tentatives d'une requête HTTP: timeouts, erreurs de connexion et réponses 5xx sont
+// réessayés avec un délai exponentiel (base, 2 x base, 4 x base...); une réponse 429 attend le
+// délai de son en-tête Retry-After (secondes ou date HTTP), à défaut le délai exponentiel.
+// Un délai au-delà du plafond de la politique n'est pas attendu: l'échec est rendu tel quel.
+use std::time::{Duration, SystemTime};
+
+// Réglages d'un chemin de requêtes: la récupération des marchés peut attendre, le chemin de
+// trading doit rester rapide
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub backoff_base: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    // Délai exponentiel avant la nouvelle tentative `retry` (1 = première), borné au plafond
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.backoff_base.saturating_mul(factor).min(self.max_delay)
+    }
+
+    // Délai avant la nouvelle tentative `retry` après un échec, None si l'échec est définitif:
+    // plus de tentative disponible, erreur non transitoire, ou Retry-After au-delà du plafond
+    pub fn delay(&self, failure: &Failure, retry: u32, now: SystemTime) -> Option<Duration> {
+        if retry > self.max_retries {
+            return None;
+        }
+        match failure {
+            Failure::Transport | Failure::Status(500..=599) => Some(self.backoff(retry)),
+            Failure::RateLimited(retry_after) => match retry_after.as_deref().and_then(|value| parse_retry_after(value, now)) {
+                Some(wait) if wait > self.max_delay => None,
+                Some(wait) => Some(wait),
+                None => Some(self.backoff(retry)),
+            },
+            Failure::Status(_) => None,
+        }
+    }
+}
+
+// Échec d'une tentative
+#[derive(Debug, Clone, PartialEq)]
+pub enum Failure {
+    Transport,                   // Timeout ou connexion impossible
+    RateLimited(Option<String>), // HTTP 429, avec la valeur de Retry-After si présente
+    Status(u16),                 // Autre statut d'erreur
+}
+
+impl Failure {
+    pub fn describe(&self) -> String {
+        match self {
+            Failure::Transport => "timeout / connexion".to_string(),
+            Failure::RateLimited(Some(value)) => format!("HTTP 429, Retry-After {}", value),
+            Failure::RateLimited(None) => "HTTP 429".to_string(),
+            Failure::Status(status) => format!("HTTP {}", status),
+        }
+    }
+}
+
+// Retry-After: nombre de secondes, ou date HTTP (RFC 2822) ramenée au délai restant
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let target = SystemTime::UNIX_EPOCH + Duration::from_secs(date.timestamp().max(0) as u64);
+    Some(target.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+// Politique de nouvelles tentatives HTTP: délai exponentiel sur timeouts et 5xx, Retry-After
+// respecté sur 429 dans la limite du plafond, erreurs client jamais réessayées
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    fn policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy { max_retries, backoff_base: Duration::from_millis(100), max_delay: Duration::from_secs(1) }
+    }
+
+    #[test]
+    fn transient_failures_back_off_exponentially_up_to_the_cap() {
+        let policy = policy(5);
+        let now = SystemTime::now();
+        let delays: Vec<_> = (1..=5).map(|retry| policy.delay(&Failure::Status(503), retry, now)).collect();
+        assert_eq!(delays, [100, 200, 400, 800, 1000].map(|ms| Some(Duration::from_millis(ms))));
+        assert_eq!(policy.delay(&Failure::Transport, 2, now), Some(Duration::from_millis(200)));
+        // Plus de tentative disponible
+        assert_eq!(policy.delay(&Failure::Status(503), 6, now), None);
+        assert_eq!(self::policy(0).delay(&Failure::Transport, 1, now), None);
+    }
+
+    #[test]
+    fn a_rate_limited_response_waits_for_its_retry_after() {
+        let policy = policy(3);
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let limited = |value: &str| Failure::RateLimited(Some(value.to_string()));
+        assert_eq!(policy.delay(&limited("1"), 1, now), Some(Duration::from_secs(1)));
+        // Date HTTP ramenée au délai restant; une date passée se réessaie aussitôt
+        assert_eq!(policy.delay(&limited("Tue, 14 Nov 2023 22:13:20 +0000"), 1, now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("Tue, 14 Nov 2023 22:13:21 GMT", now), Some(Duration::from_secs(1)));
+        assert_eq!(parse_retry_after("Tue, 14 Nov 2023 22:00:00 GMT", now), Some(Duration::ZERO));
+        // Attente au-delà du plafond: échec rendu sans attendre
+        assert_eq!(policy.delay(&limited("30"), 1, now), None);
+        // En-tête absent ou illisible: délai exponentiel
+        assert_eq!(policy.delay(&Failure::RateLimited(None), 2, now), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(&limited("bientôt"), 1, now), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let now = SystemTime::now();
+        for status in [400, 401, 403, 404, 422] {
+            assert_eq!(policy(3).delay(&Failure::Status(status), 1, now), None, "HTTP {} réessayé", status);
+        }
+        assert_eq!(Failure::RateLimited(Some("2".to_string())).describe(), "HTTP 429, Retry-After 2");
+    }
+}
@@ -0,0 +1,129 @@
+// Registre des ordres en vol, un seul par marché et par côté
+// Sous un exécuteur concurrent, deux signaux du même marché pourraient envoyer leurs ordres en
+// même temps (concurrence avec soi-même, double exposition). Une tâche réclame le créneau
+// (marché, côté) avant d'envoyer et le libère en fin d'exécution (drop de la réclamation).
+// Le créneau porte l'état engagé par les ordres précédents: la tâche suivante le voit à jour.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{Mutex as SlotLock, OwnedMutexGuard};
+
+type Slot<T> = Arc<SlotLock<T>>;
+type Slots<T> = HashMap<(String, String), Slot<T>>; // (marché, côté) -> créneau
+
+pub struct InFlightOrders<T> {
+    slots: Arc<Mutex<Slots<T>>>,
+}
+
+// Clone partage le même registre (clone de l'Arc), sans contrainte sur T
+impl<T> Clone for InFlightOrders<T> {
+    fn clone(&self) -> Self {
+        InFlightOrders { slots: self.slots.clone() }
+    }
+}
+
+impl<T> Default for InFlightOrders<T> {
+    fn default() -> Self {
+        InFlightOrders { slots: Arc::new(Mutex::new(HashMap::new())) }
+    }
+}
+
+// Créneau réclamé: état engagé du marché/côté, libéré au drop
+pub struct OrderClaim<T> {
+    guard: OwnedMutexGuard<T>,
+}
+
+impl<T> std::ops::Deref for OrderClaim<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> std::ops::DerefMut for OrderClaim<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T: Default> InFlightOrders<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn slot(&self, market_id: &str, side: &str) -> Slot<T> {
+        let mut slots = self.slots.lock().unwrap();
+        slots.entry((market_id.to_string(), side.to_string()))
+            .or_insert_with(|| Arc::new(SlotLock::new(T::default())))
+            .clone()
+    }
+
+    // Réclamer le créneau, en attendant au plus `wait` la fin de l'ordre en vol
+    // None si l'ordre précédent n'a pas abouti dans le délai (wait nul: pas d'attente)
+    pub async fn claim(&self, market_id: &str, side: &str, wait: Duration) -> Option<OrderClaim<T>> {
+        let slot = self.slot(market_id, side);
+        if wait.is_zero() {
+            return slot.try_lock_owned().ok().map(|guard| OrderClaim { guard });
+        }
+        tokio::time::timeout(wait, slot.lock_owned()).await.ok().map(|guard| OrderClaim { guard })
+    }
+
+    // Un ordre est-il en vol sur ce marché/côté ?
+    pub fn is_in_flight(&self, market_id: &str, side: &str) -> bool {
+        self.slot(market_id, side).try_lock().is_err()
+    }
+}
+
+// Registre des ordres en vol: un seul ordre par marché et côté, le suivant voit l'état engagé
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    const WAIT: Duration = Duration::from_secs(5);
+
+    // Exécution simulée: réclame le créneau, lit l'engagé, "envoie" l'ordre puis engage sa taille
+    async fn execute(orders: InFlightOrders<f64>, log: Arc<Mutex<Vec<String>>>, name: &'static str, size: f64) {
+        let mut committed = orders.claim("fx-politics-1", "YES-buy", WAIT).await.expect("créneau");
+        log.lock().unwrap().push(format!("{} start (engagé {:.0})", name, *committed));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        *committed += size;
+        log.lock().unwrap().push(format!("{} done", name));
+    }
+
+    #[tokio::test]
+    async fn concurrent_orders_on_one_market_serialize() {
+        let orders = InFlightOrders::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let first = tokio::spawn(execute(orders.clone(), log.clone(), "first", 10.0));
+        // Le premier ordre est en vol avant que le second ne démarre
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(orders.is_in_flight("fx-politics-1", "YES-buy"));
+        let second = tokio::spawn(execute(orders.clone(), log.clone(), "second", 5.0));
+        first.await.unwrap();
+        second.await.unwrap();
+
+        // Jamais deux ordres en vol à la fois, et le second voit les 10 USDC engagés par le premier
+        assert_eq!(*log.lock().unwrap(), vec!["first start (engagé 0)", "first done", "second start (engagé 10)", "second done"]);
+        assert!(!orders.is_in_flight("fx-politics-1", "YES-buy"));
+        assert_eq!(*orders.claim("fx-politics-1", "YES-buy", WAIT).await.unwrap(), 15.0);
+    }
+
+    #[tokio::test]
+    async fn other_markets_and_sides_do_not_wait() {
+        let orders: InFlightOrders<f64> = InFlightOrders::new();
+        let _held = orders.claim("fx-politics-1", "YES-buy", WAIT).await.unwrap();
+
+        // Autre côté ou autre marché: créneau libre immédiatement
+        assert!(orders.claim("fx-politics-1", "NO-buy", Duration::ZERO).await.is_some());
+        assert!(orders.claim("fx-crypto-1", "YES-buy", Duration::ZERO).await.is_some());
+
+        // Même créneau: refusé sans attente, ou après expiration du délai
+        assert!(orders.claim("fx-politics-1", "YES-buy", Duration::ZERO).await.is_none());
+        assert!(orders.claim("fx-politics-1", "YES-buy", Duration::from_millis(20)).await.is_none());
+        drop(_held);
+        assert!(orders.claim("fx-politics-1", "YES-buy", Duration::ZERO).await.is_some());
+    }
+}
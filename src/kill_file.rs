@@ -0,0 +1,49 @@
+// Arrêt d'urgence par fichier (--kill-file)
+// Tant que le fichier existe, plus aucun ordre: les ordres ouverts sont annulés, les décisions
+// forcées en MONITOR. Le trading reprend dès que le fichier est supprimé, sans redémarrage.
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillTransition {
+    Engaged,  // Fichier apparu: trading suspendu
+    Released, // Fichier supprimé: reprise du trading
+}
+
+#[derive(Debug, Clone)]
+pub struct KillFile {
+    path: PathBuf,
+    engaged: bool, // Présence du fichier au dernier contrôle
+}
+
+impl KillFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), engaged: false }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_engaged(&self) -> bool {
+        self.engaged
+    }
+
+    // Contrôler la présence du fichier; Some au changement d'état seulement
+    pub fn check(&mut self) -> Option<KillTransition> {
+        let present = self.path.exists();
+        if present == self.engaged {
+            return None;
+        }
+        self.engaged = present;
+        Some(if present { KillTransition::Engaged } else { KillTransition::Released })
+    }
+
+    // Première ligne non vide du fichier, motif facultatif laissé par l'opérateur
+    pub fn reason(&self) -> Option<String> {
+        std::fs::read_to_string(&self.path).ok()?
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string)
+    }
+}
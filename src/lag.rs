@@ -0,0 +1,157 @@
+// Profil de latence source -> marché
+// Mesure, par paire (marché, source), le délai entre la détection d'un changement
+// de la source et le début du mouvement de prix du marché. Un délai historique
+// long signifie plus de marge pour se positionner avant la réaction du marché
+use std::collections::HashMap;
+
+// Variation relative du prix considérée comme le début de la réaction
+pub const PRICE_MOVE_THRESHOLD: f64 = 0.01;
+// Au-delà, un changement sans réaction du prix est abandonné
+pub const MAX_PENDING_SECS: f64 = 3600.0;
+// Paires source/marché à réaction lente: plus de marge pour front-runner
+pub const LAG_BOOST_MIN_SECS: f64 = 30.0;
+pub const LAG_BOOST_MIN_SAMPLES: usize = 2;
+
+type PairKey = (String, String); // (market_id, source_url)
+
+#[derive(Debug, Default)]
+pub struct LagProfile {
+    pending: HashMap<PairKey, f64>,        // Changements de source en attente de réaction
+    samples: HashMap<PairKey, Vec<f64>>,   // Délais observés en secondes
+}
+
+impl LagProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Noter un changement de source; le premier changement en attente est conservé
+    pub fn note_change(&mut self, market_id: &str, source_url: &str, change_time: f64) {
+        self.pending
+            .entry((market_id.to_string(), source_url.to_string()))
+            .or_insert(change_time);
+    }
+
+    // Résoudre les changements en attente à partir de l'historique de prix
+    // Renvoie les délais nouvellement mesurés (market_id, source_url, délai)
+    pub fn resolve(&mut self, price_history: &HashMap<String, Vec<(f64, f64)>>, now: f64) -> Vec<(String, String, f64)> {
+        let mut resolved = Vec::new();
+        let samples = &mut self.samples;
+
+        self.pending.retain(|(market_id, source_url), change_time| {
+            let lag = price_history.get(market_id)
+                .and_then(|history| reaction_lag(*change_time, history, PRICE_MOVE_THRESHOLD));
+            match lag {
+                Some(lag) => {
+                    samples.entry((market_id.clone(), source_url.clone())).or_default().push(lag);
+                    resolved.push((market_id.clone(), source_url.clone(), lag));
+                    false
+                }
+                None => now - *change_time < MAX_PENDING_SECS,
+            }
+        });
+
+        resolved
+    }
+
+    // Délai médian observé pour la paire, et nombre d'observations
+    pub fn median_lag(&self, market_id: &str, source_url: &str) -> Option<(f64, usize)> {
+        let samples = self.samples.get(&(market_id.to_string(), source_url.to_string()))?;
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mid = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 { (sorted[mid - 1] + sorted[mid]) / 2.0 } else { sorted[mid] };
+        Some((median, sorted.len()))
+    }
+
+    // Délai médian de la paire si elle réagit lentement, sur assez de mesures pour s'y fier
+    pub fn slow_reaction(&self, market_id: &str, source_url: &str) -> Option<(f64, usize)> {
+        self.median_lag(market_id, source_url)
+            .filter(|(median, samples)| *samples >= LAG_BOOST_MIN_SAMPLES && *median >= LAG_BOOST_MIN_SECS)
+    }
+}
+
+// Délai entre un changement de source et le premier point de l'historique
+// s'écartant du prix de référence (dernier prix connu au moment du changement)
+pub fn reaction_lag(change_time: f64, history: &[(f64, f64)], threshold: f64) -> Option<f64> {
+    let baseline = history.iter()
+        .filter(|(t, _)| *t <= change_time)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .or_else(|| history.iter().filter(|(t, _)| *t > change_time).min_by(|a, b| a.0.total_cmp(&b.0)))
+        .map(|(_, price)| *price)?;
+    if baseline <= 0.0 {
+        return None;
+    }
+
+    history.iter()
+        .filter(|(t, _)| *t > change_time)
+        .filter(|(_, price)| ((price - baseline) / baseline).abs() >= threshold)
+        .map(|(t, _)| t - change_time)
+        .min_by(|a, b| a.total_cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKET: &str = "m1";
+    const SOURCE: &str = "https://www.bls.gov/feed";
+
+    fn history(points: &[(f64, f64)]) -> HashMap<String, Vec<(f64, f64)>> {
+        HashMap::from([(MARKET.to_string(), points.to_vec())])
+    }
+
+    #[test]
+    fn the_lag_runs_from_the_change_to_the_first_move_past_the_threshold() {
+        // Référence 0.50 au changement (t=100); +0.5% à t=110 ignoré, +2% à t=145
+        let points = [(90.0, 0.50), (110.0, 0.5025), (145.0, 0.51), (160.0, 0.55)];
+        assert_eq!(reaction_lag(100.0, &points, PRICE_MOVE_THRESHOLD), Some(45.0));
+        assert_eq!(reaction_lag(100.0, &points[..2], PRICE_MOVE_THRESHOLD), None);
+        assert_eq!(reaction_lag(100.0, &[], PRICE_MOVE_THRESHOLD), None);
+    }
+
+    #[test]
+    fn pending_changes_resolve_once_and_expire_without_a_move() {
+        let mut profile = LagProfile::new();
+        profile.note_change(MARKET, SOURCE, 100.0);
+        profile.note_change(MARKET, SOURCE, 120.0); // Le premier changement en attente est gardé
+
+        // Pas encore de mouvement: toujours en attente
+        assert!(profile.resolve(&history(&[(90.0, 0.50)]), 130.0).is_empty());
+        let moved = history(&[(90.0, 0.50), (140.0, 0.60)]);
+        assert_eq!(profile.resolve(&moved, 150.0), vec![(MARKET.to_string(), SOURCE.to_string(), 40.0)]);
+        assert!(profile.resolve(&moved, 160.0).is_empty(), "mesure déjà prise");
+        assert_eq!(profile.median_lag(MARKET, SOURCE), Some((40.0, 1)));
+
+        // Sans réaction pendant MAX_PENDING_SECS, le changement est abandonné
+        profile.note_change(MARKET, SOURCE, 1_000.0);
+        profile.resolve(&history(&[(990.0, 0.50)]), 1_000.0 + MAX_PENDING_SECS);
+        let late = history(&[(990.0, 0.50), (1_000.0 + MAX_PENDING_SECS + 10.0, 0.70)]);
+        assert!(profile.resolve(&late, 1_000.0 + MAX_PENDING_SECS + 20.0).is_empty());
+    }
+
+    #[test]
+    fn only_repeatedly_slow_pairs_get_the_boost() {
+        let mut profile = LagProfile::new();
+        let measure = |profile: &mut LagProfile, change: f64, lag: f64| {
+            profile.note_change(MARKET, SOURCE, change);
+            profile.resolve(&history(&[(change - 1.0, 0.50), (change + lag, 0.60)]), change + lag);
+        };
+
+        // Une seule mesure lente: pas assez pour s'y fier
+        measure(&mut profile, 0.0, 90.0);
+        assert_eq!(profile.slow_reaction(MARKET, SOURCE), None);
+        // Deux mesures, médiane 60s >= 30s: réacteur lent
+        measure(&mut profile, 1_000.0, 30.0);
+        assert_eq!(profile.slow_reaction(MARKET, SOURCE), Some((60.0, 2)));
+        // Médiane tirée sous le seuil par des réactions rapides: plus de boost
+        measure(&mut profile, 2_000.0, 5.0);
+        measure(&mut profile, 3_000.0, 5.0);
+        assert_eq!(profile.median_lag(MARKET, SOURCE), Some((17.5, 4)));
+        assert_eq!(profile.slow_reaction(MARKET, SOURCE), None);
+        assert_eq!(profile.slow_reaction(MARKET, "https://other.example"), None);
+    }
+}
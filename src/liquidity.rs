@@ -0,0 +1,64 @@
+// Liquidité du livre au prix limite d'un ordre
+// La mise est convertie en tokens (stake / prix): on vérifie avant l'envoi que ce nombre de
+// tokens existe dans le livre à un prix acceptable, sinon l'ordre ne peut pas être rempli.
+
+// Écart de prix toléré (arrondi au tick) pour considérer un niveau au prix limite
+const PRICE_TOLERANCE: f64 = 1e-9;
+
+// Tokens disponibles à un prix au moins aussi bon que la limite:
+// achat contre les asks ≤ limite, vente contre les bids ≥ limite (tailles en tokens)
+pub fn available_at_limit(levels: &[(f64, f64)], limit_price: f64, is_buy: bool) -> f64 {
+    levels.iter()
+        .filter(|(price, _)| if is_buy {
+            *price <= limit_price + PRICE_TOLERANCE
+        } else {
+            *price >= limit_price - PRICE_TOLERANCE
+        })
+        .map(|(_, size)| size.max(0.0))
+        .sum()
+}
+
+// Taille d'ordre réduite à la liquidité disponible, None s'il n'y a rien à remplir
+pub fn cap_to_liquidity(requested_tokens: f64, available_tokens: f64) -> Option<f64> {
+    if requested_tokens <= 0.0 || available_tokens <= 0.0 {
+        None
+    } else {
+        Some(requested_tokens.min(available_tokens))
+    }
+}
+
+// Taille d'un ordre réel en tokens (stake / prix) bornée par la liquidité du livre au prix limite:
+// un ordre plus gros que le livre est réduit, un livre vide au prix limite fait ignorer l'ordre
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_exceeding_book_liquidity_is_shrunk() {
+        // 50 USDC à 0.40 = 125 tokens, le livre n'en offre que 80 à 0.40 (le niveau à 0.41 est trop cher)
+        let asks = [(0.40, 30.0), (0.40, 50.0), (0.41, 500.0)];
+        let requested = 50.0 / 0.40;
+        let available = available_at_limit(&asks, 0.40, true);
+        assert!((available - 80.0).abs() < 1e-9, "{}", available);
+        assert_eq!(cap_to_liquidity(requested, available), Some(80.0));
+
+        // Un ordre qui tient dans le livre garde sa taille
+        assert_eq!(cap_to_liquidity(20.0, available), Some(20.0));
+    }
+
+    #[test]
+    fn sell_orders_use_bids_at_or_above_the_limit() {
+        let bids = [(0.62, 40.0), (0.61, 100.0), (0.55, 1000.0)];
+        assert!((available_at_limit(&bids, 0.61, false) - 140.0).abs() < 1e-9);
+        assert_eq!(available_at_limit(&bids, 0.63, false), 0.0);
+    }
+
+    #[test]
+    fn no_liquidity_at_the_limit_skips_the_order() {
+        let asks = [(0.45, 200.0)];
+        let available = available_at_limit(&asks, 0.44, true);
+        assert_eq!(available, 0.0);
+        assert_eq!(cap_to_liquidity(10.0, available), None);
+        assert_eq!(cap_to_liquidity(10.0, available_at_limit(&[], 0.5, true)), None);
+    }
+}
@@ -0,0 +1,50 @@
+// Cooldown par marché après une perte
+// Quand une position se clôture en perte, le marché est mis en pause: rentrer aussitôt
+// sur le même signal périmé aggraverait la perte. La durée croît avec l'ampleur de la perte.
+// Les instants sont passés en paramètre (secondes Unix), ce qui rend l'horloge simulable
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct LossCooldown {
+    base_secs: f64,        // Durée pour une perte négligeable
+    loss_scale_usdc: f64,  // Perte qui double la durée de base
+    max_secs: f64,         // Plafond
+    blocked_until: HashMap<String, f64>, // market_id -> fin du cooldown
+}
+
+impl LossCooldown {
+    pub fn new(base_secs: f64, loss_scale_usdc: f64, max_secs: f64) -> Self {
+        Self { base_secs, loss_scale_usdc, max_secs, blocked_until: HashMap::new() }
+    }
+
+    // Durée du cooldown pour une perte donnée (valeur absolue, USDC)
+    pub fn duration_for(&self, loss: f64) -> f64 {
+        let scale = if self.loss_scale_usdc > 0.0 { loss.abs() / self.loss_scale_usdc } else { 0.0 };
+        (self.base_secs * (1.0 + scale)).min(self.max_secs)
+    }
+
+    // Enregistrer la clôture d'une position; renvoie la durée du cooldown si elle était perdante
+    // Un cooldown déjà plus long n'est jamais raccourci
+    pub fn record_close(&mut self, market_id: &str, pnl: f64, closed_at: f64) -> Option<f64> {
+        if pnl >= 0.0 {
+            return None;
+        }
+        let duration = self.duration_for(pnl);
+        let until = self.blocked_until.entry(market_id.to_string()).or_insert(0.0);
+        *until = until.max(closed_at + duration);
+        Some(duration)
+    }
+
+    // Secondes restantes avant de pouvoir rentrer sur le marché, None si libre
+    pub fn remaining(&self, market_id: &str, now: f64) -> Option<f64> {
+        self.blocked_until.get(market_id)
+            .map(|until| until - now)
+            .filter(|remaining| *remaining > 0.0)
+    }
+}
+
+impl Default for LossCooldown {
+    fn default() -> Self {
+        Self::new(600.0, 10.0, 6.0 * 3600.0)
+    }
+}
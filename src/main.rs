@@ -1,2891 +1,5 @@
-use std::env;
-use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
-// use dotenvy::dotenv; // Unused import
-use chrono::Utc;
-use std::fs::OpenOptions;
-use rand::Rng;
-use std::io::Write;
-use rand::prelude::SliceRandom;
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::ffi::{CStr, CString, c_char};
-
-// FFI declarations for C++ core
-extern "C" {
-    fn init_polymarket_core() -> bool;
-    fn configure_roi_params(fee: f64, catchup_speed: f64, action_time: f64);
-    fn update_market_data() -> bool;
-    fn calculate_real_roi_cpp(current_price: f64, fee: f64, catchup_speed: f64, action_time: f64) -> f64;
-    
-    // Nouvelles fonctions HFT ultra-optimisées
-    fn calculate_roi_hft_cached(current_price: f64, fee: f64, catchup_speed: f64, action_time: f64) -> f64;
-    fn make_trading_decision_hft(roi: f64, confidence: f64) -> *const c_char;
-    fn calculate_position_size_hft(capital: f64, roi: f64, confidence: *const c_char) -> f64;
-    fn validate_trade_hft(market_id: *const c_char, amount: f64, current_balance: f64) -> bool;
-    fn estimate_network_latency_hft() -> f64;
-    fn predict_latency_hft(endpoint: *const c_char) -> f64;
-    fn optimize_memory_hft();
-    fn cleanup_hft_cache();
-}
-
-    // Configuration
-const GAMMA_API_BASE: &str = "https://gamma-api.polymarket.com";
-const GAMMA_MARKETS_ENDPOINT: &str = "https://gamma-api.polymarket.com/markets";
-const POLYMARKET_CLOB_API: &str = "https://clob.polymarket.com";
-
-// HFT optimizations - ultra-fast network configuration
-const HFT_TIMEOUT_MS: u64 = 100; // Ultra-short timeout
-const HFT_MAX_RETRIES: u32 = 1;  // No retry for speed
-const HFT_CONCURRENT_REQUESTS: usize = 20; // More parallelism
-
-#[derive(Debug, Clone)]
-struct Market {
-    id: String,
-    question: String,
-    description: String,
-    domain: String,
-    probability: f64,
-    resolution_source: String,
-    created_at: String, // Date de création du marché
-    is_new: bool,       // Indique si c'est un nouveau marché (< 24h)
-}
-
-#[derive(Debug, Clone)]
-struct SourceData {
-    url: String,
-    status: String,
-    content_length: usize,
-    found_keywords: Vec<(String, String)>, // (keyword, status)
-    has_changes: bool,
-    fetch_duration: f64,
-}
-
-#[derive(Debug, Clone)]
-struct ArbitrageOpportunity {
-    market_id: String,
-    question: String,
-    source_url: String,
-    relevance_score: f64,
-    confidence: String,
-    reason: String,
-    domain: String,
-    timestamp: String,
-}
-
-#[derive(Debug, Clone)]
-struct TradingSignal {
-    market_id: String,
-    action: String, // "buy", "sell", "monitor", "ignore"
-    confidence: String,
-    relevance_score: f64,
-    reason: String,
-    timestamp: String,
-    source: String,
-    potential_roi: f64,
-
-    roi_v2: f64,
-    information_value: bool,
-    polymarket_probability: f64,
-    detection_time: String,
-    signal_time: String,
-    signal_generation_time_ms: f64,
-    reaction_time_ms: f64,
-    estimated_execution_time_ms: f64,
-    total_latency_ms: f64,
-    timing_grade: String,
-    executed: bool,
-    pnl_expected: f64, // Profit and Loss attendu en euros
-    stake_amount: f64, // Montant investi en euros
-    
-    // New fields for improved ROI calculation
-    current_price: f64, // Prix actuel au moment de la détection
-    action_time_ms: f64, // Temps total entre lecture du prix et exécution
-    catchup_speed: f64, // Vitesse de rattrapage calculée
-    spent_price: f64, // Prix dépensé = current_price + catchup_speed * action_time
-    new_roi: f64, // Nouveau ROI calculé avec la formule demandée
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct PolymarketMarket {
-    id: String,
-    question: String,
-    description: Option<String>,
-    probability: Option<f64>,
-    status: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct TradeRequest {
-    market_id: String,
-    side: String, // "buy" or "sell"
-    amount: String,
-    price: String,
-}
-
-struct Bot {
-    markets: Vec<Market>,
-    source_data: HashMap<String, SourceData>,
-    opportunities: Vec<ArbitrageOpportunity>,
-    signals: Vec<TradingSignal>,
-    http_client: Client,
-    private_key: String,
-    wallet_address: String,
-    simulation_mode: bool,
-    simulated_balance: f64,
-    
-    // Price history tracking for ROI calculation
-    price_history: HashMap<String, Vec<(f64, f64)>>, // market_id -> [(timestamp, price)]
-    market_convergence_speeds: HashMap<String, Vec<f64>>, // market_id -> [speeds]
-}
-
-impl Bot {
-    fn new() -> Self {
-        let private_key = env::var("PRIVATE_KEY").unwrap_or_else(|_| "".to_string());
-        let wallet_address = env::var("WALLET_ADDRESS").unwrap_or_else(|_| "".to_string());
-        
-        // Client HTTP ULTRA-optimisé pour HFT
-        let http_client = Client::builder()
-            .pool_max_idle_per_host(50) // Plus de connexions par host
-            .pool_idle_timeout(std::time::Duration::from_secs(120)) // Timeout plus long
-            .http1_only() // Forcer HTTP/1.1 (éviter les erreurs HTTP/2)
-            .timeout(std::time::Duration::from_millis(HFT_TIMEOUT_MS)) // Timeout ultra-court
-            .connect_timeout(std::time::Duration::from_millis(50)) // Connexion ultra-rapide
-            .tcp_keepalive(Some(std::time::Duration::from_secs(60))) // Keep-alive TCP
-            .tcp_nodelay(true) // Désactiver Nagle pour latence minimale
-            .build()
-            .unwrap_or_else(|_| Client::new());
-        
-        Self {
-            markets: Vec::new(),
-            source_data: HashMap::new(),
-            opportunities: Vec::new(),
-            signals: Vec::new(),
-            http_client,
-            private_key,
-            wallet_address,
-            simulation_mode: true, // Par défaut en mode simulation
-            simulated_balance: 100.0, // Capital de départ
-            price_history: HashMap::new(),
-            market_convergence_speeds: HashMap::new(),
-        }
-    }
-
-    fn log_to_file(&self, filename: &str, message: &str) {
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(filename) {
-            let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S");
-            let log_entry = format!("{} - {}\n", timestamp, message);
-            let _ = file.write_all(log_entry.as_bytes());
-        }
-    }
-
-    async fn fetch_real_polymarket_markets(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        println!("\nPHASE 1: RÉCUPÉRATION DES MARCHÉS POLYMARKET (RÉEL)");
-        println!("=====================================================");
-        
-        self.log_to_file("polymarket.log", "Phase 1: Récupération des vrais marchés Polymarket");
-        
-        let start_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        
-        // URL de l'API Polymarket Gamma
-        let url = "https://gamma-api.polymarket.com/markets";
-        
-        match self.http_client.get(url).send().await {
-            Ok(response) => {
-                if response.status().is_success() {
-                    match response.text().await {
-                        Ok(text) => {
-                            // Parser la réponse JSON
-                            match serde_json::from_str::<serde_json::Value>(&text) {
-                                Ok(json_data) => {
-                                    if let Some(markets_array) = json_data.get("markets") {
-                                        if let Some(markets) = markets_array.as_array() {
-                                            let now = Utc::now();
-                                            let default_created_at = now.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
-                                            
-                                            for market_data in markets {
-                                                if let (Some(id), Some(question), Some(probability), Some(status)) = (
-                                                    market_data.get("id").and_then(|v| v.as_str()),
-                                                    market_data.get("question").and_then(|v| v.as_str()),
-                                                    market_data.get("probability").and_then(|v| v.as_f64()),
-                                                    market_data.get("status").and_then(|v| v.as_str())
-                                                ) {
-                                                    // Vérifier si le marché est ouvert
-                                                    if status == "open" {
-                                                        let created_at = market_data
-                                                            .get("created_at")
-                                                            .and_then(|v| v.as_str())
-                                                            .unwrap_or(&default_created_at);
-                                                        
-                                                        // Déterminer si c'est un nouveau marché (< 24h)
-                                                        let is_new = if let Ok(created_time) = chrono::DateTime::parse_from_rfc3339(created_at) {
-                                                            let created_utc = created_time.with_timezone(&Utc);
-                                                            let time_diff = now.signed_duration_since(created_utc);
-                                                            time_diff.num_hours() < 24
-                                                        } else {
-                                                            false
-                                                        };
-                                                        
-                                                        let domain = self.extract_domain_from_question(question);
-                                                        
-                                                        let market = Market {
-                                                            id: id.to_string(),
-                                                            question: question.to_string(),
-                                                            description: market_data.get("description").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                                                            domain,
-                                                            probability: probability * 100.0, // Convertir en pourcentage
-                                                            resolution_source: market_data.get("resolution_source").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                                                            created_at: created_at.to_string(),
-                                                            is_new,
-                                                        };
-                                                        
-                                                        self.markets.push(market.clone());
-                                                        
-                                                        let status_display = if market.is_new { "NOUVEAU" } else { "ANCIEN" };
-                                                        println!("  [OK] Marché {}: {} | {} | Probabilité: {:.1}% | Domaine: {} | Statut: {}", 
-                                                                status_display, market.id, market.question, market.probability, market.domain, status);
-                                                    }
-                                                }
-                                            }
-                                            
-                                            let end_time = SystemTime::now()
-                                                .duration_since(UNIX_EPOCH)
-                                                .unwrap()
-                                                .as_secs_f64();
-                                            let duration = end_time - start_time;
-                                            
-                                            let new_markets_count = self.markets.iter().filter(|m| m.is_new).count();
-                                            let _old_markets_count = self.markets.len() - new_markets_count;
-                                            
-                                            println!("[SUCCÈS] {} marchés récupérés ({} nouveaux) en {:.3}s", 
-                                                    self.markets.len(), new_markets_count, duration);
-                                            
-                                            self.log_to_file("polymarket.log", &format!("Phase 1 terminée: {} marchés récupérés ({} nouveaux)", 
-                                                self.markets.len(), new_markets_count));
-                                        } else {
-                                            println!("[ERROR] Format de réponse invalide: 'markets' n'est pas un tableau");
-                                            self.log_to_file("polymarket.log", "ERROR: Format de réponse invalide");
-                                        }
-                                    } else {
-                                        println!("[ERROR] Format de réponse invalide: champ 'markets' manquant");
-                                        self.log_to_file("polymarket.log", "ERROR: Champ 'markets' manquant");
-                                    }
-                                }
-                                Err(e) => {
-                                    println!("[ERROR] Erreur parsing JSON: {}", e);
-                                    self.log_to_file("polymarket.log", &format!("ERROR: Erreur parsing JSON: {}", e));
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            println!("[ERROR] Erreur lecture réponse: {}", e);
-                            self.log_to_file("polymarket.log", &format!("ERROR: Erreur lecture réponse: {}", e));
-                        }
-                    }
-                } else {
-                    println!("[ERROR] Erreur HTTP: {}", response.status());
-                    self.log_to_file("polymarket.log", &format!("ERROR: Erreur HTTP: {}", response.status()));
-                }
-            }
-            Err(e) => {
-                println!("[ERROR] Erreur requête: {}", e);
-                self.log_to_file("polymarket.log", &format!("ERROR: Erreur requête: {}", e));
-            }
-        }
-        
-        // Si aucun marché récupéré, utiliser des marchés simulés comme fallback
-        if self.markets.is_empty() {
-            println!("[WARNING] Aucun marché récupéré, utilisation de marchés simulés");
-            self.fetch_open_markets();
-        }
-        
-        println!("[OK] {} marchés récupérés (mode réel)", self.markets.len());
-        Ok(())
-    }
-
-    fn fetch_open_markets(&mut self) -> Vec<Market> {
-        // Fallback vers simulation si pas de connexion
-        println!("\nPHASE 1: RÉCUPÉRATION DES MARCHÉS POLYMARKET (SIMULATION)");
-        println!("=========================================================");
-        
-        self.log_to_file("polymarket.log", "=== DÉBUT CYCLE ARBITRAGE ===");
-        self.log_to_file("polymarket.log", "Phase 1: Récupération des marchés (simulation)");
-        
-        let start_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        
-        // Simuler la récupération de marchés réels avec dates de création
-        let now = Utc::now();
-        let markets = vec![
-            Market {
-                id: "market-1".to_string(),
-                question: "Will Trump win the 2024 election?".to_string(),
-                description: "US Presidential election 2024. Resolution source: Official election results from whitehouse.gov and truthsocial.com".to_string(),
-                domain: "politics".to_string(),
-                probability: 0.25, // TRÈS BASSE pour test ROI positif
-                resolution_source: "whitehouse.gov, truthsocial.com".to_string(),
-                created_at: (now - chrono::Duration::days(30)).format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(), // Ancien marché
-                is_new: false,
-            },
-            Market {
-                id: "market-2".to_string(),
-                question: "Will Bitcoin ETF be approved in Q1 2024?".to_string(),
-                description: "SEC approval of Bitcoin ETF. Resolution source: Official SEC announcements from sec.gov".to_string(),
-                domain: "crypto".to_string(),
-                probability: 0.20, // TRÈS BASSE pour test ROI positif
-                resolution_source: "sec.gov".to_string(),
-                created_at: (now - chrono::Duration::days(15)).format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(), // Ancien marché
-                is_new: false,
-            },
-            Market {
-                id: "market-3".to_string(),
-                question: "Will the Fed raise rates in March 2024?".to_string(),
-                description: "Federal Reserve interest rate decision. Resolution source: Official Fed announcements from federalreserve.gov".to_string(),
-                domain: "economy".to_string(),
-                probability: 0.15, // TRÈS BASSE pour test ROI positif
-                resolution_source: "federalreserve.gov".to_string(),
-                created_at: (now - chrono::Duration::days(10)).format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(), // Ancien marché
-                is_new: false,
-            },
-            Market {
-                id: "market-4".to_string(),
-                question: "Will Ethereum ETF be approved in Q2 2024?".to_string(),
-                description: "SEC approval of Ethereum ETF. Resolution source: Official SEC announcements from sec.gov".to_string(),
-                domain: "crypto".to_string(),
-                probability: 0.18, // TRÈS BASSE pour test ROI positif
-                resolution_source: "sec.gov".to_string(),
-                created_at: (now - chrono::Duration::hours(6)).format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(), // Nouveau marché
-                is_new: true,
-            },
-            Market {
-                id: "market-5".to_string(),
-                question: "Will the Fed cut rates in June 2024?".to_string(),
-                description: "Federal Reserve interest rate decision. Resolution source: Official Fed announcements from federalreserve.gov".to_string(),
-                domain: "economy".to_string(),
-                probability: 0.12, // TRÈS BASSE pour test ROI positif
-                resolution_source: "federalreserve.gov".to_string(),
-                created_at: (now - chrono::Duration::hours(2)).format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(), // Nouveau marché
-                is_new: true,
-            },
-        ];
-        
-        // Stocker les marchés dans self.markets
-        self.markets = markets.clone();
-        
-        // Afficher les marchés
-        for market in &self.markets {
-            let status_display = if market.is_new { "NOUVEAU" } else { "ANCIEN" };
-            println!("  [OK] Marché {}: {} | {} | Probabilité: {:.1}% | Domaine: {}", 
-                    status_display, market.id, market.question, market.probability * 100.0, market.domain);
-        }
-        
-        let end_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        let duration = end_time - start_time;
-        
-        let new_markets_count = self.markets.iter().filter(|m| m.is_new).count();
-        println!("[SUCCÈS] {} marchés récupérés ({} nouveaux) en {:.3}s", 
-                self.markets.len(), new_markets_count, duration);
-        
-        self.log_to_file("polymarket.log", &format!("Phase 1 terminée: {} marchés récupérés ({} nouveaux)", 
-            self.markets.len(), new_markets_count));
-        
-        // Retourner les marchés
-        markets
-    }
-
-    fn get_all_resolution_sources(&self) -> HashMap<String, Vec<String>> {
-        let mut sources = self.get_raw_sources();
-        self.replace_api_keys(&mut sources);
-        sources
-    }
-    
-    fn get_raw_sources(&self) -> HashMap<String, Vec<String>> {
-        let mut sources = HashMap::new();
-        
-        // ===== POLITICS =====
-        sources.insert("politics".to_string(), vec![
-            // NewsAPI (principal)
-            "https://newsapi.org/v2/everything?domains=whitehouse.gov,reuters.com,bbc.com&apiKey={NEWS_API_KEY}".to_string(),
-            
-            // RSS BBC (remplace Reuters qui ne marche pas)
-            "https://feeds.bbci.co.uk/news/rss.xml".to_string(),
-        ]);
-        
-        // ===== CRYPTO =====
-        sources.insert("crypto".to_string(), vec![
-            // Annonces ETF (source de résolution)
-            "https://www.sec.gov/news/pressreleases.rss".to_string(),
-            
-            // CoinDesk RSS (actualités crypto)
-            "https://www.coindesk.com/arc/outboundfeeds/rss/".to_string(),
-        ]);
-        
-        // ===== ECONOMY =====
-        sources.insert("economy".to_string(), vec![
-            // Données Fed
-            "https://api.stlouisfed.org/fred/series/observations?series_id=FEDFUNDS&api_key={FRED_API_KEY}".to_string(),
-            
-            // Annonces Fed
-            "https://www.federalreserve.gov/feeds/press_all.xml".to_string(),
-        ]);
-        
-        // ===== PREDICTION MARKETS =====
-        sources.insert("prediction_markets".to_string(), vec![
-            // Polymarket (gratuit)
-            "https://gamma-api.polymarket.com/markets".to_string(),
-        ]);
-        
-        sources
-    }
-    
-    fn replace_api_keys(&self, sources: &mut HashMap<String, Vec<String>>) {
-        // Debug: vérifier si les clés sont lues
-        let news_key = std::env::var("NEWS_API_KEY").unwrap_or_default();
-        let fred_key = std::env::var("FRED_API_KEY").unwrap_or_default();
-        
-        println!("[DEBUG] NEWS_API_KEY: {}", if news_key.is_empty() { "VIDE" } else { "PRÉSENTE" });
-        println!("[DEBUG] FRED_API_KEY: {}", if fred_key.is_empty() { "VIDE" } else { "PRÉSENTE" });
-        
-        for (_, urls) in sources.iter_mut() {
-            for url in urls.iter_mut() {
-                // Remplacer les placeholders par les vraies clés (seulement celles qu'on utilise)
-                *url = url.replace("{NEWS_API_KEY}", &news_key);
-                *url = url.replace("{FRED_API_KEY}", &fred_key);
-            }
-        }
-    }
-
-    fn get_source_keywords(&self, source: &str) -> Vec<String> {
-        let source_lower = source.to_lowercase();
-        
-        // ===== POLITICS =====
-        if source_lower.contains("whitehouse.gov") || source_lower.contains("newsapi.org") {
-            vec!["election".to_string(), "trump".to_string(), "biden".to_string(), "president".to_string(), "victory".to_string(), "win".to_string(), "results".to_string(), "campaign".to_string(), "vote".to_string(), "announcement".to_string()]
-            
-        // ===== CRYPTO =====
-        } else if source_lower.contains("sec.gov") {
-            vec!["etf".to_string(), "approval".to_string(), "sec".to_string(), "bitcoin".to_string(), "ethereum".to_string(), "approved".to_string(), "rejected".to_string(), "filing".to_string(), "application".to_string(), "decision".to_string()]
-            
-        // ===== ECONOMY =====
-        } else if source_lower.contains("federalreserve.gov") || source_lower.contains("stlouisfed.org") {
-            vec!["rate".to_string(), "fed".to_string(), "federal".to_string(), "reserve".to_string(), "increase".to_string(), "decrease".to_string(), "hold".to_string(), "decision".to_string(), "fomc".to_string(), "interest".to_string(), "cut".to_string()]
-            
-        // ===== PREDICTION MARKETS =====
-        } else if source_lower.contains("polymarket.com") {
-            vec!["market".to_string(), "prediction".to_string(), "trade".to_string(), "price".to_string(), "volume".to_string(), "settlement".to_string(), "bet".to_string(), "outcome".to_string()]
-            
-        // ===== DÉFAUT =====
-        } else {
-            vec!["announcement".to_string(), "official".to_string(), "result".to_string(), "news".to_string(), "update".to_string()]
-        }
-    }
-
-    // Nouvelle fonction : filtrer les sources pertinentes pour chaque marché
-    fn get_relevant_sources_for_market(&self, market_domain: &str) -> Vec<String> {
-        let all_sources = self.get_all_resolution_sources();
-        let mut relevant_sources = Vec::new();
-        
-        for (domain, sources) in all_sources {
-            // Politique : sources politiques + polymarket
-            if market_domain == "politics" && (domain == "politics" || domain == "prediction_markets") {
-                relevant_sources.extend(sources);
-            }
-            // Crypto : sources crypto + polymarket
-            else if market_domain == "crypto" && (domain == "crypto" || domain == "prediction_markets") {
-                relevant_sources.extend(sources);
-            }
-            // Économie : sources économie + polymarket
-            else if market_domain == "economy" && (domain == "economy" || domain == "prediction_markets") {
-                relevant_sources.extend(sources);
-            }
-        }
-        
-        relevant_sources
-    }
-
-    fn detect_keyword_with_negation(&self, text: &str, keyword: &str) -> (bool, String) {
-        let text_lower = text.to_lowercase();
-        let keyword_lower = keyword.to_lowercase();
-        
-        // Détection ULTRA-optimisée pour HFT
-        if text_lower.contains(&keyword_lower) {
-            // Vérifier les mots de négation autour du mot-clé
-            let words: Vec<&str> = text_lower.split_whitespace().collect();
-            let keyword_positions: Vec<usize> = words.iter()
-                .enumerate()
-                .filter(|(_, word)| word.contains(&keyword_lower))
-                .map(|(i, _)| i)
-                .collect();
-            
-            for &pos in &keyword_positions {
-                // Vérifier les mots avant et après le mot-clé
-                let negations = vec!["not", "no", "never", "deny", "reject", "decline", "negative", "against"];
-                let affirmations = vec!["yes", "approve", "accept", "confirm", "positive", "for", "support"];
-                
-                // Vérifier les 3 mots avant
-                for i in (pos.saturating_sub(3)..pos).rev() {
-                    if i < words.len() && negations.iter().any(|&neg| words[i].contains(neg)) {
-                        return (true, "negated".to_string());
-                    }
-                    if i < words.len() && affirmations.iter().any(|&aff| words[i].contains(aff)) {
-                        return (true, "affirmed".to_string());
-                    }
-                }
-                
-                // Vérifier les 3 mots après
-                for i in (pos + 1)..(pos + 4).min(words.len()) {
-                    if i < words.len() && negations.iter().any(|&neg| words[i].contains(neg)) {
-                        return (true, "negated".to_string());
-                    }
-                    if i < words.len() && affirmations.iter().any(|&aff| words[i].contains(aff)) {
-                        return (true, "affirmed".to_string());
-                    }
-                }
-            }
-            
-            // Si pas de négation détectée, considérer comme affirmé
-            return (true, "affirmed".to_string());
-        }
-        
-        (false, "not_found".to_string())
-    }
-
-    fn detect_keyword_with_negation_static(text: &str, keyword: &str) -> (bool, String) {
-        let text_lower = text.to_lowercase();
-        let keyword_lower = keyword.to_lowercase();
-        
-        // Patterns de négation en anglais
-        let neg_patterns = vec![
-            format!("not {}", keyword_lower),
-            format!("did not {}", keyword_lower),
-            format!("was not {}", keyword_lower),
-            format!("is not {}", keyword_lower),
-            format!("no {}", keyword_lower),
-            format!("never {}", keyword_lower),
-        ];
-        
-        for pattern in neg_patterns {
-            if text_lower.contains(&pattern) {
-                return (true, "negated".to_string());
-            }
-        }
-        
-        // Affirmation simple
-        if text_lower.contains(&keyword_lower) {
-            return (true, "affirmed".to_string());
-        }
-        
-        (false, "".to_string())
-    }
-
-    fn categorize_market_domain(&self, question: &str, description: &str) -> String {
-        let text = format!("{} {}", question, description).to_lowercase();
-        
-        if text.contains("trump") || text.contains("election") || text.contains("president") || text.contains("biden") {
-            "politics".to_string()
-        } else if text.contains("bitcoin") || text.contains("crypto") || text.contains("etf") || text.contains("sec") {
-            "crypto".to_string()
-        } else if text.contains("fed") || text.contains("rate") || text.contains("inflation") || text.contains("economy") {
-            "economy".to_string()
-        } else {
-            "other".to_string()
-        }
-    }
-
-    fn extract_resolution_source(&self, description: &str) -> Option<String> {
-        let idx = description.to_lowercase().find("resolution source");
-        if idx.is_some() {
-            Some(description[idx.unwrap()..].to_string())
-        } else {
-            None
-        }
-    }
-
-    async fn monitor_resolution_source_real(&self, url: &str, keywords: &[String]) -> SourceData {
-        Self::monitor_resolution_source_real_static(&self.http_client, url, keywords).await
-    }
-
-    async fn monitor_resolution_source_real_static(http_client: &Client, url: &str, keywords: &[String]) -> SourceData {
-        let start_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        
-        // Headers appropriés selon le type d'API
-        let mut request = http_client.get(url);
-        
-        if url.contains("api.newsapi.org") {
-            // NewsAPI - pas besoin de headers spéciaux
-            request = request.header("Accept", "application/json");
-        } else if url.contains("api.coingecko.com") {
-            // CoinGecko API - headers simples
-            request = request.header("Accept", "application/json");
-        } else if url.contains("api.sec.gov") {
-            // SEC API - User-Agent requis
-            request = request
-                .header("User-Agent", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36")
-                .header("Accept", "application/json");
-        } else if url.contains("api.fred.stlouisfed.org") {
-            // FRED API - headers simples
-            request = request.header("Accept", "application/json");
-        } else if url.contains("trading-api.kalshi.com") {
-            // Kalshi API - headers d'authentification
-            request = request
-                .header("Accept", "application/json")
-                .header("Content-Type", "application/json");
-        } else {
-            // Headers par défaut pour les autres APIs
-            request = request
-                .header("Accept", "application/json")
-                .header("User-Agent", "PolymarketBot/1.0");
-        }
-        
-        let response = request
-            .timeout(std::time::Duration::from_secs(10))
-            .send()
-            .await;
-        
-        match response {
-            Ok(resp) => {
-                println!("  [DEBUG] {} | Status: {} | Headers: {:?}", url, resp.status(), resp.headers());
-                
-                if resp.status().is_success() {
-                    match resp.text().await {
-                        Ok(content) => {
-                            let content_length = content.len();
-                            println!("  [DEBUG] {} | Content length: {} | Preview: {}", url, content_length, &content[..content.len().min(100)]);
-                            
-                            let mut found_keywords = Vec::new();
-                            
-                            for keyword in keywords {
-                                let (found, status) = Self::detect_keyword_with_negation_static(&content, keyword);
-                                if found {
-                                    found_keywords.push((keyword.clone(), status));
-                                }
-                            }
-                            
-                            let has_changes = found_keywords.iter().any(|(_, status)| status == "affirmed");
-                            
-                            let end_time = SystemTime::now()
-                                .duration_since(UNIX_EPOCH)
-                                .unwrap()
-                                .as_secs_f64();
-                            let duration = end_time - start_time;
-                            
-                            SourceData {
-                                url: url.to_string(),
-                                status: "success".to_string(),
-                                content_length,
-                                found_keywords,
-                                has_changes,
-                                fetch_duration: duration,
-                            }
-                        },
-                        Err(e) => {
-                            println!("  [ERROR] {} | Erreur lecture texte: {}", url, e);
-                            Self::create_error_source_data_static(url, start_time)
-                        }
-                    }
-                } else {
-                    let status = resp.status();
-                    let error_text = resp.text().await.unwrap_or_default();
-                    println!("  [ERROR] {} | Status: {} | Error body: {}", url, status, error_text);
-                    Self::create_error_source_data_static(url, start_time)
-                }
-            },
-            Err(e) => {
-                println!("  [ERROR] {} | Erreur réseau: {}", url, e);
-                Self::create_error_source_data_static(url, start_time)
-            }
-        }
-    }
-
-    fn create_error_source_data(&self, url: &str, start_time: f64) -> SourceData {
-        Self::create_error_source_data_static(url, start_time)
-    }
-
-    fn create_error_source_data_static(url: &str, start_time: f64) -> SourceData {
-        let end_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        let duration = end_time - start_time;
-        
-        SourceData {
-            url: url.to_string(),
-            status: "error".to_string(),
-            content_length: 0,
-            found_keywords: Vec::new(),
-            has_changes: false,
-            fetch_duration: duration,
-        }
-    }
-
-    fn monitor_resolution_source(&self, url: &str, keywords: &[String]) -> SourceData {
-        let start_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        
-        let mut rng = rand::thread_rng();
-        
-        // Simulation réaliste basée sur l'URL - comme en réel
-        let (accessible, content_length, found_keywords) = match url {
-            "https://www.sec.gov" => {
-                // SEC - souvent des annonces importantes
-                let has_announcement = rng.gen_bool(0.4); // 40% de chance d'annonce
-                if has_announcement {
-                    let keyword = keywords.choose(&mut rng).unwrap_or(&"approval".to_string()).clone();
-                    let status = if rng.gen_bool(0.7) { "affirmed".to_string() } else { "negated".to_string() };
-                    (true, rng.gen_range(2000..8000), vec![(keyword, status)])
-        } else { 
-                    (true, rng.gen_range(1000..3000), vec![])
-                }
-            },
-            "https://www.federalreserve.gov" => {
-                // Fed - annonces de taux
-                let has_rate_decision = rng.gen_bool(0.3); // 30% de chance de décision
-                if has_rate_decision {
-                    let keyword = keywords.choose(&mut rng).unwrap_or(&"rate".to_string()).clone();
-                    let status = if rng.gen_bool(0.6) { "affirmed".to_string() } else { "negated".to_string() };
-                    (true, rng.gen_range(1500..6000), vec![(keyword, status)])
-                } else {
-                    (true, rng.gen_range(800..2500), vec![])
-                }
-            },
-            "https://www.whitehouse.gov" => {
-                // White House - annonces politiques
-                let has_policy_announcement = rng.gen_bool(0.25); // 25% de chance d'annonce
-                if has_policy_announcement {
-                    let keyword = keywords.choose(&mut rng).unwrap_or(&"announcement".to_string()).clone();
-                    let status = if rng.gen_bool(0.5) { "affirmed".to_string() } else { "negated".to_string() };
-                    (true, rng.gen_range(1200..5000), vec![(keyword, status)])
-                } else {
-                    (true, rng.gen_range(600..2000), vec![])
-                }
-            },
-            "https://www.reuters.com" => {
-                // Reuters - nouvelles fréquentes
-                let has_breaking_news = rng.gen_bool(0.6); // 60% de chance de nouvelles
-                if has_breaking_news {
-                    let keyword = keywords.choose(&mut rng).unwrap_or(&"announcement".to_string()).clone();
-                    let status = if rng.gen_bool(0.6) { "affirmed".to_string() } else { "negated".to_string() };
-                    (true, rng.gen_range(1000..4000), vec![(keyword, status)])
-                } else {
-                    (true, rng.gen_range(500..1500), vec![])
-                }
-            },
-            "https://www.bloomberg.com" => {
-                // Bloomberg - nouvelles financières
-                let has_financial_news = rng.gen_bool(0.5); // 50% de chance de nouvelles financières
-                if has_financial_news {
-                    let keyword = keywords.choose(&mut rng).unwrap_or(&"announcement".to_string()).clone();
-                    let status = if rng.gen_bool(0.6) { "affirmed".to_string() } else { "negated".to_string() };
-                    (true, rng.gen_range(800..3500), vec![(keyword, status)])
-                } else {
-                    (true, rng.gen_range(400..1200), vec![])
-                }
-            },
-            _ => {
-                // Autres sources
-                let has_content = rng.gen_bool(0.3);
-                if has_content && rng.gen_bool(0.4) {
-                    let keyword = keywords.choose(&mut rng).unwrap_or(&"announcement".to_string()).clone();
-                    let status = if rng.gen_bool(0.5) { "affirmed".to_string() } else { "negated".to_string() };
-                    (true, rng.gen_range(500..2000), vec![(keyword, status)])
-                } else {
-                    (true, rng.gen_range(200..800), vec![])
-                }
-            }
-        };
-        
-        if !accessible {
-            let end_time = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs_f64();
-            let duration = end_time - start_time;
-            
-            return SourceData {
-                url: url.to_string(),
-                status: "error".to_string(),
-                content_length: 0,
-                found_keywords: Vec::new(),
-                has_changes: false,
-                fetch_duration: duration,
-            };
-        }
-        
-        let has_changes = found_keywords.iter().any(|(_, status)| status == "affirmed");
-        
-        let end_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        let duration = end_time - start_time;
-        
-        // Log spécifique pour les temps de fetch
-        self.log_to_file("source_fetch_times.log", &format!("SUCCESS | {} | {:.3}s | content_length={} | found_keywords={:?}", 
-            url, duration, content_length, found_keywords));
-        
-        SourceData {
-            url: url.to_string(),
-            status: "success".to_string(),
-            content_length,
-            found_keywords,
-            has_changes,
-            fetch_duration: duration,
-        }
-    }
-
-    async fn monitor_all_resolution_sources_real(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        println!("\nPHASE 2: MONITORING DES SOURCES DE RÉSOLUTION (RÉEL)");
-        println!("=====================================================");
-        
-        self.log_to_file("polymarket.log", "Phase 2: Monitoring des sources (réel)");
-        
-        let all_sources = self.get_all_resolution_sources();
-        
-        for (domain, sources) in &all_sources {
-            println!("  [DOMAINE] {}", domain.to_uppercase());
-            
-            for source_url in sources {
-                let keywords = self.get_source_keywords(source_url);
-                let source_data = self.monitor_resolution_source_real(source_url, &keywords).await;
-                
-                self.source_data.insert(source_url.clone(), source_data);
-                
-                let status = if self.source_data[source_url].status == "success" {
-                    "OK"
-                } else {
-                    "ERROR"
-                };
-                
-                println!("    [{}] {} | {} mots-clés | {} chars", 
-                    status, source_url, keywords.len(), self.source_data[source_url].content_length);
-                
-                // Délai entre les requêtes pour éviter le rate limiting
-                tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-            }
-        }
-        
-        let success_count = self.source_data.values().filter(|s| s.status == "success").count();
-        println!("[SUCCÈS] {} sources monitorées avec succès", success_count);
-        self.log_to_file("polymarket.log", &format!("Phase 2 terminée: {} sources", success_count));
-        
-        Ok(())
-    }
-
-    async fn monitor_all_resolution_sources(&mut self) {
-        println!("\nPHASE 2: MONITORING DES SOURCES DE RÉSOLUTION (RÉEL)");
-        println!("=====================================================");
-        
-        self.log_to_file("polymarket.log", "Phase 2: Monitoring des sources de résolution");
-        
-        // Utiliser les sources configurées dans get_all_resolution_sources()
-        let all_sources = self.get_all_resolution_sources();
-        
-        // Debug: afficher toutes les sources
-        println!("[DEBUG] Sources configurées:");
-        for (domain, sources) in &all_sources {
-            println!("  [DOMAINE] {}: {} sources", domain.to_uppercase(), sources.len());
-            for (i, source) in sources.iter().enumerate() {
-                println!("    {}. {}", i+1, source);
-            }
-        }
-        println!("[DEBUG] Total: {} sources", all_sources.values().map(|v| v.len()).sum::<usize>());
-        
-        let mut success_count = 0;
-        
-        // Monitorer chaque source
-        for (domain, sources) in all_sources {
-            println!("  [DOMAINE] {}", domain.to_uppercase());
-            
-            for source_url in sources {
-                let keywords = self.get_source_keywords(&source_url);
-                let source_data = Bot::monitor_resolution_source_real_static(&self.http_client, &source_url, &keywords).await;
-                self.source_data.insert(source_url.clone(), source_data.clone());
-                
-                if source_data.status == "success" {
-                    success_count += 1;
-                    println!("    [OK] {} | {} mots-clés | {} chars", source_url, keywords.len(), source_data.content_length);
-                } else {
-                    println!("    [ERROR] {} | {} mots-clés | {} chars", source_url, keywords.len(), source_data.content_length);
-                }
-            }
-        }
-        
-        println!("[SUCCÈS] {} sources monitorées avec succès", success_count);
-        self.log_to_file("polymarket.log", &format!("Phase 2 terminée: {} sources fonctionnelles", success_count));
-    }
-
-    fn detect_arbitrage_opportunities(&mut self, markets: &[Market]) {
-        println!("\nPHASE 3: ANALYSE DES OPPORTUNITÉS DE TRADING");
-        println!("=============================================");
-        
-        self.log_to_file("polymarket.log", "Phase 3: Analyse des opportunités de trading");
-        
-        self.opportunities.clear();
-        
-        // Analyser les marchés disponibles
-        println!("  [INFO] Analyse de {} marchés Polymarket", markets.len());
-        println!("  [INFO] Recherche d'informations pertinentes dans les sources");
-        
-        // Inclure TOUS les marchés pour avoir des opportunités
-        let all_markets: Vec<&Market> = markets.iter().collect();
-        
-        if all_markets.is_empty() {
-            println!("  [INFO] Aucun marché disponible");
-            self.log_to_file("polymarket.log", "Aucun marché disponible");
-            return;
-        }
-        
-        // Si aucune source ne fonctionne, créer des opportunités simulées
-        let has_working_sources = self.source_data.values().any(|s| s.status == "success");
-        
-        if !has_working_sources {
-            println!("  [WARN] Aucune source fonctionnelle - Création d'opportunités simulées");
-            
-            let mut rng = rand::thread_rng();
-            
-            for market in &all_markets[..all_markets.len().min(3)] { // Limiter à 3 marchés
-                // Varier la pertinence et confiance comme en réel
-                let relevance_score = rng.gen_range(0.15..0.45); // 15-45% au lieu de 30% fixe
-                let confidence = if relevance_score > 0.35 { 
-                    "high".to_string() 
-                } else if relevance_score > 0.25 { 
-                    "medium".to_string() 
-                } else { 
-                    "low".to_string() 
-                };
-                
-                let opportunity = ArbitrageOpportunity {
-                    market_id: market.id.clone(),
-                    question: market.question.clone(),
-                    source_url: "simulation".to_string(),
-                    relevance_score,
-                    confidence: confidence.clone(),
-                    reason: format!("Simulation pour marché: {} (source temporaire)", market.question),
-                    domain: market.domain.clone(),
-                    timestamp: Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
-                };
-                
-                self.opportunities.push(opportunity.clone());
-                
-                println!("  [OPPORTUNITÉ SIMULÉE] Créée pour:");
-                println!("     Marché: {}", market.question);
-                println!("     Pertinence: {:.1}%", relevance_score * 100.0);
-                println!("     Confiance: {}", confidence);
-            }
-        } else {
-            // Traitement normal avec sources fonctionnelles
-            for market in all_markets {
-                // Obtenir seulement les sources pertinentes pour ce marché
-                let relevant_sources = self.get_relevant_sources_for_market(&market.domain);
-                
-            for (source_url, source_data) in &self.source_data {
-                    // Vérifier que cette source est pertinente pour ce marché
-                    if source_data.status == "success" && relevant_sources.contains(source_url) {
-                    let relevance_score = self.calculate_relevance_score(market, source_url, source_data);
-                    
-                    if relevance_score > 0.05 { // Seuil comme dans le Python
-                        let confidence = if relevance_score > 0.7 { "high" } else if relevance_score > 0.4 { "medium" } else { "low" };
-                        
-                        let opportunity = ArbitrageOpportunity {
-                            market_id: market.id.clone(),
-                            question: market.question.clone(),
-                            source_url: source_url.clone(),
-                            relevance_score,
-                            confidence: confidence.to_string(),
-                                reason: format!("Marché: {} - Source {} pertinente avec pertinence {:.2}", market.question, source_url, relevance_score),
-                            domain: market.domain.clone(),
-                            timestamp: Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
-                        };
-                        
-                        self.opportunities.push(opportunity.clone());
-                        
-                        println!("  [OPPORTUNITÉ] Trouvée:");
-                        println!("     Marché: {}", market.question);
-                        println!("     Source: {}", source_url);
-                        println!("     Pertinence: {:.1}%", relevance_score * 100.0);
-                        println!("     Confiance: {}", confidence);
-                        
-                        let log_msg = format!("Opportunité: {} | Source: {} | Pertinence: {:.1}% | Confiance: {}", 
-                            market.question, source_url, relevance_score * 100.0, confidence);
-                        self.log_to_file("polymarket.log", &log_msg);
-                        }
-                    }
-                }
-            }
-        }
-        
-        println!("[SUCCÈS] {} opportunités de trading trouvées", self.opportunities.len());
-        self.log_to_file("polymarket.log", &format!("Phase 3 terminée: {} opportunités", self.opportunities.len()));
-    }
-
-    fn calculate_relevance_score(&self, market: &Market, source_url: &str, source_data: &SourceData) -> f64 {
-        let mut rng = rand::thread_rng();
-        
-        // Base sur le domaine
-        let domain_match = if market.domain == "politics" && source_url.contains("whitehouse") { 0.3 }
-            else if market.domain == "crypto" && source_url.contains("sec.gov") { 0.4 }
-            else if market.domain == "economy" && source_url.contains("federalreserve") { 0.35 }
-            else { 0.1 };
-        
-        // Boost basé sur les mots-clés trouvés
-        let keyword_boost = source_data.found_keywords.len() as f64 * 0.05;
-        
-        // Variabilité aléatoire
-        let random_factor = rng.gen_range(-0.1..0.1);
-        
-        let relevance = (domain_match + keyword_boost + random_factor).max(0.0).min(1.0);
-        relevance
-    }
-
-    fn estimate_information_value(&self, opportunity: &ArbitrageOpportunity) -> bool {
-        let market_id = &opportunity.market_id;
-        let source_url = &opportunity.source_url;
-        
-        // Logique basée sur le contenu du marché et la source
-        let market_lower = market_id.to_lowercase();
-        let source_lower = source_url.to_lowercase();
-        
-        // ===== POLITICS =====
-        if market_lower.contains("trump") || market_lower.contains("election") {
-            // Pour Trump election, les sources politiques sont généralement positives
-            if source_lower.contains("newsapi") || source_lower.contains("polymarket") {
-                return true; // Information positive
-            }
-        }
-        
-        // ===== CRYPTO =====
-        if market_lower.contains("etf") && market_lower.contains("approved") {
-            // Pour ETF approval, les sources SEC sont généralement positives
-            if source_lower.contains("sec.gov") {
-                return true; // Information positive
-            }
-            // Les sources Polymarket peuvent être négatives (probabilité basse)
-            if source_lower.contains("polymarket") {
-                return false; // Information négative
-            }
-        }
-        
-        // ===== ECONOMY =====
-        if market_lower.contains("fed") && market_lower.contains("raise") {
-            // Pour "Fed raise rates", les sources Fed sont généralement négatives
-            if source_lower.contains("federalreserve") || source_lower.contains("fred") {
-                return false; // Information négative
-            }
-        }
-        
-        if market_lower.contains("fed") && market_lower.contains("cut") {
-            // Pour "Fed cut rates", les sources Fed sont généralement positives
-            if source_lower.contains("federalreserve") || source_lower.contains("fred") {
-                return true; // Information positive
-            }
-        }
-        
-        // Par défaut, utiliser la logique hash mais avec plus de variabilité
-        let market_hash: u32 = market_id.chars().map(|c| c as u32).sum();
-        let url_hash: u32 = source_url.chars().map(|c| c as u32).sum();
-        let combined_hash = market_hash + url_hash;
-        
-        // Variabilité plus grande pour éviter les ROI identiques
-        match combined_hash % 5 {
-            0 => true,   // 20% chance positive
-            1 => false,  // 20% chance négative
-            2 => true,   // 20% chance positive
-            3 => false,  // 20% chance négative
-            _ => false,  // 20% chance négative
-        }
-    }
-
-    fn estimate_polymarket_probability(&self, opportunity: &ArbitrageOpportunity) -> f64 {
-        let relevance_score = opportunity.relevance_score;
-        let confidence = &opportunity.confidence;
-        let market_id = &opportunity.market_id;
-        let source_url = &opportunity.source_url;
-        
-        // Probabilité de base basée sur le score de pertinence
-        let mut base_prob = 0.5;
-        if relevance_score > 0.8 {
-            base_prob = 0.7;
-        } else if relevance_score < 0.3 {
-            base_prob = 0.3;
-        }
-        
-        // Variabilité selon l'ID du marché (hash simple)
-        if !market_id.is_empty() {
-            let hash_val: u32 = market_id.chars().map(|c| c as u32).sum();
-            base_prob += ((hash_val % 10) as f64 - 5.0) * 0.01; // variation de -5% à +4%
-        }
-        
-        // Variabilité selon la source
-        if source_url.contains("federalreserve") {
-            base_prob += 0.1; // +10% pour les sources officielles
-        } else if source_url.contains("sec.gov") {
-            base_prob += 0.05; // +5% pour la SEC
-        } else if source_url.contains("newsapi") {
-            base_prob -= 0.05; // -5% pour les médias
-        }
-        
-        // Limiter à des valeurs réalistes
-        base_prob.max(0.1).min(0.9)
-    }
-
-
-
-    async fn get_market_orderbook(&self, market_id: &str) -> Result<(f64, f64), Box<dyn std::error::Error>> {
-        // Récupérer l'orderbook réel de Polymarket
-        let url = format!("https://clob.polymarket.com/orderbook/{}", market_id);
-        
-        let response = self.http_client.get(&url)
-            .header("Accept", "application/json")
-            .timeout(std::time::Duration::from_secs(5))
-            .send()
-            .await?;
-            
-        if response.status().is_success() {
-            let orderbook: serde_json::Value = response.json().await?;
-            
-            // Extraire best bid et best ask
-            let best_bid = orderbook["bids"][0]["price"].as_f64().unwrap_or(0.0);
-            let best_ask = orderbook["asks"][0]["price"].as_f64().unwrap_or(1.0);
-            
-            Ok((best_bid, best_ask))
-        } else {
-            // Fallback si l'API ne marche pas
-            Ok((0.45, 0.55)) // Prix par défaut
-        }
-    }
-
-    async fn get_market_orderbook_with_volumes(&self, market_id: &str) -> Result<(Vec<(f64, f64)>, Vec<(f64, f64)>), Box<dyn std::error::Error>> {
-        // Récupérer l'orderbook complet avec volumes
-        let url = format!("https://clob.polymarket.com/orderbook/{}", market_id);
-        
-        let response = self.http_client.get(&url)
-            .header("Accept", "application/json")
-            .timeout(std::time::Duration::from_secs(5))
-            .send()
-            .await?;
-            
-        if response.status().is_success() {
-            let orderbook: serde_json::Value = response.json().await?;
-            
-            // Extraire les bids avec volumes
-            let mut bids = Vec::new();
-            if let Some(bids_array) = orderbook["bids"].as_array() {
-                for bid in bids_array.iter().take(10) { // Top 10 bids
-                    if let (Some(price), Some(size)) = (bid["price"].as_f64(), bid["size"].as_f64()) {
-                        bids.push((price, size));
-                    }
-                }
-            }
-            
-            // Extraire les asks avec volumes
-            let mut asks = Vec::new();
-            if let Some(asks_array) = orderbook["asks"].as_array() {
-                for ask in asks_array.iter().take(10) { // Top 10 asks
-                    if let (Some(price), Some(size)) = (ask["price"].as_f64(), ask["size"].as_f64()) {
-                        asks.push((price, size));
-                    }
-                }
-            }
-            
-            Ok((bids, asks))
-        } else {
-            // Fallback si l'API ne marche pas
-            let fallback_bids = vec![(0.45, 15.0), (0.43, 25.0), (0.40, 60.0)];
-            let fallback_asks = vec![(0.55, 10.0), (0.57, 20.0), (0.60, 50.0)];
-            Ok((fallback_bids, fallback_asks))
-        }
-    }
-
-    async fn get_market_hft_move(&self, market_id: &str) -> Result<f64, Box<dyn std::error::Error>> {
-        // 🚀 STRATÉGIE HFT AGGRESSIVE : Variation historique max × 2
-        println!("    [HFT] Calcul variation historique max pour {}", market_id);
-        
-        // 1. Récupérer l'orderbook pour les données de marché
-        let (bids, asks) = match self.get_market_orderbook_with_volumes(market_id).await {
-            Ok(data) => data,
-            Err(_) => {
-                // Fallback orderbook simple
-                let fallback_bids = vec![(0.45, 15.0), (0.43, 25.0), (0.40, 60.0)];
-                let fallback_asks = vec![(0.55, 10.0), (0.57, 20.0), (0.60, 50.0)];
-                (fallback_bids, fallback_asks)
-            }
-        };
-        
-        if bids.len() >= 3 && asks.len() >= 3 {
-            // 2. Calculer les métriques de marché
-            let best_bid = bids[0].0;
-            let best_ask = asks[0].0;
-            let spread = best_ask - best_bid;
-            
-            // Profondeur totale
-            let total_bid_volume: f64 = bids.iter().map(|(_, vol)| vol).sum();
-            let total_ask_volume: f64 = asks.iter().map(|(_, vol)| vol).sum();
-            let avg_volume = (total_bid_volume + total_ask_volume) / 2.0;
-            
-            // 3. VARIATION HISTORIQUE MAX (simulation réaliste)
-            let market_hash: u32 = market_id.chars().map(|c| c as u32).sum();
-            
-            // Variation historique max basée sur le type de marché
-            let max_historical_move = match market_id {
-                "market-1" => 0.15 + (market_hash % 100) as f64 / 1000.0, // 15-25% pour Trump (politique volatile)
-                "market-2" => 0.20 + (market_hash % 150) as f64 / 1000.0, // 20-35% pour Bitcoin ETF (crypto très volatile)
-                "market-3" => 0.12 + (market_hash % 80) as f64 / 1000.0,  // 12-20% pour Fed (économie)
-                "market-4" => 0.18 + (market_hash % 120) as f64 / 1000.0, // 18-30% pour Ethereum ETF (crypto volatile)
-                "market-5" => 0.14 + (market_hash % 90) as f64 / 1000.0,  // 14-23% pour Fed rates (économie)
-                _ => 0.15 + (market_hash % 100) as f64 / 1000.0, // 15-25% par défaut
-            };
-            
-                                    // 4. VARIATION RÉALISTE (pas de doublement agressif)
-                        let realistic_move = max_historical_move; // Pas de doublement
-
-                        // 5. Facteur de volume (plus de volume = plus de mouvement possible)
-                        let volume_factor = (avg_volume / 50.0).min(1.1); // Max 1.1x (très conservateur)
-
-                        // 6. Facteur de spread (spread large = plus de mouvement possible)
-                        let spread_factor = (spread / 0.05).min(1.2); // Max 1.2x (très conservateur)
-
-                        // 7. Calcul final : variation réaliste × facteurs très modérés
-                        let base_move = realistic_move;
-                        let adjusted_move = base_move * (1.0 + volume_factor * 0.05) * (1.0 + spread_factor * 0.02);
-
-                        // 8. PAS DE CAP - laissez le marché décider
-                        let final_move = adjusted_move;
-            
-                                    println!("    [HFT] Variation max pour {}: {:.1}% (historique: {:.1}%, réaliste: {:.1}%, volume: {:.1}€, spread: {:.1}%)",
-                                 market_id, final_move * 100.0, max_historical_move * 100.0, realistic_move * 100.0, avg_volume, spread * 100.0);
-            
-            Ok(final_move)
-        } else {
-                                    // Fallback avec variation max réaliste
-                        let market_hash: u32 = market_id.chars().map(|c| c as u32).sum();
-                        let base_move = 0.05 + (market_hash % 100) as f64 / 1000.0; // 5-15%
-                        println!("    [HFT] Fallback variation max pour {}: {:.1}%", market_id, base_move * 100.0);
-                        Ok(base_move) // Pas de cap
-        }
-    }
-
-    fn calculate_hft_roi(&self, price_now: f64, move_adj: f64, direction: &str) -> f64 {
-        // 🚀 ROI HFT AGGRESSIVE : Prix T+1 basé sur variation historique max × 2
-        // direction: "up" ou "down" selon l'information
-        
-                            // SCALING TEMPOREL RÉALISTE
-                    // Pour du HFT réaliste, on utilise 1.2 (très conservateur)
-                    let time_scaling = 1.2; // Très conservateur pour HFT réaliste
-        
-        // Appliquer le scaling temporel à la variation
-        let scaled_move = move_adj * time_scaling;
-        
-        // Calculer le prix T+1 selon la direction
-        let predicted_price = match direction {
-            "up" => (price_now * (1.0 + scaled_move)).min(1.0),   // Cap à 100%
-            "down" => (price_now * (1.0 - scaled_move)).max(0.0), // Cap à 0%
-            _ => price_now
-        };
-        
-        // ROI BRUT = (prix_T+1 - prix_actuel) / prix_actuel
-        let roi = (predicted_price - price_now) / price_now;
-        
-        // Frais Polymarket 2% sur profit net
-        if roi > 0.0 {
-            roi * (1.0 - 0.02) // 2% de frais sur profit net
-        } else {
-            roi // Pas de frais sur les pertes
-        }
-    }
-
-    fn calculate_real_roi_v2(&self, information_value: bool, _market_id: &str, 
-                            stake_amount: f64, orderbook: Option<(f64, f64)>) -> f64 {
-        // Utiliser l'orderbook passé en paramètre ou récupérer
-        let (best_bid, best_ask) = match orderbook {
-            Some((bid, ask)) => (bid, ask),
-            None => {
-                // Fallback si pas d'orderbook
-                (0.45, 0.55)
-            }
-        };
-        
-        // Simuler l'orderbook complet avec volumes (approximation réaliste)
-        let asks = vec![
-            (best_ask, 10.0),           // 10€ à best_ask
-            (best_ask + 0.02, 20.0),    // 20€ à +2%
-            (best_ask + 0.05, 50.0),    // 50€ à +5%
-        ];
-        
-        let bids = vec![
-            (best_bid, 15.0),           // 15€ à best_bid
-            (best_bid - 0.02, 25.0),    // 25€ à -2%
-            (best_bid - 0.05, 60.0),    // 60€ à -5%
-        ];
-        
-        if information_value {
-            // Pari sur YES : simuler l'achat en traversant l'orderbook
-            let mut remaining_stake = stake_amount;
-            let mut total_cost = 0.0;
-            
-            for (price, volume) in &asks {
-                if remaining_stake <= 0.0 {
-                    break;
-                }
-                
-                let amount_to_buy = remaining_stake.min(*volume);
-                total_cost += amount_to_buy * price;
-                remaining_stake -= amount_to_buy;
-            }
-            
-            if remaining_stake > 0.0 {
-                // Si pas assez de volume, utiliser le prix le plus élevé
-                total_cost += remaining_stake * (best_ask + 0.10);
-            }
-            
-            let average_price = total_cost / stake_amount;
-            let gross_profit = 1.0 - average_price;
-            let net_profit = gross_profit * (1.0 - 0.02); // 2% sur profit net
-            net_profit.max(0.0)
-            
-        } else {
-            // Pari sur NO : simuler la vente en traversant l'orderbook
-            let mut remaining_stake = stake_amount;
-            let mut total_revenue = 0.0;
-            
-            for (price, volume) in &bids {
-                if remaining_stake <= 0.0 {
-                    break;
-                }
-                
-                let amount_to_sell = remaining_stake.min(*volume);
-                total_revenue += amount_to_sell * price;
-                remaining_stake -= amount_to_sell;
-            }
-            
-            if remaining_stake > 0.0 {
-                // Si pas assez de volume, utiliser le prix le plus bas
-                total_revenue += remaining_stake * (best_bid - 0.10);
-            }
-            
-            let average_price = total_revenue / stake_amount;
-            let gross_profit = average_price;
-            let net_profit = gross_profit * (1.0 - 0.02); // 2% sur profit net
-            net_profit.max(0.0)
-        }
-    }
-
-    async fn calculate_real_roi_with_volumes(&self, information_value: bool, market_id: &str, 
-                                           stake_amount: f64) -> Result<f64, Box<dyn std::error::Error>> {
-        // Récupérer l'orderbook complet avec volumes
-        let (bids, asks) = self.get_market_orderbook_with_volumes(market_id).await?;
-        
-        if information_value {
-            // Pari sur YES : simuler l'achat en traversant l'orderbook réel
-            let mut remaining_stake = stake_amount;
-            let mut total_cost = 0.0;
-            
-            for (price, volume) in &asks {
-                if remaining_stake <= 0.0 {
-                    break;
-                }
-                
-                let amount_to_buy = remaining_stake.min(*volume);
-                total_cost += amount_to_buy * price;
-                remaining_stake -= amount_to_buy;
-            }
-            
-            if remaining_stake > 0.0 {
-                // Si pas assez de volume, utiliser le prix le plus élevé + slippage
-                let worst_price = asks.last().map(|(p, _)| p + 0.15).unwrap_or(0.70);
-                total_cost += remaining_stake * worst_price;
-            }
-            
-            let average_price = total_cost / stake_amount;
-            let gross_profit = 1.0 - average_price;
-            let net_profit = gross_profit * (1.0 - 0.02); // 2% sur profit net
-            Ok(net_profit.max(0.0))
-            
-        } else {
-            // Pari sur NO : simuler la vente en traversant l'orderbook réel
-            let mut remaining_stake = stake_amount;
-            let mut total_revenue = 0.0;
-            
-            for (price, volume) in &bids {
-                if remaining_stake <= 0.0 {
-                    break;
-                }
-                
-                let amount_to_sell = remaining_stake.min(*volume);
-                total_revenue += amount_to_sell * price;
-                remaining_stake -= amount_to_sell;
-            }
-            
-            if remaining_stake > 0.0 {
-                // Si pas assez de volume, utiliser le prix le plus bas - slippage
-                let worst_price = bids.last().map(|(p, _)| p - 0.15).unwrap_or(0.30);
-                total_revenue += remaining_stake * worst_price;
-            }
-            
-            let average_price = total_revenue / stake_amount;
-            let gross_profit = average_price;
-            let net_profit = gross_profit * (1.0 - 0.02); // 2% sur profit net
-            Ok(net_profit.max(0.0))
-        }
-    }
-
-    // UNIFIED ROI CALCULATION - C++ only (simplified)
-    fn calculate_potential_roi_v2(&self, _information_value: bool, polymarket_probability: f64, 
-                                 polymarket_fee: f64, _time_factor: f64, market_status: &str) -> f64 {
-        if market_status == "closed" {
-            return 0.0;
-        }
-        
-        // Use C++ HFT calculation only
-        let current_price = polymarket_probability;
-        let action_time = 0.01; // 10ms default
-        
-        unsafe {
-            calculate_roi_hft_cached(
-                current_price,
-                polymarket_fee,
-                0.025, // catchup_speed 2.5%/s
-                action_time
-            )
-        }
-    }
-
-    // UNIFIED ROI CALCULATION - C++ only
-    fn calculate_potential_roi(&self, _relevance_score: f64, information_value: bool, 
-                              polymarket_probability: f64, polymarket_fee: f64,
-                              time_factor: f64, market_status: &str, _use_v2: bool) -> HashMap<String, f64> {
-        // Use C++ HFT calculation only
-        let current_price = polymarket_probability;
-        let action_time = 0.01; // 10ms default
-        
-        let roi = unsafe {
-            calculate_roi_hft_cached(
-                current_price,
-                polymarket_fee,
-                0.025, // catchup_speed 2.5%/s
-                action_time
-            )
-        };
-        
-        let mut result = HashMap::new();
-        result.insert("roi_v2".to_string(), roi);
-        result.insert("primary_roi".to_string(), roi);
-        result
-    }
-
-    // UNIFIED ROI CALCULATION - Using C++ HFT only
-    fn calculate_new_roi(&self, current_price: f64, action_time_ms: f64, market_id: &str, 
-                        information_value: bool, fee: f64) -> (f64, f64, f64) {
-        // Use C++ HFT calculation for unified ROI
-        let action_time_seconds = action_time_ms / 1000.0;
-        
-        unsafe {
-            let roi = calculate_roi_hft_cached(current_price, fee, 0.025, action_time_seconds);
-            let catchup_speed = 0.025; // 2.5% per second (unified)
-            let spent_price = current_price + (catchup_speed * action_time_seconds);
-            
-            (roi, catchup_speed, spent_price)
-        }
-    }
-
-    // Fonctions supprimées - maintenant gérées par le C++ via FFI
-
-    // Mettre à jour l'historique des prix pour un marché
-    async fn update_price_history(&mut self, market_id: &str, price: f64) {
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        
-        // Vérifier si l'historique existe déjà
-        let needs_history = !self.price_history.contains_key(market_id) || 
-                           self.price_history.get(market_id).unwrap().is_empty();
-        
-        // Si c'est le premier prix pour ce marché, essayer de récupérer l'historique réel
-        if needs_history {
-            match self.fetch_real_price_history(market_id).await {
-                Ok(_) => {
-                    println!("    [HISTORIQUE] Historique réel récupéré pour {}", market_id);
-                },
-                Err(_) => {
-                    println!("    [HISTORIQUE] Fallback vers historique simulé pour {}", market_id);
-                    self.create_simulated_price_history(market_id, price, current_time);
-                }
-            }
-        }
-        
-        // Ajouter le prix actuel
-        let entry = self.price_history.entry(market_id.to_string()).or_insert_with(Vec::new);
-        entry.push((current_time, price));
-        
-        // Garder seulement les 100 dernières entrées pour éviter la surcharge mémoire
-        if entry.len() > 100 {
-            entry.remove(0);
-        }
-    }
-
-    // Créer un historique de prix simulé réaliste
-    fn create_simulated_price_history(&mut self, market_id: &str, current_price: f64, current_time: f64) {
-        let entry = self.price_history.entry(market_id.to_string()).or_insert_with(Vec::new);
-        let mut rng = rand::thread_rng();
-        
-        // Créer 20 points d'historique sur les 24 dernières heures
-        for i in 0..20 {
-            let time_offset = (i as f64) * 3600.0; // 1 heure entre chaque point
-            let historical_time = current_time - time_offset;
-            
-            // Variation aléatoire réaliste (±5% par heure)
-            let variation = rng.gen_range(-0.05..0.05);
-            let historical_price = (current_price + variation).max(0.01).min(0.99);
-            
-            entry.push((historical_time, historical_price));
-        }
-        
-        // Trier par ordre chronologique
-        entry.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-    }
-
-    // Récupérer l'historique réel des prix Polymarket
-    async fn fetch_real_price_history(&mut self, market_id: &str) -> Result<(), Box<dyn std::error::Error>> {
-        println!("    [HISTORIQUE] Récupération de l'historique des prix pour {}", market_id);
-        
-        // URL de l'API Polymarket pour l'historique des prix
-        let url = format!("https://gamma-api.polymarket.com/markets/{}/price-history", market_id);
-        
-        match self.http_client.get(&url).send().await {
-            Ok(response) => {
-                if response.status().is_success() {
-                    match response.text().await {
-                        Ok(text) => {
-                            // Parser la réponse JSON
-                            match serde_json::from_str::<serde_json::Value>(&text) {
-                                Ok(json_data) => {
-                                    if let Some(price_history_array) = json_data.get("priceHistory") {
-                                        if let Some(history) = price_history_array.as_array() {
-                                            let entry = self.price_history.entry(market_id.to_string()).or_insert_with(Vec::new);
-                                            
-                                            for price_point in history {
-                                                if let (Some(timestamp), Some(price)) = (
-                                                    price_point.get("timestamp").and_then(|v| v.as_f64()),
-                                                    price_point.get("price").and_then(|v| v.as_f64())
-                                                ) {
-                                                    entry.push((timestamp, price));
-                                                }
-                                            }
-                                            
-                                            // Trier par ordre chronologique
-                                            entry.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-                                            
-                                            println!("    [HISTORIQUE] {} points de prix récupérés pour {}", entry.len(), market_id);
-                                        }
-                                    }
-                                },
-                                Err(e) => {
-                                    println!("    [ERREUR] Impossible de parser l'historique JSON: {}", e);
-                                }
-                            }
-                        },
-                        Err(e) => {
-                            println!("    [ERREUR] Impossible de lire la réponse: {}", e);
-                        }
-                    }
-                } else {
-                    println!("    [ERREUR] Statut HTTP: {}", response.status());
-                }
-            },
-            Err(e) => {
-                println!("    [ERREUR] Impossible de récupérer l'historique: {}", e);
-            }
-        }
-        
-        Ok(())
-    }
-
-    // Mettre à jour les vitesses de convergence
-    fn update_convergence_speed(&mut self, market_id: &str, speed: f64) {
-        // Vérifier si les vitesses existent déjà
-        let needs_speeds = !self.market_convergence_speeds.contains_key(market_id) || 
-                          self.market_convergence_speeds.get(market_id).unwrap().is_empty();
-        
-        // Si c'est la première vitesse pour ce marché, créer des vitesses simulées réalistes
-        if needs_speeds {
-            self.create_simulated_convergence_speeds(market_id);
-        }
-        
-        let speeds = self.market_convergence_speeds.entry(market_id.to_string()).or_insert_with(Vec::new);
-        speeds.push(speed);
-        
-        // Garder seulement les 50 dernières vitesses
-        if speeds.len() > 50 {
-            speeds.remove(0);
-        }
-    }
-
-    // Créer des vitesses de convergence simulées réalistes
-    fn create_simulated_convergence_speeds(&mut self, market_id: &str) {
-        let speeds = self.market_convergence_speeds.entry(market_id.to_string()).or_insert_with(Vec::new);
-        let mut rng = rand::thread_rng();
-        
-        // Créer 10 vitesses historiques réalistes
-        for _ in 0..10 {
-            // Vitesse entre 0.5% et 8% par seconde
-            let speed = rng.gen_range(0.005..0.08);
-            speeds.push(speed);
-        }
-    }
-
-    fn estimate_trade_execution_time(&self, action: &str, polymarket_probability: f64, relevance_score: f64) -> f64 {
-        // Temps d'exécution ULTRA-optimisé pour HFT (objectif < 20ms)
-        let base_time = match action {
-            "buy" => 8.0,    // Réduit à 8ms (optimisation extrême)
-            "sell" => 6.0,   // Réduit à 6ms
-            "monitor" => 0.0,
-            "ignore" => 0.0,
-            _ => 5.0,
-        };
-        
-        let mut adjusted_time = base_time;
-        
-        // Ajustement selon la probabilité du marché
-        if polymarket_probability > 0.8 {
-            adjusted_time *= 0.4; // 60% plus rapide pour les marchés haute probabilité
-        } else if polymarket_probability < 0.3 {
-            adjusted_time *= 0.8; // 20% plus lent pour les marchés basse probabilité
-        }
-        
-        // Ajustement selon le score de pertinence
-        if relevance_score > 0.8 {
-            adjusted_time *= 0.5; // 50% plus rapide pour haute pertinence
-        } else if relevance_score < 0.3 {
-            adjusted_time *= 0.7; // 30% plus lent pour basse pertinence
-        }
-        
-        // Latence réseau ULTRA-optimisée (avec keep-alive et HTTP/2)
-        let network_latency = 2.0 + (relevance_score * 5.0); // Réduit à 2-7ms
-        
-        // Latence API Polymarket ULTRA-optimisée (avec HTTP/2 et pool)
-        let api_latency = 5.0 + (polymarket_probability * 10.0); // Réduit à 5-15ms
-        
-        let total_execution_time = adjusted_time + network_latency + api_latency;
-        total_execution_time.max(0.0)
-    }
-
-    fn get_timing_grade(&self, latency_ms: f64) -> String {
-        // Grades de timing ULTRA-stricts pour HFT (objectif < 20ms)
-        if latency_ms < 15.0 {
-            "S++".to_string() // Ultra-ultra-rapide (< 15ms)
-        } else if latency_ms < 25.0 {
-            "S+".to_string()  // Ultra-rapide (< 25ms)
-        } else if latency_ms < 35.0 {
-            "S".to_string()   // Très rapide (< 35ms)
-        } else if latency_ms < 45.0 {
-            "A+".to_string()  // Rapide (< 45ms)
-        } else if latency_ms < 60.0 {
-            "A".to_string()   // Bon (< 60ms)
-        } else if latency_ms < 80.0 {
-            "B+".to_string()  // Acceptable (< 80ms)
-        } else if latency_ms < 120.0 {
-            "B".to_string()   // Moyen (< 120ms)
-        } else if latency_ms < 180.0 {
-            "C".to_string()   // Lent (< 180ms)
-        } else {
-            "D".to_string()   // Très lent (> 180ms)
-        }
-    }
-
-    fn calculate_pnl(&self, roi: f64, stake_amount: f64) -> f64 {
-        // PnL = ROI * montant investi
-        roi * stake_amount
-    }
-
-    // Gérer le capital disponible de manière réaliste
-    fn get_available_balance(&self) -> f64 {
-        if self.simulation_mode {
-            // Balance simulée qui évolue avec les trades
-            self.simulated_balance
-        } else {
-            // TODO: Connect to real wallet to get balance
-            1.0 // Minimum capital in real mode
-        }
-    }
-    
-    fn update_simulated_balance(&mut self, pnl: f64) {
-        if self.simulation_mode {
-            self.simulated_balance += pnl;
-            self.simulated_balance = self.simulated_balance.max(0.0); // Pas de balance négative
-        }
-    }
-
-    fn get_stake_amount(&self, action: &str, confidence: &str) -> f64 {
-        // Système Risk Fixed Fraction ULTRA-optimisé pour HFT
-        let available_balance = self.get_available_balance();
-        
-        // Facteurs de risque dynamiques selon les conditions
-        let market_volatility_factor = 1.2;
-        let time_factor = 1.1;
-        
-        let risk_fraction = match confidence {
-            "high" => 0.08,   // 8% du capital pour haute confiance
-            "medium" => 0.03, // 3% du capital pour confiance moyenne
-            "low" => 0.015,   // 1.5% du capital pour basse confiance
-            _ => 0.02,
-        };
-
-        let calculated_stake = available_balance * risk_fraction * market_volatility_factor * time_factor;
-        
-        // Ajuster selon l'action
-        let final_stake = match action {
-            "buy" | "sell" => calculated_stake,
-            "monitor" => 0.0,
-            "ignore" => 0.0,
-            _ => calculated_stake * 0.5, // Réduire de 50% pour actions non standard
-        };
-
-        // Limites de sécurité adaptatives
-        let min_stake = 0.5;  // Minimum 0.5€ (augmenté)
-        let max_stake = 8.0;  // Maximum 8€ par trade (augmenté)
-        
-        final_stake.max(min_stake).min(max_stake)
-    }
-
-    async fn generate_trading_signals(&mut self) {
-        println!("\nPHASE 4: CALCUL DES DÉCISIONS DE TRADING");
-        println!("=========================================");
-        
-        self.log_to_file("polymarket.log", "Phase 4: Calcul des décisions de trading");
-        
-        // Préparer les données de prix
-        println!("    [INFO] Préparation des données de prix...");
-        let market_data: Vec<_> = self.markets.iter()
-            .map(|market| (market.id.clone(), market.probability))
-            .collect();
-        
-        for (market_id, probability) in market_data {
-            self.update_price_history(&market_id, probability).await;
-        }
-        
-        // Collecter les données nécessaires d'abord
-        let mut price_updates = Vec::new();
-        let mut convergence_updates = Vec::<(String, f64)>::new();
-        
-        for opportunity in &self.opportunities {
-            let signal_start_time = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs_f64();
-            
-            let relevance_score = opportunity.relevance_score;
-            let information_value = self.estimate_information_value(opportunity);
-            let polymarket_probability = self.estimate_polymarket_probability(opportunity);
-            
-            // Calculer ROI avec les deux formules
-            let _roi_data = self.calculate_potential_roi(
-                relevance_score,
-                information_value,
-                polymarket_probability,
-                0.02, // fee 2%
-                1.1,  // time_factor
-                "open",
-                true  // use_v2
-            );
-            
-            // Utiliser le ROI HFT basé sur l'historique des prix
-            let current_price = polymarket_probability; // Prix actuel du marché
-            
-            // Collecter les mises à jour à faire plus tard
-            price_updates.push((opportunity.market_id.clone(), current_price));
-            
-            // Calculer le mouvement HFT pondéré par volume
-            let hft_move = match self.get_market_hft_move(&opportunity.market_id).await {
-                Ok(move_val) => move_val,
-                Err(_) => 0.05, // Fallback si erreur
-            };
-            
-            let direction = if information_value { "up" } else { "down" };
-            
-            let roi_v2 = self.calculate_hft_roi(
-                current_price,
-                hft_move,
-                direction
-            );
-            
-            // Afficher le calcul de ROI
-            if roi_v2 > 0.0 {
-                println!("    [INFO] ROI calculé: {:.1}% pour {} (mouvement: {:.1}%, direction: {})", 
-                         roi_v2 * 100.0, opportunity.market_id, hft_move * 100.0, direction);
-            }
-            
-            // Logique d'arbitrage avec ROI HFT basé sur l'historique
-            let predicted_outcome = if information_value { 1.0 } else { 0.0 };
-            let difference = predicted_outcome - polymarket_probability;
-            
-            // Vérifier si on a déjà un signal pour ce marché + source
-            let _signal_key = format!("{}-{}", opportunity.market_id, opportunity.source_url);
-            let already_has_signal = self.signals.iter().any(|s| {
-                s.market_id == opportunity.market_id && s.source == opportunity.source_url
-            });
-            
-                // Calculer les métriques de timing
-    let signal_end_time = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs_f64();
-    let signal_generation_time = (signal_end_time - signal_start_time) * 1000.0;
-    
-    let detection_time = &opportunity.timestamp;
-    let signal_time = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
-    
-    let reaction_time_ms = signal_generation_time;
-    let estimated_execution_ms = self.estimate_trade_execution_time("MONITOR", polymarket_probability, relevance_score);
-    let total_latency_ms = reaction_time_ms + estimated_execution_ms;
-    
-        // Calculer le ROI avec cache C++
-    let expected_roi = unsafe {
-        calculate_roi_hft_cached(
-            current_price,
-            0.02, // fee 2%
-            0.025, // catchup_speed 2.5%/s
-            total_latency_ms / 1000.0 // action_time en secondes
-        )
-    };
-    
-    // Décision ultra-rapide avec C++ (latence < 100ns)
-    let action = unsafe {
-        let c_action = make_trading_decision_hft(expected_roi, relevance_score);
-        let action_str = CStr::from_ptr(c_action).to_string_lossy().into_owned();
-        action_str
-    };
-    
-    println!("[DECISION] {} pour {} (ROI attendu: {:.1}%)", action, opportunity.market_id, expected_roi * 100.0);
-            
-            // Calcul de position size ultra-rapide avec C++ (latence < 50ns)
-    let stake_amount = unsafe {
-        let confidence_c = CString::new(opportunity.confidence.as_str()).unwrap();
-        calculate_position_size_hft(
-            self.simulated_balance,
-            expected_roi,
-            confidence_c.as_ptr()
-        )
-    };
-            let pnl_expected = self.calculate_pnl(expected_roi, stake_amount);
-            
-            // Enrichir la raison avec les détails de la source
-            let source_domain = self.extract_domain_from_url(&opportunity.source_url);
-            let enriched_reason = self.create_enriched_reason(&opportunity, &source_domain, information_value);
-            
-            let signal = TradingSignal {
-                market_id: opportunity.market_id.clone(),
-                action: action.clone(), // Utiliser la vraie décision du C++
-                confidence: opportunity.confidence.clone(),
-                relevance_score,
-                reason: enriched_reason.clone(),
-                timestamp: signal_time.clone(),
-                source: opportunity.source_url.clone(),
-                potential_roi: expected_roi, // Utiliser le ROI calculé
-                
-                roi_v2: expected_roi, // Utiliser le ROI unifié
-                information_value,
-                polymarket_probability,
-                detection_time: detection_time.clone(),
-                signal_time,
-                signal_generation_time_ms: signal_generation_time,
-                reaction_time_ms,
-                estimated_execution_time_ms: estimated_execution_ms,
-                total_latency_ms,
-                timing_grade: self.get_timing_grade(total_latency_ms),
-                executed: false,
-                pnl_expected,
-                stake_amount,
-                
-                // Nouveaux champs pour le ROI amélioré
-                current_price,
-                action_time_ms: total_latency_ms,
-                catchup_speed: 0.025, // Valeur par défaut
-                spent_price: current_price,
-                new_roi: expected_roi,
-            };
-            
-            self.signals.push(signal.clone());
-            
-            // Afficher seulement les signaux de trading (pas les MONITOR)
-            // Le C++ décidera de l'action finale, donc on affiche rien ici
-            // Les signaux seront affichés après traitement par le C++
-            
-            let log_msg = format!("Signal: {} | {} | ROI: {:.1}% | Stake: {:.2}€ | PnL: {:.2}€ | Confiance: {} | Timing: {}", 
-                action.to_uppercase(), opportunity.question, expected_roi * 100.0, stake_amount, pnl_expected, opportunity.confidence, signal.timing_grade);
-            self.log_to_file("polymarket.log", &log_msg);
-            
-            // Log timing metrics avec PnL
-            self.log_to_file("trade_timing.log", &format!("TRADE | {} | {} | reaction={:.0}ms | execution={:.0}ms | total={:.0}ms | grade={} | roi={:.1}% | stake={:.2}€ | pnl={:.2}€", 
-                action.to_uppercase(), opportunity.market_id, reaction_time_ms, estimated_execution_ms, total_latency_ms, signal.timing_grade, expected_roi * 100.0, stake_amount, pnl_expected));
-        }
-        
-        // Appliquer les mises à jour après la boucle
-        for (market_id, price) in price_updates {
-            self.update_price_history(&market_id, price).await;
-        }
-        
-        for (market_id, speed) in &convergence_updates {
-            self.update_convergence_speed(market_id, *speed);
-        }
-        
-        println!("[SUCCÈS] {} décisions de trading calculées", self.signals.len());
-        self.log_to_file("polymarket.log", &format!("Phase 4 terminée: {} signaux", self.signals.len()));
-    }
-
-    // Nouvelle méthode pour afficher des signaux clairs et compréhensibles
-    fn display_clear_trading_signal(&self, signal: &TradingSignal, opportunity: &ArbitrageOpportunity, source_domain: &str, information_value: bool, roi_v2: f64) {
-        if signal.action == "BUY" || signal.action == "SELL" {
-            println!("🎯 {} | {} | ROI: {:.1}% | {}", 
-                signal.action, 
-                opportunity.question, 
-                roi_v2 * 100.0,
-                source_domain
-            );
-        }
-    }
-
-    // Extraire le domaine de l'URL
-    fn extract_domain_from_url(&self, url: &str) -> String {
-        if let Some(domain) = url.split("//").nth(1) {
-            if let Some(domain_only) = domain.split('/').next() {
-                return domain_only.to_string();
-            }
-        }
-        "source inconnue".to_string()
-    }
-
-    // Extraire le domaine d'une question
-    fn extract_domain_from_question(&self, question: &str) -> String {
-        let question_lower = question.to_lowercase();
-        
-        if question_lower.contains("bitcoin") || question_lower.contains("ethereum") || 
-           question_lower.contains("crypto") || question_lower.contains("blockchain") ||
-           question_lower.contains("defi") || question_lower.contains("nft") {
-            "crypto".to_string()
-        } else if question_lower.contains("fed") || question_lower.contains("federal") ||
-                  question_lower.contains("rate") || question_lower.contains("economy") ||
-                  question_lower.contains("inflation") || question_lower.contains("gdp") {
-            "economy".to_string()
-        } else if question_lower.contains("election") || question_lower.contains("president") ||
-                  question_lower.contains("congress") || question_lower.contains("senate") ||
-                  question_lower.contains("vote") || question_lower.contains("politics") {
-            "politics".to_string()
-        } else {
-            "general".to_string()
-        }
-    }
-
-    // Créer une raison enrichie avec les détails
-    fn create_enriched_reason(&self, opportunity: &ArbitrageOpportunity, source_domain: &str, information_value: bool) -> String {
-        let impact = if information_value { "positif" } else { "négatif" };
-        let keyword = self.get_keyword_example(source_domain, information_value);
-        
-        format!("Source: {} — Mot-clé '{}' détecté — impact {} sur {}", 
-                source_domain, keyword, impact, opportunity.question)
-    }
-
-    // Obtenir un exemple de mot-clé selon la source et l'impact
-    fn get_keyword_example(&self, source_domain: &str, information_value: bool) -> String {
-        match source_domain {
-            "www.sec.gov" => if information_value { "approval" } else { "rejection" }.to_string(),
-            "www.federalreserve.gov" => if information_value { "cut" } else { "hold" }.to_string(),
-            "www.whitehouse.gov" => if information_value { "support" } else { "oppose" }.to_string(),
-            "www.reuters.com" | "www.bbc.com" => if information_value { "positive" } else { "negative" }.to_string(),
-            "www.coingecko.com" | "coinmarketcap.com" => if information_value { "bullish" } else { "bearish" }.to_string(),
-            _ => if information_value { "favorable" } else { "défavorable" }.to_string(),
-        }
-    }
-
-    async fn execute_real_trades(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        println!("\nPHASE 5: EXÉCUTION DES VRAIS TRADES");
-        println!("====================================");
-        
-        self.log_to_file("polymarket.log", "Phase 5: Exécution des vrais trades");
-        
-        let mut executed_count = 0;
-        let mut signals_to_update = Vec::new();
-        let mut balance_updates = Vec::new();
-        
-        // Première passe : identifier et exécuter les trades
-        for signal in &self.signals {
-            if (signal.action == "buy" || signal.action == "sell") && !signal.executed {
-                let stake_amount = signal.stake_amount;
-                let available_balance = self.get_available_balance();
-
-                if available_balance >= stake_amount {
-                    let price_f = if signal.action == "buy" {
-                        signal.polymarket_probability
-                    } else {
-                        1.0 - signal.polymarket_probability
-                    };
-                    let amount_f = stake_amount / price_f;
-
-                    let amount = format!("{:.4}", amount_f);
-                    let price = format!("{:.4}", price_f);
-
-                    println!("  [TRADE] Tentative d'exécution réelle...");
-                    println!("     Action: {}", signal.action.to_uppercase());
-                    println!("     Marché: {}", signal.reason);
-                    println!("     Montant stake: {:.2}€", stake_amount);
-                    println!("     Amount tokens: {} | Price: {}", amount, price);
-                    println!("     ROI attendu: {:.1}%", signal.potential_roi * 100.0);
-                    println!("     Solde restant: {:.2}€", available_balance - stake_amount);
-                    
-                    match self.execute_real_trade(&signal.market_id, &signal.action, &amount, &price).await {
-                        Ok(success) => {
-                            if success {
-                                executed_count += 1;
-                                // Marquer pour mise à jour
-                                signals_to_update.push((signal.market_id.clone(), signal.source.clone()));
-                                balance_updates.push(-stake_amount);
-                                println!("  [SUCCESS] Trade exécuté avec succès!");
-                                
-                                let log_msg = format!("VRAI TRADE: {} | {} | Stake: {:.2}€ | ROI: {:.1}% | Prix: {} | Solde: {:.2}€", 
-                                    signal.action.to_uppercase(), signal.reason, stake_amount, signal.potential_roi * 100.0, price, self.get_available_balance());
-                                self.log_to_file("polymarket.log", &log_msg);
-                            } else {
-                                println!("  [ERROR] Échec de l'exécution du trade");
-                            }
-                        },
-                        Err(e) => {
-                            println!("  [ERROR] Erreur lors de l'exécution: {}", e);
-                        }
-                    }
-                } else {
-                    println!("  [SKIP] Trade ignoré - Solde insuffisant ({:.2}€ restant)", available_balance);
-                }
-            }
-        }
-        
-        // Deuxième passe : mettre à jour les signaux exécutés
-        for (market_id, source) in signals_to_update {
-            if let Some(signal_mut) = self.signals.iter_mut().find(|s| s.market_id == market_id && s.source == source) {
-                signal_mut.executed = true;
-            }
-        }
-        
-        // Troisième passe : mettre à jour le solde
-        for balance_update in balance_updates {
-            self.update_simulated_balance(balance_update);
-        }
-        
-        println!("[SUCCÈS] {} vrais trades exécutés", executed_count);
-        self.log_to_file("polymarket.log", &format!("Phase 5 terminée: {} vrais trades", executed_count));
-        
-        Ok(())
-    }
-
-    fn execute_trades(&mut self) {
-        println!("\nPHASE 5: SIMULATION DES TRADES");
-        println!("==============================");
-        
-        self.log_to_file("polymarket.log", "Phase 5: Simulation des trades");
-        
-        let mut executed_count = 0;
-        
-        let mut signals_to_execute: Vec<(String, String, f64, String, f64)> = Vec::new();
-        
-        for signal in &self.signals {
-            if (signal.action == "buy" || signal.action == "sell") && !signal.executed {
-                signals_to_execute.push((
-                    signal.action.clone(),
-                    signal.reason.clone(),
-                    signal.potential_roi,
-                    signal.timing_grade.clone(),
-                    signal.total_latency_ms
-                ));
-            }
-        }
-        
-        for (action, reason, potential_roi, timing_grade, total_latency_ms) in signals_to_execute {
-            let execution_result = self.execute_single_trade_simple(&action, &reason);
-            
-            if execution_result {
-                executed_count += 1;
-                
-                println!("  [SIMULATION] Trade simulé:");
-                println!("     Action: {}", action.to_uppercase());
-                println!("     Marché: {}", reason);
-                println!("     ROI attendu: {:.1}%", potential_roi * 100.0);
-                println!("     Performance: {} ({}ms)", timing_grade, total_latency_ms as i32);
-                
-                let log_msg = format!("Trade exécuté: {} | {} | ROI: {:.1}% | Timing: {}", 
-                    action.to_uppercase(), reason, potential_roi * 100.0, timing_grade);
-                self.log_to_file("polymarket.log", &log_msg);
-            }
-        }
-        
-        println!("[SUCCÈS] {} trades simulés", executed_count);
-        self.log_to_file("polymarket.log", &format!("Phase 5 terminée: {} trades exécutés", executed_count));
-    }
-
-    async fn execute_real_trade(&self, market_id: &str, action: &str, amount: &str, price: &str) -> Result<bool, Box<dyn std::error::Error>> {
-        println!("  [INFO] Simulation d'exécution sur Polymarket...");
-        
-        // Préparer la requête de trade
-        let trade_request = TradeRequest {
-            market_id: market_id.to_string(),
-            side: action.to_string(),
-            amount: amount.to_string(),
-            price: price.to_string(),
-        };
-        
-        // Headers appropriés pour éviter le blocage Cloudflare
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse()?);
-        headers.insert("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36".parse()?);
-        headers.insert("Accept", "application/json".parse()?);
-        headers.insert("Accept-Language", "en-US,en;q=0.9".parse()?);
-        headers.insert("Connection", "keep-alive".parse()?);
-        
-        // Ajouter l'authentification si disponible (optionnelle)
-        if !self.private_key.is_empty() {
-            // Authentification optionnelle pour les trades réels
-            headers.insert("Authorization", format!("Bearer {}", self.private_key).parse()?);
-        }
-        
-        // Utiliser l'API CLOB officielle pour les trades
-        let trade_url = format!("{}/orders", POLYMARKET_CLOB_API);
-        
-        println!("  [DEBUG] Tentative de trade sur: {}", trade_url);
-        
-        // Appel API Polymarket pour exécuter le trade
-        let response = self.http_client
-            .post(&trade_url)
-            .headers(headers)
-            .json(&trade_request)
-            .timeout(std::time::Duration::from_secs(30))
-            .send()
-            .await;
-        
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    match resp.json::<Value>().await {
-                        Ok(response_data) => {
-                            println!("  [SUCCESS] Trade exécuté: {:?}", response_data);
-                            self.log_to_file("polymarket.log", &format!("Trade réussi: {} {} {} {}", market_id, action, amount, price));
-                            Ok(true)
-                        },
-                        Err(e) => {
-                            println!("  [ERROR] Erreur parsing réponse: {}", e);
-                            Ok(false)
-                        }
-                    }
-                } else {
-                    let status = resp.status();
-                    let error_text = resp.text().await.unwrap_or_else(|_| "Erreur inconnue".to_string());
-                    println!("  [ERROR] Échec du trade (status {}): {}", status, error_text);
-                    
-                    // Si on reçoit une page HTML (Cloudflare), c'est un blocage
-                    if error_text.contains("Cloudflare") || error_text.contains("blocked") {
-                        println!("  [BLOCKED] Bloqué par Cloudflare - Utilisez un VPN ou changez d'IP");
-                        self.log_to_file("polymarket.log", "BLOCAGE CLOUDFLARE DÉTECTÉ");
-                    }
-                    
-                    Ok(false)
-                }
-            },
-            Err(e) => {
-                println!("  [ERROR] Erreur réseau: {}", e);
-                Ok(false)
-            }
-        }
-    }
-
-    fn execute_single_trade_simple(&self, action: &str, reason: &str) -> bool {
-        let mut rng = rand::thread_rng();
-        
-        // Taux de succès basé sur l'action
-        let success_rate = if action == "buy" { 0.90 } else { 0.85 };
-        
-        if rng.gen_bool(success_rate) {
-            // Simuler un délai d'exécution
-            std::thread::sleep(std::time::Duration::from_millis(100));
-            true
-        } else {
-            println!("  [ERROR] Échec d'exécution pour: {}", reason);
-            false
-        }
-    }
-
-    fn print_summary(&self) {
-        // Affichage silencieux pour optimiser les performances
-        
-        if !self.signals.is_empty() {
-            // Filtrer seulement les signaux de trading (pas les MONITOR)
-            let trading_signals: Vec<&TradingSignal> = self.signals.iter().filter(|s| s.action == "buy" || s.action == "sell").collect();
-            
-            if !trading_signals.is_empty() {
-                let total_roi: f64 = trading_signals.iter().map(|s| s.potential_roi).sum();
-                let avg_roi = total_roi / trading_signals.len() as f64;
-                let total_stake: f64 = trading_signals.iter().map(|s| s.stake_amount).sum();
-                let total_pnl: f64 = trading_signals.iter().map(|s| s.pnl_expected).sum();
-                
-                // Statistiques détaillées des trades
-                let buy_signals = trading_signals.iter().filter(|s| s.action == "buy").count();
-                let sell_signals = trading_signals.iter().filter(|s| s.action == "sell").count();
-                
-                // Sources déclencheuses principales
-                let mut source_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-                for signal in &trading_signals {
-                    let domain = self.extract_domain_from_url(&signal.source);
-                    *source_counts.entry(domain).or_insert(0) += 1;
-                }
-                
-                // Trouver le meilleur trade (ROI le plus élevé)
-                let best_trade = trading_signals.iter().max_by(|a, b| a.potential_roi.partial_cmp(&b.potential_roi).unwrap());
-                
-                println!("Signaux de trading: {} (sur {} total)", trading_signals.len(), self.signals.len());
-                println!("Trades Buy: {} | Trades Sell: {}", buy_signals, sell_signals);
-            println!("ROI total potentiel: {:.1}%", total_roi * 100.0);
-            println!("ROI moyen par signal: {:.1}%", avg_roi * 100.0);
-                println!("Capital total investi: {:.2}€ (système de stake intelligent)", total_stake);
-                println!("PnL total attendu: {:.2}€", total_pnl);
-                let total_latency_ms: f64 = trading_signals.iter().map(|s| s.total_latency_ms).sum();
-                let average_latency_ms = if trading_signals.is_empty() { 0.0 } else { total_latency_ms / trading_signals.len() as f64 };
-                println!("Latence moyenne: {:.0}ms", average_latency_ms);
-                
-                // Sources déclencheuses principales (corrigé)
-                let mut source_counts_clean: HashMap<String, usize> = HashMap::new();
-                for signal in &trading_signals {
-                    let source_name = if signal.source == "simulation" {
-                        "SIMULATION".to_string()
-                    } else {
-                        self.extract_domain_from_url(&signal.source)
-                    };
-                    *source_counts_clean.entry(source_name).or_default() += 1;
-                }
-                
-                println!("\n--- Analyse des Trades ---");
-                println!("Sources déclencheuses principales:");
-                let mut sorted_sources: Vec<(&String, &usize)> = source_counts_clean.iter().collect();
-                sorted_sources.sort_by(|a, b| b.1.cmp(a.1));
-                for (source, count) in sorted_sources.iter().take(3) {
-                    println!("  - {}: {} trades", source, count);
-                }
-                
-                println!("Trades Buy: {} | Trades Sell: {}", buy_signals, sell_signals);
-                
-                if let Some(trade) = best_trade {
-                    let source_name = if trade.source == "simulation" {
-                        "SIMULATION".to_string()
-                    } else {
-                        self.extract_domain_from_url(&trade.source)
-                    };
-                    println!("Top trade: {} (ROI attendu: {:.1}%, Source: {})", 
-                        trade.reason.split(" — ").nth(2).unwrap_or("Marché"), 
-                        trade.potential_roi * 100.0, source_name);
-                }
-            } else {
-                println!("Aucun signal de trading généré (tous en mode MONITOR)");
-                
-                // Afficher quand même les sources d'opportunités
-                let mut source_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-                for opportunity in &self.opportunities {
-                    let domain = self.extract_domain_from_url(&opportunity.source_url);
-                    *source_counts.entry(domain).or_insert(0) += 1;
-                }
-                
-                if !source_counts.is_empty() {
-                    let mut sorted_sources: Vec<_> = source_counts.iter().collect();
-                    sorted_sources.sort_by(|a, b| b.1.cmp(a.1));
-                    
-                    println!("Sources d'opportunités détectées:");
-                    for (source, count) in sorted_sources.iter().take(3) {
-                        println!("  • {}: {} opportunités", source, count);
-                    }
-                }
-            }
-        }
-        
-        let avg_timing: f64 = self.signals.iter().map(|s| s.total_latency_ms).sum::<f64>() / self.signals.len() as f64;
-        println!("Latence moyenne: {:.0}ms", avg_timing);
-        
-        let total_pnl: f64 = self.signals.iter().map(|s| s.pnl_expected).sum();
-        let _total_stake: f64 = self.signals.iter().map(|s| s.stake_amount).sum();
-        
-        self.log_to_file("polymarket.log", &format!("Résumé: {} marchés, {} sources, {} opportunités, {} signaux, PnL total: {:.2}€", 
-            self.markets.len(), self.source_data.len(), self.opportunities.len(), self.signals.len(), total_pnl));
-    }
-
-    fn print_validation_report(&self) {
-        // Affichage professionnel pour validation
-        println!("\n{}", "=".repeat(60));
-        println!("RAPPORT DE PERFORMANCE - POLYMARKET BOT");
-        println!("{}", "=".repeat(60));
-        
-        // Statistiques générales
-        println!("METRIQUES GENERALES:");
-        println!("   • Marchés Polymarket: {}", self.markets.len());
-        println!("   • Sources de résolution: {}", self.source_data.len());
-        
-        // Compter seulement les vraies opportunités (ROI > 0)
-        let profitable_opportunities = self.signals.iter().filter(|s| s.potential_roi > 0.0).count();
-        println!("   • Opportunités d'arbitrage: {} (ROI > 0)", profitable_opportunities);
-        println!("   • Signaux de trading: {}", self.signals.len());
-        
-        // Analyse des signaux
-        if !self.signals.is_empty() {
-            let buy_signals = self.signals.iter().filter(|s| s.action == "BUY").count();
-            let sell_signals = self.signals.iter().filter(|s| s.action == "SELL").count();
-            let monitor_signals = self.signals.iter().filter(|s| s.action == "MONITOR").count();
-            
-            let total_pnl = self.signals.iter().map(|s| s.pnl_expected).sum::<f64>();
-            let avg_roi = self.signals.iter().map(|s| s.potential_roi).sum::<f64>() / self.signals.len() as f64;
-            
-            println!("\nANALYSE DES SIGNAUX:");
-            println!("   • Signaux BUY: {} (ROI moyen: {:.1}%)", buy_signals, 
-                if buy_signals > 0 { self.signals.iter().filter(|s| s.action == "BUY").map(|s| s.potential_roi).sum::<f64>() / buy_signals as f64 * 100.0 } else { 0.0 });
-            println!("   • Signaux SELL: {} (ROI moyen: {:.1}%)", sell_signals,
-                if sell_signals > 0 { self.signals.iter().filter(|s| s.action == "SELL").map(|s| s.potential_roi).sum::<f64>() / sell_signals as f64 * 100.0 } else { 0.0 });
-            println!("   • Signaux MONITOR: {}", monitor_signals);
-            println!("   • ROI moyen global: {:.1}%", avg_roi * 100.0);
-            println!("   • PnL total attendu: {:.2}€", total_pnl);
-        }
-        
-        // Performance technique
-        println!("\nPERFORMANCE TECHNIQUE:");
-        println!("   • Latence moyenne: {}ms", 
-            if !self.signals.is_empty() { 
-                format!("{:.0}", self.signals.iter().map(|s| s.total_latency_ms).sum::<f64>() / self.signals.len() as f64)
-            } else { "N/A".to_string() });
-        println!("   • Module C++: Optimise");
-        println!("   • Sources temps reel: Actives");
-        println!("   • Gestion des risques: Integree");
-        
-        // Sources principales (seulement les vraies opportunités)
-        let profitable_signals: Vec<_> = self.signals.iter().filter(|s| s.potential_roi > 0.0).collect();
-        if !profitable_signals.is_empty() {
-            let mut source_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-            for signal in profitable_signals {
-                let domain = self.extract_domain_from_url(&signal.source);
-                *source_counts.entry(domain).or_insert(0) += 1;
-            }
-            
-            let mut sorted_sources: Vec<_> = source_counts.iter().collect();
-            sorted_sources.sort_by(|a, b| b.1.cmp(a.1));
-            
-            println!("\nSOURCES PRINCIPALES (opportunités profitables):");
-            for (source, count) in sorted_sources.iter().take(3) {
-                println!("   • {}: {} opportunites", source, count);
-            }
-        } else {
-            println!("\nSOURCES PRINCIPALES:");
-            println!("   • Aucune opportunité profitable détectée");
-        }
-        
-        // Recommandations
-        println!("\nRECOMMANDATIONS:");
-        if !self.signals.is_empty() {
-            let high_roi_signals = self.signals.iter().filter(|s| s.potential_roi > 0.10).count();
-            if high_roi_signals > 0 {
-                println!("   • {} opportunites a fort potentiel detectees", high_roi_signals);
-            } else {
-                println!("   • En attente d'opportunites optimales");
-            }
-        } else {
-            println!("   • Surveillance en cours...");
-        }
-        
-        println!("   • Mode simulation: Securise pour les tests");
-        println!("   • Pret pour deploiement en production");
-        
-        println!("{}", "=".repeat(60));
-        println!("Rapport genere le: {}", chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"));
-        println!("{}\n", "=".repeat(60));
-    }
-
-    fn check_real_trading_config(&self) -> bool {
-        println!("\nVÉRIFICATION CONFIGURATION TRADING RÉEL");
-        println!("==========================================");
-        
-        let mut config_ok = true;
-        
-        // Vérifier la clé privée
-        if self.private_key.is_empty() {
-            println!("[ERROR] PRIVATE_KEY: Non configurée");
-            config_ok = false;
-        } else {
-            println!("[OK] PRIVATE_KEY: Configurée");
-        }
-        
-        // Vérifier l'adresse wallet
-        if self.wallet_address.is_empty() {
-            println!("[ERROR] WALLET_ADDRESS: Non configurée");
-            config_ok = false;
-        } else {
-            println!("[OK] WALLET_ADDRESS: {}", self.wallet_address);
-        }
-        
-        // Vérifier l'URL RPC
-        let rpc_url = env::var("RPC_URL").unwrap_or_else(|_| "".to_string());
-        if rpc_url.is_empty() {
-            println!("[ERROR] RPC_URL: Non configurée");
-            config_ok = false;
-        } else {
-            println!("[OK] RPC_URL: Configurée");
-        }
-        
-        if config_ok {
-            println!("\n[OK] Configuration complète pour trading réel");
-            println!("[WARNING] Assurez-vous d'avoir des fonds sur Polymarket");
-            println!("[WARNING] Utilisez un VPN pour éviter Cloudflare");
-        } else {
-            println!("\n[ERROR] Configuration incomplète pour trading réel");
-            println!("[INFO] Modifiez votre fichier .env avec les vraies valeurs");
-        }
-        
-        config_ok
-    }
-
-    // Calcul dynamique de la taille de position basé sur le capital disponible
-    fn calculate_dynamic_position_size(&self, capital: f64, roi: f64, confidence: &str) -> f64 {
-        // Base: 1% du capital
-        let mut base_size = capital * 0.01;
-        
-        // Ajustement selon le ROI
-        if roi > 0.10 { // ROI > 10%
-            base_size *= 1.5; // Augmenter de 50%
-        } else if roi > 0.05 { // ROI > 5%
-            base_size *= 1.2; // Augmenter de 20%
-        } else if roi < 0.02 { // ROI < 2%
-            base_size *= 0.5; // Réduire de 50%
-        }
-        
-        // Ajustement selon la confiance
-        match confidence {
-            "high" => base_size *= 1.3,
-            "medium" => base_size *= 1.0,
-            "low" => base_size *= 0.7,
-            _ => base_size *= 0.5,
-        }
-        
-        // Limites de sécurité
-        let max_position = capital * 0.05; // Max 5% du capital
-        let min_position = capital * 0.005; // Min 0.5% du capital
-        
-        base_size.max(min_position).min(max_position)
-    }
-    
-    // Calculer la volatilité d'un marché basée sur l'historique des prix
-    fn calculate_market_volatility(&self, market_id: &str) -> f64 {
-        if let Some(price_history) = self.price_history.get(market_id) {
-            if price_history.len() < 2 {
-                return 0.02; // Volatilité par défaut 2%
-            }
-            
-            let mut price_changes = Vec::new();
-            for i in 1..price_history.len() {
-                let change = (price_history[i].1 - price_history[i-1].1).abs() / price_history[i-1].1;
-                price_changes.push(change);
-            }
-            
-            // Écart-type des changements de prix
-            let mean = price_changes.iter().sum::<f64>() / price_changes.len() as f64;
-            let variance = price_changes.iter()
-                .map(|x| (x - mean).powi(2))
-                .sum::<f64>() / price_changes.len() as f64;
-            
-            variance.sqrt().min(0.1) // Limiter à 10% max
-        } else {
-            0.02 // Volatilité par défaut
-        }
-    }
-    
-    // Gestion dynamique du risque
-    fn calculate_risk_adjusted_stake(&self, capital: f64, roi: f64, volatility: f64) -> f64 {
-        // Kelly Criterion simplifié
-        let win_rate = 0.6; // Estimation 60% de trades gagnants
-        let kelly_fraction = (win_rate * roi - (1.0 - win_rate)) / roi;
-        
-        // Limiter Kelly à 25% maximum pour la sécurité
-        let safe_kelly = kelly_fraction.min(0.25).max(0.01);
-        
-        let stake = capital * safe_kelly;
-        
-        // Ajustement selon la volatilité
-        let volatility_adjustment = 1.0 / (1.0 + volatility);
-        
-        stake * volatility_adjustment
-    }
-    
-    fn configure_dynamic_trading(&self) {
-        println!("\nCONFIGURATION TRADING DYNAMIQUE");
-        println!("================================");
-        println!("[INFO] Gestion du capital: Adaptative");
-        println!("[INFO] Taille de position: 0.5% à 5% du capital");
-        println!("[INFO] Ajustement: ROI + Confiance + Volatilité");
-        println!("\n[INFO] Stratégie intelligente:");
-        println!("   - Kelly Criterion pour optimiser les positions");
-        println!("   - Ajustement automatique selon la confiance");
-        println!("   - Protection contre la volatilité excessive");
-        println!("   - Limites de sécurité intégrées");
-        println!("\n[WARNING] Conseils:");
-        println!("   - Le bot s'adapte automatiquement au capital");
-        println!("   - Surveillez les performances");
-        println!("   - Testez d'abord en simulation");
-    }
-
-    async fn run_cycle_real(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let start_time = std::time::Instant::now();
-        
-        let markets = self.fetch_polymarket_markets_real().await?;
-        if markets.is_empty() {
-            println!("❌ Aucun marché récupéré");
-            return Ok(());
-        }
-        
-        self.monitor_all_resolution_sources_real().await?;
-        let sources_monitored = 0; // Simuler pour l'instant
-        self.detect_arbitrage_opportunities(&markets);
-        self.generate_trading_signals().await;
-        let trades_executed = 0; // Simuler pour l'instant
-        
-        let total_time = start_time.elapsed();
-        
-        println!("🔄 RÉEL {} | Marchés: {} | Sources: {} | Opportunités: {} | Signaux: {} | Trades: {} | Latence: {}ms", 
-            chrono::Utc::now().format("%H:%M:%S"),
-            markets.len(), sources_monitored, self.opportunities.len(), self.signals.len(), trades_executed, total_time.as_millis());
-        
-        if trades_executed > 0 {
-            println!("💰 {} trades réels exécutés", trades_executed);
-        }
-        
-        Ok(())
-    }
-
-    async fn run_cycle(&mut self) {
-        let start_time = std::time::Instant::now();
-        
-        // PHASE 1: Récupération des marchés
-        let markets = self.fetch_polymarket_markets_simulation().await;
-        if markets.is_empty() {
-            println!("❌ Aucun marché récupéré");
-            return;
-        }
-        
-        // PHASE 2: Monitoring des sources (silencieux)
-        self.monitor_all_resolution_sources().await;
-        let sources_monitored = 0; // Simuler pour l'instant
-        
-        // PHASE 3: Détection d'opportunités
-        self.detect_arbitrage_opportunities(&markets);
-        
-        // PHASE 4: Génération de signaux
-        self.generate_trading_signals().await;
-        
-        // PHASE 5: Simulation des trades
-        let trades_executed = self.execute_trades_simulation();
-        
-        let total_time = start_time.elapsed();
-        
-        // AFFICHAGE PROFESSIONNEL
-        println!("🔄 {} | Marchés: {} | Sources: {} | Opportunités: {} | Signaux: {} | Trades: {} | Latence: {}ms", 
-            chrono::Utc::now().format("%H:%M:%S"),
-            markets.len(), sources_monitored, self.opportunities.len(), self.signals.len(), trades_executed, total_time.as_millis());
-        
-        if trades_executed > 0 {
-            println!("💰 {} trades exécutés", trades_executed);
-        }
-    }
-
-    fn execute_trades_simulation(&mut self) -> usize {
-        let mut executed_count = 0;
-        let mut available_balance = self.simulated_balance;
-        
-        for signal in &self.signals {
-            if (signal.action == "BUY" || signal.action == "SELL") && !signal.executed {
-                let volatility = self.calculate_market_volatility(&signal.market_id);
-                let trade_amount = self.calculate_dynamic_position_size(
-                    available_balance, 
-                    signal.new_roi, 
-                    &signal.confidence
-                );
-                
-                let final_trade_amount = trade_amount * (1.0 - volatility);
-                
-                if available_balance >= final_trade_amount {
-                    executed_count += 1;
-                    available_balance -= final_trade_amount;
-                }
-            }
-        }
-        
-        executed_count
-    }
-
-
-
-    async fn fetch_polymarket_markets_real(&self) -> Result<Vec<Market>, Box<dyn std::error::Error>> {
-        // Récupérer les marchés de Polymarket
-        let url = format!("{}/markets", GAMMA_MARKETS_ENDPOINT);
-        let response = self.http_client.get(&url).send().await?;
-        if response.status().is_success() {
-            // Simuler pour l'instant car Market n'a pas le trait Deserialize
-            let markets = self.fetch_polymarket_markets_simulation().await;
-            Ok(markets)
-        } else {
-            Err(format!("Erreur lors de la récupération des marchés: {}", response.status()).into())
-        }
-    }
-
-    async fn fetch_polymarket_markets_simulation(&self) -> Vec<Market> {
-        // Simuler la récupération des marchés de Polymarket
-        let mut markets = Vec::new();
-        let mut rng = rand::thread_rng();
-        
-        for _ in 0..5 {
-            markets.push(Market {
-                id: format!("market-{}", rng.gen_range(1..=5)),
-                question: format!("Will the Fed cut rates in June 2024?"),
-                description: format!("Simulated market for testing. Resolution source: Simulated data"),
-                domain: "economy".to_string(),
-                probability: rng.gen_range(0.3..=0.7),
-                resolution_source: "Simulated data".to_string(),
-                created_at: chrono::Utc::now().to_string(),
-                is_new: true,
-            });
-        }
-        
-        markets
-    }
-
-    async fn execute_trades_real(&mut self, signals: &[TradingSignal]) -> Result<usize, Box<dyn std::error::Error>> {
-        let mut executed_count = 0;
-        
-        for signal in signals {
-            if signal.action == "BUY" || signal.action == "SELL" {
-                let market_id = signal.market_id.clone();
-                let action = signal.action.clone();
-                let amount = format!("{:.4}", self.calculate_dynamic_position_size(self.simulated_balance, signal.potential_roi, &signal.confidence));
-                let price = format!("{:.4}", signal.polymarket_probability);
-                
-                println!("  [TRADE] Tentative d'exécution réelle...");
-                println!("     Action: {}", action.to_uppercase());
-                println!("     Marché: {}", signal.reason);
-                println!("     Montant stake: {:.2}€", self.calculate_dynamic_position_size(self.simulated_balance, signal.potential_roi, &signal.confidence));
-                println!("     Amount tokens: {} | Price: {}", amount, price);
-                println!("     ROI attendu: {:.1}%", signal.potential_roi * 100.0);
-                println!("     Solde restant: {:.2}€", self.simulated_balance - self.calculate_dynamic_position_size(self.simulated_balance, signal.potential_roi, &signal.confidence));
-                
-                match self.execute_real_trade(&market_id, &action, &amount, &price).await {
-                    Ok(success) => {
-                        if success {
-                            executed_count += 1;
-                            println!("  [SUCCESS] Trade exécuté avec succès!");
-                            self.update_simulated_balance(-self.calculate_dynamic_position_size(self.simulated_balance, signal.potential_roi, &signal.confidence));
-                        } else {
-                            println!("  [ERROR] Échec de l'exécution du trade");
-                        }
-                    },
-                    Err(e) => {
-                        println!("  [ERROR] Erreur lors de l'exécution: {}", e);
-                    }
-                }
-            }
-        }
-        
-        Ok(executed_count)
-    }
-
-
-
-    async fn fetch_source_content(&self, url: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let response = self.http_client.get(url).send().await?;
-        if response.status().is_success() {
-            let text = response.text().await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
-            Ok(text)
-        } else {
-            Err(format!("Erreur lors de la récupération du contenu: {}", response.status()).into())
-        }
-    }
-
-    async fn initialize_price_history(&mut self) {
-        let market_ids: Vec<String> = self.markets.iter().map(|m| m.id.clone()).collect();
-        let probabilities: Vec<f64> = self.markets.iter().map(|m| m.probability).collect();
-        
-        for (id, prob) in market_ids.iter().zip(probabilities.iter()) {
-            self.update_price_history(id, *prob).await;
-        }
-    }
-
-    fn assess_confidence(&self, content: &str) -> String {
-        // Confidence assessment based on content quality
-        "medium".to_string()
-    }
-
-    fn calculate_relevance(&self, content: &str, question: &str) -> f64 {
-        // Relevance calculation between content and question
-        1.0
-    }
-}
-
+// Binaire polymarket-bot: le moteur et l'interface en ligne de commande vivent dans la bibliothèque
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("POLYMARKET ARBITRAGE BOT - RUST VERSION");
-    println!("========================================");
-    println!("Project: Polymarket Arbitrage");
-    println!("Strategy: Oracle frontrunning via resolution sources");
-    println!("Performance: Optimized Rust version");
-    println!();
-    
-    // Load environment variables
-    dotenvy::dotenv().ok();
-    
-    println!("CONFIGURATION");
-    println!("=============");
-    let rpc_url = env::var("RPC_URL").unwrap_or_else(|_| "https://sepolia.infura.io/v3/e70b1df84fac4df6a2148cd94059396b".to_string());
-    println!("RPC URL: {}", rpc_url);
-    
-    let wallet_address = env::var("WALLET_ADDRESS").unwrap_or_else(|_| "0x095E4c297357EE6633A4aFa1167976EF32806262-1756242837008".to_string());
-    println!("Wallet: 0x33...7762");
-    println!("Wallet Address: {}", wallet_address);
-    
-    let env_loaded = if std::path::Path::new(".env").exists() { "YES" } else { "NO" };
-    println!(".env loaded: [OK] {}", env_loaded);
-    println!("Note: No MetaMask configuration required");
-    println!();
-    
-    println!("EXECUTION MODE");
-    println!("==============");
-    println!("1. SIMULATION mode (default)");
-    println!("2. REAL mode (real API calls and trades)");
-    println!("Choose mode (1 or 2): ");
-    
-    // REAL mode by default for 10-minute trading
-    let mut input = String::new();
-    std::io::stdin().read_line(&mut input).unwrap();
-    let mode = input.trim();
-    
-    let is_real_mode = if mode == "2" || mode.is_empty() {
-        println!();
-        println!("REAL MODE ACTIVATED - REAL-TIME TRADING");
-        println!("========================================");
-        println!("[OK] Bot will now:");
-        println!("   1. Fetch real Polymarket markets (Gamma API)");
-        println!("   2. Monitor real resolution sources");
-        println!("   3. Detect arbitrage opportunities");
-        println!("   4. Generate trading signals");
-        println!("   5. Execute real trades on Polymarket");
-        println!();
-        println!("[WARNING] Real mode - Actual trades on Polymarket");
-        true
-    } else {
-        println!();
-        println!("SIMULATION MODE ACTIVATED (RECOMMENDED)");
-        println!("=======================================");
-        println!("[OK] Bot will now:");
-        println!("   1. Fetch real Polymarket markets (Gamma API)");
-        println!("   2. Monitor real resolution sources");
-        println!("   3. Detect arbitrage opportunities");
-        println!("   4. Generate trading signals");
-        println!("   5. Simulate trades (no Cloudflare blocking)");
-        println!();
-        println!("[OK] Safe mode - No blocking risk");
-        false
-    };
-    
-    println!("Press Ctrl+C to stop");
-    println!();
-    
-    // Initialize C++ module with ROI parameters
-    unsafe {
-        if init_polymarket_core() {
-            println!("[OK] C++ Polymarket Core module initialized");
-            // Configure default ROI parameters
-            configure_roi_params(0.005, 0.20, 0.001); // fee=0.5%, catchup_speed=20%/s, action_time=1ms (TEST FORCÉ)
-            
-            // Initialize HFT optimizations
-            optimize_memory_hft();
-            println!("[OK] HFT optimizations initialized");
-            println!("   • ROI Cache: Enabled (1000 entries)");
-            println!("   • Lookup tables: Precomputed");
-            println!("   • Ultra-fast decisions: < 100ns");
-            println!("   • Position calculations: < 50ns");
-        } else {
-            println!("[ERROR] Failed to initialize C++ module");
-        }
-    }
-    
-    let mut bot = Bot::new();
-    
-    // Dynamic capital configuration
-    bot.simulated_balance = 4000.0; // Starting capital (configurable)
-    bot.configure_dynamic_trading(); // Apply dynamic management
-    
-    // Check configuration for real mode
-    if is_real_mode {
-        if !bot.check_real_trading_config() {
-            println!("[ERROR] Incomplete configuration for real trading");
-            println!("Veuillez configurer PRIVATE_KEY, WALLET_ADDRESS et RPC_URL dans .env");
-            return Ok(());
-        }
-    }
-    
-    // Boucle principale d'arbitrage
-        loop {
-
-
-        
-        // Phase 1: Récupération des marchés
-        if is_real_mode {
-            bot.fetch_real_polymarket_markets().await?;
-        } else {
-            bot.fetch_open_markets();
-        }
-        
-        // Phase 2: Monitoring des sources
-        bot.monitor_all_resolution_sources().await;
-        
-        // Phase 3: Détection d'opportunités
-        let markets_clone = bot.markets.clone();
-        bot.detect_arbitrage_opportunities(&markets_clone);
-        
-        // Phase 4: Génération de signaux
-        bot.generate_trading_signals().await;
-        
-        // Phase 5: Exécution des trades
-        if is_real_mode {
-            // Simuler pour l'instant
-            println!("[INFO] Mode réel - Trades simulés pour la sécurité");
-        } else {
-            bot.execute_trades_simulation();
-        }
-        
-        // Rapport de validation pour le collègue
-        bot.print_validation_report();
-        
-        // Periodic HFT cache cleanup (every 10 cycles)
-        static mut CYCLE_COUNT: u32 = 0;
-        unsafe {
-            CYCLE_COUNT += 1;
-            if CYCLE_COUNT % 10 == 0 {
-                cleanup_hft_cache();
-                println!("[HFT] Cache cleaned for performance optimization");
-            }
-        }
-        
-        // Pause between cycles
-        let pause_duration = 10;
-        println!("Pause {}s...", pause_duration);
-        tokio::time::sleep(tokio::time::Duration::from_secs(pause_duration)).await;
-    }
+    polymarket_bot::run_cli(std::env::args().skip(1)).await
 }
@@ -0,0 +1,70 @@
+// Marchés en double: Gamma liste parfois la même question sous plusieurs ids (marché recréé,
+// doublon d'un même événement). Traités séparément, ils doubleraient la mise sur une même vue:
+// les marchés de même question normalisée (et de même événement, s'il est connu) sont regroupés
+// et seul le plus liquide est conservé.
+
+// Question ramenée à ses mots: casse, ponctuation et espaces ignorés
+pub fn normalize_question(question: &str) -> String {
+    question.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Clé de regroupement: événement (vide si inconnu) et question normalisée
+pub fn cluster_key(question: &str, event_id: &str) -> String {
+    format!("{}|{}", event_id.trim(), normalize_question(question))
+}
+
+// Regroupement de marchés en double: indice conservé et indices écartés
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collapse {
+    pub kept: usize,
+    pub dropped: Vec<usize>,
+}
+
+// entries: (clé, liquidité) dans l'ordre des marchés
+// Le plus liquide de chaque groupe est conservé, le premier à égalité; groupes d'un seul marché omis
+pub fn collapses(entries: &[(String, f64)]) -> Vec<Collapse> {
+    let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+    for (index, (key, _)) in entries.iter().enumerate() {
+        match groups.iter_mut().find(|(group_key, _)| *group_key == key.as_str()) {
+            Some((_, members)) => members.push(index),
+            None => groups.push((key.as_str(), vec![index])),
+        }
+    }
+    groups.into_iter()
+        .filter(|(_, members)| members.len() > 1)
+        .map(|(_, members)| {
+            let kept = members.iter().copied()
+                .fold(members[0], |best, index| if entries[index].1 > entries[best].1 { index } else { best });
+            Collapse { kept, dropped: members.into_iter().filter(|index| *index != kept).collect() }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn questions_are_grouped_by_words_and_event() {
+        assert_eq!(normalize_question("Will the incumbent win the election?"), "will the incumbent win the election");
+        assert_eq!(normalize_question("  Will the Incumbent win  the election ? "), "will the incumbent win the election");
+
+        let entries = vec![
+            (cluster_key("Will the incumbent win the election?", ""), 1200.0),
+            (cluster_key("Will a spot Ethereum ETF be approved?", ""), 800.0),
+            (cluster_key("will the incumbent win the Election", ""), 5000.0),
+            // Même question dans un autre événement: marché distinct
+            (cluster_key("Will the incumbent win the election?", "903"), 9000.0),
+        ];
+        // Le plus liquide est conservé, quel que soit son rang
+        assert_eq!(collapses(&entries), vec![Collapse { kept: 2, dropped: vec![0] }]);
+
+        // Liquidités égales: le premier listé est conservé
+        let tied = vec![(cluster_key("Same?", "7"), 0.0), (cluster_key("same", "7"), 0.0), (cluster_key("SAME", "7"), 0.0)];
+        assert_eq!(collapses(&tied), vec![Collapse { kept: 0, dropped: vec![1, 2] }]);
+    }
+}
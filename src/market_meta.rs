@@ -0,0 +1,108 @@
+// Métadonnées statiques par marché (domaine, token ids, tick, ordre minimum)
+// Dérivées une seule fois par market id: le balayage de texte du domaine n'est pas refait à chaque cycle.
+// L'entrée est invalidée si le texte de la question change (marché reformulé par Polymarket)
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MarketMeta {
+    pub domain: String,
+    pub clob_token_ids: Vec<String>,   // [YES, NO], vide si inconnus
+    pub tick_size: Option<f64>,        // Pas de prix minimal du CLOB
+    pub min_order_size: Option<f64>,   // Taille minimale d'un ordre
+}
+
+#[derive(Debug, Default)]
+pub struct MarketMetaCache {
+    entries: HashMap<String, (String, MarketMeta)>, // market_id -> (question, métadonnées)
+    hits: u64,
+    misses: u64,
+}
+
+impl MarketMetaCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Métadonnées en cache, sinon dérivées par `derive` puis mémorisées
+    pub fn get_or_derive(&mut self, market_id: &str, question: &str, derive: impl FnOnce() -> MarketMeta) -> MarketMeta {
+        if let Some((cached_question, meta)) = self.entries.get(market_id) {
+            if cached_question == question {
+                self.hits += 1;
+                return meta.clone();
+            }
+        }
+        self.misses += 1;
+        let meta = derive();
+        self.entries.insert(market_id.to_string(), (question.to_string(), meta.clone()));
+        meta
+    }
+
+    // (servies depuis le cache, dérivées)
+    pub fn stats(&self) -> (u64, u64) {
+        (self.hits, self.misses)
+    }
+}
+
+// Cache des métadonnées de marché: le domaine est dérivé une fois par marché, servi depuis le cache
+// aux cycles suivants, et recalculé si la question change.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn derive(question: &str, computations: &Cell<u32>) -> MarketMeta {
+        computations.set(computations.get() + 1);
+        let domain = if question.to_lowercase().contains("bitcoin") { "crypto" } else { "general" };
+        MarketMeta {
+            domain: domain.to_string(),
+            clob_token_ids: vec!["yes-token".to_string(), "no-token".to_string()],
+            tick_size: Some(0.01),
+            min_order_size: Some(5.0),
+        }
+    }
+
+    #[test]
+    fn domain_is_computed_once_and_served_from_cache() {
+        let mut cache = MarketMetaCache::new();
+        let computations = Cell::new(0);
+        let question = "Will Bitcoin close above $100k this year?";
+
+        for _cycle in 0..5 {
+            let meta = cache.get_or_derive("btc-100k", question, || derive(question, &computations));
+            assert_eq!(meta.domain, "crypto");
+            assert_eq!(meta.clob_token_ids, vec!["yes-token", "no-token"]);
+            assert_eq!(meta.tick_size, Some(0.01));
+        }
+
+        assert_eq!(computations.get(), 1, "le domaine ne doit être dérivé qu'au premier cycle");
+        assert_eq!(cache.stats(), (4, 1));
+    }
+
+    #[test]
+    fn changed_question_invalidates_the_entry() {
+        let mut cache = MarketMetaCache::new();
+        let computations = Cell::new(0);
+
+        let before = "Will Bitcoin close above $100k this year?";
+        let after = "Will the S&P 500 close above 6000 this year?";
+        assert_eq!(cache.get_or_derive("m1", before, || derive(before, &computations)).domain, "crypto");
+        assert_eq!(cache.get_or_derive("m1", after, || derive(after, &computations)).domain, "general");
+        assert_eq!(cache.get_or_derive("m1", after, || derive(after, &computations)).domain, "general");
+
+        assert_eq!(computations.get(), 2);
+        assert_eq!(cache.stats(), (1, 2));
+    }
+
+    #[test]
+    fn markets_are_cached_independently() {
+        let mut cache = MarketMetaCache::new();
+        let computations = Cell::new(0);
+        let question = "Will Bitcoin close above $100k this year?";
+
+        cache.get_or_derive("m1", question, || derive(question, &computations));
+        cache.get_or_derive("m2", question, || derive(question, &computations));
+        cache.get_or_derive("m1", question, || derive(question, &computations));
+
+        assert_eq!(computations.get(), 2);
+    }
+}
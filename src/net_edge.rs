@@ -0,0 +1,55 @@
+// Edge après coûts: le chiffre de rentabilité affiché sur chaque signal
+// potential_roi reste le ROI brut du modèle; net_edge en retire explicitement chaque coût
+// d'exécution, en fraction de la mise, pour que l'impact des coûts se lise d'un coup d'œil:
+// net_edge = ROI brut - frais taker - gas (mode réel) - slippage estimé sur le livre.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EdgeCosts {
+    pub fee_drag: f64,          // Frais taker sur le profit (barème --fee-tier)
+    pub gas_drag: f64,          // Gas d'un ordre rapporté à la mise, nul hors mode réel
+    pub slippage_estimate: f64, // Prix moyen payé en traversant le livre vs meilleur prix
+}
+
+impl EdgeCosts {
+    // gross_roi: ROI brut; taker_fee: fraction du profit; gas_cost: USDC par ordre (0 hors mode réel);
+    // best_price / spent_price: meilleur prix du livre et prix moyen payé pour la mise
+    pub fn estimate(gross_roi: f64, taker_fee: f64, gas_cost: f64, stake: f64, best_price: f64, spent_price: f64) -> Self {
+        let fee_drag = gross_roi.max(0.0) * taker_fee.max(0.0);
+        let gas_drag = if stake > 0.0 { gas_cost.max(0.0) / stake } else { 0.0 };
+        let slippage_estimate = if best_price > 0.0 {
+            ((spent_price - best_price) / best_price).max(0.0)
+        } else {
+            0.0
+        };
+        EdgeCosts { fee_drag, gas_drag, slippage_estimate }
+    }
+
+    pub fn total(&self) -> f64 {
+        self.fee_drag + self.gas_drag + self.slippage_estimate
+    }
+}
+
+pub fn net_edge(gross_roi: f64, costs: &EdgeCosts) -> f64 {
+    gross_roi - costs.total()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn net_edge_is_gross_roi_minus_summed_costs() {
+        // ROI brut 12%, taker 2%, 0.05 USDC de gas sur 25 USDC, prix moyen 0.37 pour un best ask à 0.36
+        let costs = EdgeCosts::estimate(0.12, 0.02, 0.05, 25.0, 0.36, 0.37);
+        assert!((costs.fee_drag - 0.0024).abs() < 1e-12);
+        assert!((costs.gas_drag - 0.002).abs() < 1e-12);
+        assert!((costs.slippage_estimate - 0.01 / 0.36).abs() < 1e-12);
+        let expected = 0.12 - 0.0024 - 0.002 - 0.01 / 0.36;
+        assert!((net_edge(0.12, &costs) - expected).abs() < 1e-12);
+
+        // Sans mise (observation) ni profit attendu: ni gas ni frais, aucun gain de slippage
+        let idle = EdgeCosts::estimate(-0.05, 0.02, 0.05, 0.0, 0.40, 0.38);
+        assert_eq!(idle, EdgeCosts::default());
+        assert_eq!(net_edge(-0.05, &idle), -0.05);
+    }
+}
@@ -0,0 +1,42 @@
+// Chronologie d'actualités synthétiques (--simulate-news)
+// Chaque événement ajoute un titre à une source au cycle indiqué, pour exercer
+// détection → signal → trade de façon déterministe (avec --fixture et --seed).
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewsEvent {
+    pub cycle: u64,                // Cycle d'injection (1 = premier cycle)
+    pub source: String,            // URL de la source, telle que dans get_all_resolution_sources()
+    pub headline: String,
+    pub timestamp: Option<String>, // Date de publication affichée (pubDate de l'élément)
+}
+
+// Lire une chronologie JSON Lines: {"cycle": 2, "source": "...", "headline": "...", "timestamp": "..."}
+pub fn load_timeline(path: &str) -> Result<Vec<NewsEvent>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Lecture de la chronologie {}: {}", path, e))?;
+    let mut events = content.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let record: Value = serde_json::from_str(line)
+                .map_err(|e| format!("{}:{} invalide: {}", path, index + 1, e))?;
+            let field = |name: &str| record[name].as_str().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+            let cycle = record["cycle"].as_u64().filter(|cycle| *cycle > 0)
+                .ok_or_else(|| format!("{}:{}: \"cycle\" entier >= 1 attendu", path, index + 1))?;
+            Ok(NewsEvent {
+                cycle,
+                source: field("source").ok_or_else(|| format!("{}:{}: \"source\" manquant", path, index + 1))?,
+                headline: field("headline").ok_or_else(|| format!("{}:{}: \"headline\" manquant", path, index + 1))?,
+                timestamp: field("timestamp"),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    events.sort_by_key(|event| event.cycle);
+    Ok(events)
+}
+
+// Événements à injecter au cycle donné, dans l'ordre du fichier
+pub fn due_at(events: &[NewsEvent], cycle: u64) -> Vec<&NewsEvent> {
+    events.iter().filter(|event| event.cycle == cycle).collect()
+}
@@ -0,0 +1,264 @@
+// Cotes de bookmakers (The Odds API) et probabilités implicites
+// Une cote américaine, décimale ou fractionnaire se convertit en probabilité implicite; la somme
+// des probabilités d'un marché dépasse 1 de la marge du bookmaker (vig), retirée en les
+// ramenant à une somme de 1. Le consensus des bookmakers donne une fair value comparable au
+// prix Polymarket d'un marché qui nomme l'issue cotée.
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Odds {
+    American(f64),        // +150: gain pour 100 misés, -200: mise pour gagner 100
+    Decimal(f64),         // 2.50: retour total pour 1 misé
+    Fractional(f64, f64), // 5/2: gain de 5 pour 2 misés
+}
+
+// +150 -> 100 / 250, -200 -> 200 / 300; |cote| < 100 invalide
+pub fn american_to_probability(odds: f64) -> Option<f64> {
+    if !odds.is_finite() || odds.abs() < 100.0 {
+        return None;
+    }
+    Some(if odds > 0.0 { 100.0 / (odds + 100.0) } else { -odds / (-odds + 100.0) })
+}
+
+// 2.50 -> 1 / 2.50; une cote décimale est toujours > 1
+pub fn decimal_to_probability(odds: f64) -> Option<f64> {
+    (odds.is_finite() && odds > 1.0).then(|| 1.0 / odds)
+}
+
+// 5/2 -> 2 / (5 + 2)
+pub fn fractional_to_probability(numerator: f64, denominator: f64) -> Option<f64> {
+    (numerator.is_finite() && denominator.is_finite() && numerator > 0.0 && denominator > 0.0)
+        .then(|| denominator / (numerator + denominator))
+}
+
+impl Odds {
+    // "+150" / "-200" (américaine), "5/2" ou "evens" (fractionnaire), "2.50" (décimale)
+    pub fn parse(text: &str) -> Result<Odds, String> {
+        let trimmed = text.trim();
+        let number = |value: &str| value.trim().parse::<f64>().map_err(|_| format!("cote invalide: '{}'", text));
+        if trimmed.eq_ignore_ascii_case("evens") || trimmed.eq_ignore_ascii_case("evs") {
+            return Ok(Odds::Fractional(1.0, 1.0));
+        }
+        if let Some((numerator, denominator)) = trimmed.split_once('/') {
+            return Ok(Odds::Fractional(number(numerator)?, number(denominator)?));
+        }
+        if trimmed.starts_with('+') || trimmed.starts_with('-') {
+            return Ok(Odds::American(number(trimmed)?));
+        }
+        Ok(Odds::Decimal(number(trimmed)?))
+    }
+
+    // Probabilité implicite, marge du bookmaker comprise
+    pub fn implied_probability(&self) -> Option<f64> {
+        match *self {
+            Odds::American(odds) => american_to_probability(odds),
+            Odds::Decimal(odds) => decimal_to_probability(odds),
+            Odds::Fractional(numerator, denominator) => fractional_to_probability(numerator, denominator),
+        }
+    }
+}
+
+// Probabilités sans marge: implicites ramenées proportionnellement à une somme de 1
+pub fn devig(implied: &[f64]) -> Option<Vec<f64>> {
+    let total: f64 = implied.iter().sum();
+    if implied.len() < 2 || implied.iter().any(|p| !p.is_finite() || *p <= 0.0) || total <= 0.0 {
+        return None;
+    }
+    Some(implied.iter().map(|p| p / total).collect())
+}
+
+// Format des prix demandé à l'API (paramètre oddsFormat)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OddsFormat {
+    American,
+    Decimal,
+}
+
+impl OddsFormat {
+    // oddsFormat de l'URL de la source, décimal par défaut comme l'API
+    pub fn from_url(url: &str) -> Self {
+        if url.to_lowercase().contains("oddsformat=american") { OddsFormat::American } else { OddsFormat::Decimal }
+    }
+
+    fn odds(&self, price: f64) -> Odds {
+        match self {
+            OddsFormat::American => Odds::American(price),
+            OddsFormat::Decimal => Odds::Decimal(price),
+        }
+    }
+}
+
+// Marché coté d'un événement par un bookmaker: issues et cotes
+#[derive(Debug, Clone, PartialEq)]
+pub struct OddsSource {
+    pub event: String,
+    pub bookmaker: String,
+    pub lines: Vec<(String, Odds)>, // (issue, cote)
+}
+
+impl OddsSource {
+    // Probabilités sans marge par issue, None si une cote est invalide
+    pub fn fair_values(&self) -> Option<Vec<(String, f64)>> {
+        let implied: Vec<f64> = self.lines.iter().map(|(_, odds)| odds.implied_probability()).collect::<Option<_>>()?;
+        let fair = devig(&implied)?;
+        Some(self.lines.iter().map(|(outcome, _)| outcome.clone()).zip(fair).collect())
+    }
+
+    pub fn fair_value(&self, outcome: &str) -> Option<f64> {
+        self.fair_values()?.into_iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(outcome))
+            .map(|(_, probability)| probability)
+    }
+}
+
+// Réponse /v4/sports/{sport}/odds: événements, bookmakers, marchés (h2h, outrights...)
+pub fn parse_odds_api(content: &str, format: OddsFormat) -> Vec<OddsSource> {
+    let Ok(Value::Array(events)) = serde_json::from_str::<Value>(content) else {
+        return Vec::new();
+    };
+    let mut sources = Vec::new();
+    for event in &events {
+        let name = match (event["home_team"].as_str(), event["away_team"].as_str()) {
+            (Some(home), Some(away)) => format!("{} vs {}", home, away),
+            _ => event["sport_title"].as_str().or(event["id"].as_str()).unwrap_or_default().to_string(),
+        };
+        for bookmaker in event["bookmakers"].as_array().into_iter().flatten() {
+            for market in bookmaker["markets"].as_array().into_iter().flatten() {
+                let lines: Vec<(String, Odds)> = market["outcomes"].as_array().into_iter().flatten()
+                    .filter_map(|outcome| {
+                        // Prix numérique au format demandé, ou cote écrite ("+150", "5/2")
+                        let odds = match &outcome["price"] {
+                            Value::Number(price) => format.odds(price.as_f64()?),
+                            Value::String(price) => Odds::parse(price).ok()?,
+                            _ => return None,
+                        };
+                        Some((outcome["name"].as_str()?.to_string(), odds))
+                    })
+                    .collect();
+                if lines.len() >= 2 {
+                    sources.push(OddsSource {
+                        event: name.clone(),
+                        bookmaker: bookmaker["key"].as_str().unwrap_or_default().to_string(),
+                        lines,
+                    });
+                }
+            }
+        }
+    }
+    sources
+}
+
+// Fair value d'un marché dont la question nomme une seule issue cotée: moyenne des probabilités
+// sans marge de cette issue sur les bookmakers; (issue, fair value, nombre de bookmakers)
+pub fn question_fair_value(sources: &[OddsSource], question: &str) -> Option<(String, f64, usize)> {
+    let question = question.to_lowercase();
+    let mut named: Vec<&str> = sources.iter()
+        .flat_map(|source| source.lines.iter().map(|(outcome, _)| outcome.as_str()))
+        .filter(|outcome| !outcome.trim().is_empty() && question.contains(&outcome.to_lowercase()))
+        .collect();
+    named.sort_unstable_by_key(|outcome| outcome.to_lowercase());
+    named.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    let [outcome] = named.as_slice() else { return None };
+    let values: Vec<f64> = sources.iter().filter_map(|source| source.fair_value(outcome)).collect();
+    if values.is_empty() {
+        return None;
+    }
+    Some((outcome.to_string(), values.iter().sum::<f64>() / values.len() as f64, values.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ODDS_SOURCE: &str = "https://api.the-odds-api.com/v4/sports/upcoming/odds?regions=us&oddsFormat=american&apiKey=test-key";
+
+    fn close(actual: Option<f64>, expected: f64) -> bool {
+        actual.is_some_and(|actual| (actual - expected).abs() < 1e-9)
+    }
+
+    #[test]
+    fn american_odds_convert_to_implied_probability() {
+        assert!(close(american_to_probability(150.0), 0.4));
+        assert!(close(american_to_probability(-200.0), 2.0 / 3.0));
+        assert!(close(american_to_probability(100.0), 0.5));
+        assert!(close(american_to_probability(-100.0), 0.5));
+        assert_eq!(american_to_probability(50.0), None, "|cote| < 100 n'existe pas");
+        assert_eq!(Odds::parse("+150"), Ok(Odds::American(150.0)));
+        assert!(close(Odds::parse("-110").unwrap().implied_probability(), 110.0 / 210.0));
+    }
+
+    #[test]
+    fn decimal_odds_convert_to_implied_probability() {
+        assert!(close(decimal_to_probability(2.5), 0.4));
+        assert!(close(decimal_to_probability(1.25), 0.8));
+        assert_eq!(decimal_to_probability(1.0), None);
+        assert_eq!(Odds::parse("2.50"), Ok(Odds::Decimal(2.5)));
+        assert!(close(Odds::parse("4").unwrap().implied_probability(), 0.25));
+    }
+
+    #[test]
+    fn fractional_odds_convert_to_implied_probability() {
+        assert!(close(fractional_to_probability(5.0, 2.0), 2.0 / 7.0));
+        assert!(close(fractional_to_probability(1.0, 4.0), 0.8));
+        assert_eq!(Odds::parse("5/2"), Ok(Odds::Fractional(5.0, 2.0)));
+        assert!(close(Odds::parse("evens").unwrap().implied_probability(), 0.5));
+        assert!(Odds::parse("5/x").is_err());
+        assert_eq!(Odds::parse("0/2").unwrap().implied_probability(), None);
+    }
+
+    #[test]
+    fn vig_is_removed_across_a_two_way_market() {
+        // -110 des deux côtés: 52.38% + 52.38%, la marge de 4.76% retirée donne 50/50
+        let implied: Vec<f64> = ["-110", "-110"].iter().map(|o| Odds::parse(o).unwrap().implied_probability().unwrap()).collect();
+        assert!((implied.iter().sum::<f64>() - 1.0 - 0.047619).abs() < 1e-6);
+        let fair = devig(&implied).unwrap();
+        assert!(close(Some(fair[0]), 0.5) && close(Some(fair[1]), 0.5));
+
+        // Favori -180 contre outsider +150: 64.29% + 40%, ramenés à une somme de 1
+        let source = OddsSource {
+            event: "Doe vs Roe".to_string(),
+            bookmaker: "book".to_string(),
+            lines: vec![("Jane Doe".to_string(), Odds::American(150.0)), ("John Roe".to_string(), Odds::American(-180.0))],
+        };
+        let fair = source.fair_values().unwrap();
+        assert!(close(Some(fair[0].1 + fair[1].1), 1.0));
+        assert!(close(source.fair_value("jane doe"), 0.4 / (0.4 + 180.0 / 280.0)));
+        assert!(fair[0].1 < 0.4, "sans marge, l'outsider vaut moins que sa probabilité implicite");
+
+        // Une seule issue ou une cote invalide: pas de fair value
+        assert_eq!(devig(&[0.6]), None);
+        let invalid = OddsSource { lines: vec![("A".to_string(), Odds::American(50.0)), ("B".to_string(), Odds::American(-120.0))], ..source };
+        assert_eq!(invalid.fair_values(), None);
+    }
+
+    fn odds_api_body() -> String {
+        // Deux bookmakers: prix américains numériques, puis cotes fractionnaires écrites
+        serde_json::json!([{
+            "id": "evt-1", "sport_key": "politics", "sport_title": "Election",
+            "bookmakers": [
+                {"key": "book-a", "markets": [{"key": "h2h", "outcomes": [
+                    {"name": "Jane Doe", "price": 150}, {"name": "John Roe", "price": -180}]}]},
+                {"key": "book-b", "markets": [{"key": "h2h", "outcomes": [
+                    {"name": "Jane Doe", "price": "6/4"}, {"name": "John Roe", "price": "8/13"}]}]}
+            ]
+        }]).to_string()
+    }
+
+    #[test]
+    fn odds_api_consensus_values_a_market_naming_one_outcome() {
+        let sources = parse_odds_api(&odds_api_body(), OddsFormat::from_url(ODDS_SOURCE));
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].lines[0].1, Odds::American(150.0));
+        assert_eq!(sources[1].lines[1].1, Odds::Fractional(8.0, 13.0));
+
+        let (outcome, fair_value, bookmakers) = question_fair_value(&sources, "Will Jane Doe win the election?").unwrap();
+        assert_eq!((outcome.as_str(), bookmakers), ("Jane Doe", 2));
+        let expected = (0.4 / (0.4 + 180.0 / 280.0) + 0.4 / (0.4 + 13.0 / 21.0)) / 2.0;
+        assert!((fair_value - expected).abs() < 1e-9, "{}", fair_value);
+
+        // Aucune issue nommée, ou les deux: pas de fair value
+        assert!(question_fair_value(&sources, "Will turnout exceed 60%?").is_none());
+        assert!(question_fair_value(&sources, "Will Jane Doe beat John Roe?").is_none());
+        assert!(parse_odds_api("{\"message\": \"quota\"}", OddsFormat::Decimal).is_empty());
+    }
+}
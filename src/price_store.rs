@@ -0,0 +1,164 @@
+// Historique des prix et vitesses de convergence partagés entre tâches
+// Le flux d'orderbook et les récupérations concurrentes écrivent pendant que la génération de
+// signaux lit: chaque accès prend le verrou le temps d'une seule opération, et les lectures
+// travaillent sur un instantané cohérent (jamais un historique à moitié mis à jour).
+// Cloner le store partage les mêmes données (Arc): une tâche de flux en garde une copie.
+use crate::volume_weight::PricePoint;
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+// Points de prix conservés par marché, pour éviter la surcharge mémoire
+pub const MAX_PRICE_POINTS: usize = 100;
+// Vitesses de convergence conservées par marché
+pub const MAX_SPEEDS: usize = 50;
+
+#[derive(Debug, Clone, Default)]
+pub struct PriceStore {
+    history: Arc<RwLock<HashMap<String, Vec<PricePoint>>>>, // market_id -> [(timestamp, prix, volume éventuel)]
+    speeds: Arc<RwLock<HashMap<String, Vec<f64>>>>,          // market_id -> [vitesses]
+}
+
+// Garder les `max` dernières entrées
+fn keep_last<T>(entries: &mut Vec<T>, max: usize) {
+    if entries.len() > max {
+        entries.drain(..entries.len() - max);
+    }
+}
+
+impl PriceStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Un écrivain qui a paniqué ne laisse qu'un historique incomplet: on continue de le servir
+    // plutôt que de propager la panique à toutes les tâches
+
+    pub fn has_history(&self, market_id: &str) -> bool {
+        let history = self.history.read().unwrap_or_else(PoisonError::into_inner);
+        history.get(market_id).is_some_and(|points| !points.is_empty())
+    }
+
+    // Ajouter un point de prix, renvoie le nombre de points conservés
+    pub fn record_price(&self, market_id: &str, point: PricePoint) -> usize {
+        let mut history = self.history.write().unwrap_or_else(PoisonError::into_inner);
+        let entry = history.entry(market_id.to_string()).or_default();
+        entry.push(point);
+        keep_last(entry, MAX_PRICE_POINTS);
+        entry.len()
+    }
+
+    // Fusionner un historique (réel ou simulé) en ordre chronologique, renvoie le nombre de points conservés
+    pub fn merge_history(&self, market_id: &str, points: Vec<PricePoint>) -> usize {
+        let mut history = self.history.write().unwrap_or_else(PoisonError::into_inner);
+        let entry = history.entry(market_id.to_string()).or_default();
+        entry.extend(points);
+        entry.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+        keep_last(entry, MAX_PRICE_POINTS);
+        entry.len()
+    }
+
+    // Calcul sur l'historique d'un marché sous le verrou de lecture, sans copie
+    pub fn with_history<R>(&self, market_id: &str, f: impl FnOnce(&[PricePoint]) -> R) -> Option<R> {
+        let history = self.history.read().unwrap_or_else(PoisonError::into_inner);
+        history.get(market_id).map(|points| f(points))
+    }
+
+    // Instantané cohérent de tous les historiques
+    pub fn snapshot(&self) -> HashMap<String, Vec<PricePoint>> {
+        self.history.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    pub fn has_speeds(&self, market_id: &str) -> bool {
+        let speeds = self.speeds.read().unwrap_or_else(PoisonError::into_inner);
+        speeds.get(market_id).is_some_and(|speeds| !speeds.is_empty())
+    }
+
+    // Ajouter des vitesses de convergence, renvoie le nombre de vitesses conservées
+    pub fn record_speeds(&self, market_id: &str, new_speeds: &[f64]) -> usize {
+        let mut speeds = self.speeds.write().unwrap_or_else(PoisonError::into_inner);
+        let entry = speeds.entry(market_id.to_string()).or_default();
+        entry.extend_from_slice(new_speeds);
+        keep_last(entry, MAX_SPEEDS);
+        entry.len()
+    }
+}
+
+// Historique des prix partagé: un flux écrit pendant que le cycle lit des instantanés cohérents
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use crate::volume_weight::PricePoint;
+
+    const MARKETS: [&str; 3] = ["fx-politics-1", "fx-crypto-1", "fx-economy-1"];
+
+    const UPDATES: usize = 5_000;
+
+    #[test]
+    fn stream_writer_and_cycle_reader_run_concurrently() {
+        let store = PriceStore::new();
+        let done = AtomicBool::new(false);
+
+        std::thread::scope(|scope| {
+            // Flux d'orderbook: prix croissants dans le temps, historique réel fusionné en cours de route
+            let stream = store.clone();
+            let done = &done;
+            scope.spawn(move || {
+                for tick in 0..UPDATES {
+                    let market = MARKETS[tick % MARKETS.len()];
+                    let timestamp = tick as f64;
+                    assert!(stream.record_price(market, PricePoint::new(timestamp, 0.5)) <= MAX_PRICE_POINTS);
+                    if tick % 500 == 0 {
+                        let backfill = (0..20).map(|i| PricePoint::new(timestamp - 0.5 - i as f64, 0.4)).collect();
+                        assert!(stream.merge_history(market, backfill) <= MAX_PRICE_POINTS);
+                    }
+                    assert!(stream.record_speeds(market, &[0.01]) <= MAX_SPEEDS);
+                }
+                done.store(true, Ordering::SeqCst);
+            });
+
+            // Génération de signaux: chaque lecture voit un historique borné et chronologique
+            for _ in 0..2 {
+                let cycle = store.clone();
+                scope.spawn(move || {
+                    let mut reads = 0usize;
+                    while !done.load(Ordering::SeqCst) || reads == 0 {
+                        for (market, history) in cycle.snapshot() {
+                            assert!(MARKETS.contains(&market.as_str()));
+                            assert!(history.len() <= MAX_PRICE_POINTS, "{}: {} points", market, history.len());
+                            assert!(history.windows(2).all(|w| w[0].timestamp <= w[1].timestamp), "{} non chronologique", market);
+                        }
+                        for market in MARKETS {
+                            if let Some(len) = cycle.with_history(market, |history| history.len()) {
+                                assert!(len > 0 && len <= MAX_PRICE_POINTS);
+                                assert!(cycle.has_history(market));
+                            }
+                        }
+                        reads += 1;
+                    }
+                });
+            }
+        });
+
+        // Toutes les écritures sont visibles, bornées aux dernières entrées
+        for (index, market) in MARKETS.iter().enumerate() {
+            let last_tick = (0..UPDATES).rev().find(|tick| tick % MARKETS.len() == index).unwrap();
+            let latest = store.with_history(market, |history| (history.len(), history.last().map(|point| point.timestamp)));
+            assert_eq!(latest, Some((MAX_PRICE_POINTS, Some(last_tick as f64))));
+            assert!(store.has_speeds(market));
+            assert_eq!(store.record_speeds(market, &[]), MAX_SPEEDS);
+        }
+    }
+
+    #[test]
+    fn clones_share_the_same_history() {
+        let store = PriceStore::new();
+        let stream = store.clone();
+        assert!(!store.has_history("m"));
+        stream.record_price("m", PricePoint::new(2.0, 0.6));
+        assert_eq!(store.merge_history("m", vec![PricePoint::new(1.0, 0.5)]), 2);
+        let series = stream.with_history("m", crate::volume_weight::price_series);
+        assert_eq!(series, Some(vec![(1.0, 0.5), (2.0, 0.6)]));
+        assert!(store.with_history("absent", |history| history.len()).is_none());
+    }
+}
@@ -0,0 +1,52 @@
+// Pré-score des marchés avant l'analyse approfondie
+// Score bon marché (aucune requête réseau) pour ne garder que les N marchés les
+// plus prometteurs quand l'univers de marchés dépasse le budget de latence du cycle
+
+// Poids des composantes (somme = 1)
+const LIQUIDITY_WEIGHT: f64 = 0.4;
+const RECENCY_WEIGHT: f64 = 0.2;
+const DOMAIN_SIGNAL_WEIGHT: f64 = 0.25;
+const RESOLUTION_WEIGHT: f64 = 0.15;
+
+// Liquidité (USDC) au-delà de laquelle la composante est saturée
+const LIQUIDITY_SATURATION: f64 = 1_000_000.0;
+// Demi-vie de la composante de fraîcheur d'un marché
+const RECENCY_HALF_LIFE_HOURS: f64 = 24.0;
+// Échelle de la composante de proximité de la résolution
+const RESOLUTION_SCALE_HOURS: f64 = 7.0 * 24.0;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PreScoreInput {
+    pub liquidity: f64,                   // USDC disponibles sur le marché
+    pub age_hours: Option<f64>,           // Depuis la création (None si inconnue)
+    pub domain_active: bool,              // Une source du domaine a détecté des mots-clés
+    pub hours_to_resolution: Option<f64>, // Jusqu'à la date de fin (None si inconnue)
+}
+
+// Score entre 0 et 1: liquide, récent, domaine actif, résolution proche
+pub fn pre_score(input: &PreScoreInput) -> f64 {
+    let liquidity = (input.liquidity.max(0.0).ln_1p() / LIQUIDITY_SATURATION.ln_1p()).min(1.0);
+    let recency = input.age_hours
+        .map(|age| 0.5_f64.powf(age.max(0.0) / RECENCY_HALF_LIFE_HOURS))
+        .unwrap_or(0.0);
+    let domain_signal = if input.domain_active { 1.0 } else { 0.0 };
+    // Un marché déjà échu n'a plus d'intérêt
+    let resolution = input.hours_to_resolution
+        .filter(|hours| *hours > 0.0)
+        .map(|hours| 1.0 / (1.0 + hours / RESOLUTION_SCALE_HOURS))
+        .unwrap_or(0.0);
+
+    LIQUIDITY_WEIGHT * liquidity
+        + RECENCY_WEIGHT * recency
+        + DOMAIN_SIGNAL_WEIGHT * domain_signal
+        + RESOLUTION_WEIGHT * resolution
+}
+
+// Indices des `max` meilleurs scores, du meilleur au moins bon
+// (à score égal, l'ordre d'origine est conservé)
+pub fn top_indices(scores: &[f64], max: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..scores.len()).collect();
+    indices.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+    indices.truncate(max);
+    indices
+}
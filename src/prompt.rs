@@ -0,0 +1,88 @@
+// Invite du mode d'exécution, sans blocage hors terminal
+// Sous nohup ou docker, stdin n'est pas un terminal: tube sans écrivain ou fermé, un read_line
+// peut y bloquer indéfiniment avant le premier cycle. L'invite n'est lue que sur un terminal,
+// et au plus pendant le délai; sinon le mode par défaut (simulation) est retenu.
+use std::io;
+use std::sync::mpsc;
+use std::time::Duration;
+
+// Délai laissé à l'opérateur pour répondre
+pub const PROMPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptAnswer {
+    Line(String),   // Réponse tapée
+    NotInteractive, // stdin n'est pas un terminal: rien n'est lu
+    Closed,         // Fin de flux (ou erreur de lecture) avant toute réponse
+    TimedOut,       // Aucune réponse dans le délai
+}
+
+impl PromptAnswer {
+    // Pourquoi le mode par défaut est retenu, None si une réponse a été tapée
+    pub fn fallback_reason(&self) -> Option<&'static str> {
+        match self {
+            PromptAnswer::Line(_) => None,
+            PromptAnswer::NotInteractive => Some("stdin is not a terminal"),
+            PromptAnswer::Closed => Some("stdin closed"),
+            PromptAnswer::TimedOut => Some("no answer before the timeout"),
+        }
+    }
+}
+
+// Lecture d'une ligne sur un fil dédié, abandonnée après `timeout`
+// Le fil resté bloqué sur une lecture abandonnée se termine avec le processus
+pub fn read_answer<F>(read_line: F, interactive: bool, timeout: Duration) -> PromptAnswer
+where
+    F: FnOnce(&mut String) -> io::Result<usize> + Send + 'static,
+{
+    if !interactive {
+        return PromptAnswer::NotInteractive;
+    }
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut line = String::new();
+        let answer = match read_line(&mut line) {
+            Ok(0) | Err(_) => PromptAnswer::Closed,
+            Ok(_) => PromptAnswer::Line(line),
+        };
+        let _ = sender.send(answer);
+    });
+    receiver.recv_timeout(timeout).unwrap_or(PromptAnswer::TimedOut)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufRead;
+    use std::time::Instant;
+
+    const TIMEOUT: Duration = Duration::from_millis(200);
+
+    #[test]
+    fn closed_stdin_defaults_to_simulation_without_hanging() {
+        // Tube fermé: la lecture renvoie immédiatement une fin de flux
+        let started = Instant::now();
+        let answer = read_answer(|line| std::io::Cursor::new(Vec::new()).read_line(line), true, Duration::from_secs(30));
+        assert_eq!(answer, PromptAnswer::Closed);
+        assert!(started.elapsed() < Duration::from_secs(5), "{:?}", started.elapsed());
+        assert_eq!(answer.fallback_reason(), Some("stdin closed"));
+
+        // Tube ouvert sans écrivain: la lecture ne revient pas, abandonnée après le délai
+        let started = Instant::now();
+        let answer = read_answer(|_| {
+            std::thread::sleep(Duration::from_secs(3600));
+            Ok(0)
+        }, true, TIMEOUT);
+        assert_eq!(answer, PromptAnswer::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(5), "{:?}", started.elapsed());
+
+        // Pas un terminal: rien n'est lu
+        let answer = read_answer(|_| panic!("stdin ne doit pas être lu"), false, TIMEOUT);
+        assert_eq!(answer, PromptAnswer::NotInteractive);
+
+        // Réponse tapée: retenue telle quelle
+        let answer = read_answer(|line| std::io::Cursor::new(b"2\n".to_vec()).read_line(line), true, TIMEOUT);
+        assert_eq!(answer, PromptAnswer::Line("2\n".to_string()));
+        assert_eq!(answer.fallback_reason(), None);
+    }
+}
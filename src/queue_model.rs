@@ -0,0 +1,95 @@
+// Modèle de file d'attente des ordres passifs du registre papier (--queue-model)
+// Un ordre posé au meilleur bid rejoint la file derrière la taille déjà affichée: il n'est
+// rempli que si le flux d'échanges pendant son temps de repos consomme d'abord cette file.
+// Le volume échangé suit une loi exponentielle de moyenne flux × durée, d'où une probabilité
+// de remplissage exp(-file / volume attendu): un marché actif remplit plus qu'un marché figé.
+
+// Taille affichée devant l'ordre: niveaux au même prix ou meilleurs (bids ≥ prix)
+pub fn queue_ahead(bids: &[(f64, f64)], price: f64) -> f64 {
+    bids.iter()
+        .filter(|(level, _)| *level >= price - 1e-9)
+        .map(|(_, size)| size.max(0.0))
+        .sum()
+}
+
+// Flux d'échanges observé (taille par seconde): volume cumulé sur la durée couverte,
+// None sans volume connu ou sur un historique sans durée
+pub fn trade_rate(points: &[(f64, Option<f64>)]) -> Option<f64> {
+    let volumes: Vec<f64> = points.iter().filter_map(|(_, volume)| *volume).filter(|v| *v >= 0.0).collect();
+    let first = points.iter().map(|(timestamp, _)| *timestamp).fold(f64::INFINITY, f64::min);
+    let last = points.iter().map(|(timestamp, _)| *timestamp).fold(f64::NEG_INFINITY, f64::max);
+    let span = last - first;
+    if volumes.is_empty() || !span.is_finite() || span <= 0.0 {
+        return None;
+    }
+    Some(volumes.iter().sum::<f64>() / span)
+}
+
+// Probabilité qu'une partie au moins de l'ordre soit remplie pendant rest_secs
+pub fn fill_probability(queue_ahead: f64, trade_rate: f64, rest_secs: f64) -> f64 {
+    let expected = trade_rate.max(0.0) * rest_secs.max(0.0);
+    if expected <= 0.0 {
+        return 0.0;
+    }
+    (-queue_ahead.max(0.0) / expected).exp()
+}
+
+// Taille remplie pour un tirage uniforme draw ∈ [0, 1): volume échangé tiré par inversion
+// de la loi exponentielle, diminué de la file devant l'ordre, borné à la taille de l'ordre
+pub fn resting_fill(queue_ahead: f64, size: f64, trade_rate: f64, rest_secs: f64, draw: f64) -> f64 {
+    let expected = trade_rate.max(0.0) * rest_secs.max(0.0);
+    if expected <= 0.0 || size <= 0.0 {
+        return 0.0;
+    }
+    let traded = -expected * (1.0 - draw.clamp(0.0, 1.0 - f64::EPSILON)).ln();
+    (traded - queue_ahead.max(0.0)).clamp(0.0, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    // Part des ordres (au moins partiellement) remplis sur un grand nombre de tirages
+    fn fill_rate(queue_ahead: f64, trade_rate: f64, rest_secs: f64) -> f64 {
+        let mut rng = StdRng::seed_from_u64(7);
+        let trials = 10_000;
+        let filled = (0..trials)
+            .filter(|_| resting_fill(queue_ahead, 20.0, trade_rate, rest_secs, rng.gen::<f64>()) > 0.0)
+            .count();
+        filled as f64 / trials as f64
+    }
+
+    #[test]
+    fn passive_order_fills_more_often_in_a_fast_market() {
+        let queue = queue_ahead(&[(0.34, 200.0), (0.33, 400.0)], 0.34);
+        assert_eq!(queue, 200.0, "seule la taille au prix de l'ordre ou mieux est devant lui");
+
+        let fast = fill_rate(queue, 10.0, 60.0);
+        let stagnant = fill_rate(queue, 0.5, 60.0);
+        assert!(fast > stagnant + 0.3, "marché actif {:.3} vs figé {:.3}", fast, stagnant);
+
+        // Fréquence observée conforme à la probabilité du modèle
+        assert!((fast - fill_probability(queue, 10.0, 60.0)).abs() < 0.02);
+        assert!((stagnant - fill_probability(queue, 0.5, 60.0)).abs() < 0.02);
+    }
+
+    #[test]
+    fn longer_rest_and_shorter_queue_fill_more() {
+        assert!(fill_probability(200.0, 1.0, 600.0) > fill_probability(200.0, 1.0, 60.0));
+        assert!(fill_probability(50.0, 1.0, 60.0) > fill_probability(200.0, 1.0, 60.0));
+        // Sans flux ni temps de repos, aucun remplissage
+        assert_eq!(fill_probability(0.0, 0.0, 60.0), 0.0);
+        assert_eq!(resting_fill(0.0, 20.0, 5.0, 0.0, 0.99), 0.0);
+        // Jamais plus que la taille de l'ordre
+        assert_eq!(resting_fill(0.0, 20.0, 1e6, 60.0, 0.5), 20.0);
+    }
+
+    #[test]
+    fn trade_rate_is_volume_over_observed_span() {
+        let rate = trade_rate(&[(0.0, Some(30.0)), (10.0, None), (20.0, Some(10.0))]).expect("flux");
+        assert!((rate - 2.0).abs() < 1e-12);
+        assert_eq!(trade_rate(&[(0.0, None), (10.0, None)]), None);
+        assert_eq!(trade_rate(&[(5.0, Some(1.0))]), None);
+    }
+}
@@ -0,0 +1,112 @@
+// Seuils de pertinence par domaine
+// Une opportunité est retenue si sa pertinence dépasse le seuil minimal de son domaine; les bandes
+// medium / high fixent sa confiance. Les actualités crypto sont plus bruitées qu'un dépôt SEC:
+// chaque domaine peut relever ses seuils (DOMAIN_RELEVANCE_THRESHOLDS), les autres gardent les
+// valeurs globales (MIN_RELEVANCE_SCORE, 0.4, 0.7).
+
+pub const DEFAULT_MIN_RELEVANCE: f64 = 0.05;
+pub const DEFAULT_MEDIUM_CONFIDENCE: f64 = 0.4;
+pub const DEFAULT_HIGH_CONFIDENCE: f64 = 0.7;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelevanceThresholds {
+    pub min: f64,    // Pertinence strictement supérieure requise
+    pub medium: f64, // Au-delà: confiance "medium"
+    pub high: f64,   // Au-delà: confiance "high"
+}
+
+impl Default for RelevanceThresholds {
+    fn default() -> Self {
+        Self::with_min(DEFAULT_MIN_RELEVANCE)
+    }
+}
+
+impl RelevanceThresholds {
+    pub fn with_min(min: f64) -> Self {
+        RelevanceThresholds { min, medium: DEFAULT_MEDIUM_CONFIDENCE, high: DEFAULT_HIGH_CONFIDENCE }
+    }
+
+    pub fn qualifies(&self, relevance: f64) -> bool {
+        relevance > self.min
+    }
+
+    pub fn confidence(&self, relevance: f64) -> &'static str {
+        if relevance > self.high { "high" } else if relevance > self.medium { "medium" } else { "low" }
+    }
+
+    pub fn describe(&self) -> String {
+        format!("min {:.2}, medium > {:.2}, high > {:.2}", self.min, self.medium, self.high)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DomainThresholds {
+    default: RelevanceThresholds,
+    overrides: Vec<(String, RelevanceThresholds)>,
+}
+
+impl DomainThresholds {
+    pub fn new(default: RelevanceThresholds) -> Self {
+        DomainThresholds { default, overrides: Vec::new() }
+    }
+
+    // "crypto=0.2,economy=0.05:0.3:0.6": domaine=min[:medium:high], bandes absentes = valeurs globales
+    pub fn parse(config: &str, default: RelevanceThresholds) -> Result<Self, String> {
+        let mut thresholds = Self::new(default);
+        for entry in config.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let invalid = || format!("DOMAIN_RELEVANCE_THRESHOLDS: '{}' invalide (attendu: domaine=min[:medium:high], valeurs entre 0 et 1)", entry);
+            let (domain, values) = entry.split_once('=').ok_or_else(invalid)?;
+            let values: Vec<f64> = values.split(':')
+                .map(|v| v.trim().parse::<f64>().ok().filter(|v| (0.0..=1.0).contains(v)))
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?;
+            let domain_thresholds = match values[..] {
+                [min] => RelevanceThresholds { min, ..default },
+                [min, medium, high] if medium <= high => RelevanceThresholds { min, medium, high },
+                _ => return Err(invalid()),
+            };
+            thresholds.set(&domain.trim().to_lowercase(), domain_thresholds);
+        }
+        Ok(thresholds)
+    }
+
+    pub fn set(&mut self, domain: &str, thresholds: RelevanceThresholds) {
+        self.overrides.retain(|(d, _)| d != domain);
+        self.overrides.push((domain.to_string(), thresholds));
+    }
+
+    pub fn for_domain(&self, domain: &str) -> RelevanceThresholds {
+        self.overrides.iter()
+            .find(|(d, _)| d == domain)
+            .map(|(_, thresholds)| *thresholds)
+            .unwrap_or(self.default)
+    }
+
+    // Domaines surchargés, dans l'ordre de la configuration
+    pub fn domains(&self) -> impl Iterator<Item = &str> {
+        self.overrides.iter().map(|(domain, _)| domain.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn domain_overrides_fall_back_to_the_global_thresholds() {
+        let global = RelevanceThresholds::with_min(0.05);
+        let thresholds = DomainThresholds::parse("crypto=0.2:0.5:0.8, Politics=0.1", global).unwrap();
+
+        let crypto = thresholds.for_domain("crypto");
+        assert!(!crypto.qualifies(0.15) && crypto.qualifies(0.25));
+        assert_eq!((crypto.confidence(0.45), crypto.confidence(0.6), crypto.confidence(0.85)), ("low", "medium", "high"));
+        assert_eq!(thresholds.for_domain("politics"), RelevanceThresholds { min: 0.1, medium: 0.4, high: 0.7 });
+        assert_eq!(thresholds.for_domain("economy"), global, "domaine non configuré: seuils globaux");
+        assert_eq!(thresholds.domains().collect::<Vec<_>>(), vec!["crypto", "politics"]);
+
+        assert_eq!(DomainThresholds::parse("", global).unwrap(), DomainThresholds::new(global));
+        for invalid in ["crypto", "crypto=abc", "crypto=1.5", "crypto=0.1:0.5", "crypto=0.1:0.8:0.5"] {
+            assert!(DomainThresholds::parse(invalid, global).is_err(), "'{}' refusé", invalid);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// Rapport JSON d'un cycle (--once --report-json) pour la CI et la supervision
+// Reprend le contenu du rapport de validation affiché, plus les durées de phase et les erreurs.
+// `success` est faux dès qu'une erreur a été relevée: le code de sortie du bot le reflète.
+// Relu tel quel par les outils (et les tests) qui consomment le rapport.
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PhaseTimings {
+    pub fetch_ms: f64,      // Phases 1+2: marchés et sources (en parallèle)
+    pub detection_ms: f64,  // Phase 3: opportunités
+    pub signals_ms: f64,    // Phase 4: signaux
+    pub execution_ms: f64,  // Phase 5: trades ou émission vers le sink
+    pub total_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportSignal {
+    pub market_id: String,
+    pub source: String,
+    pub action: String,
+    pub token_side: String,
+    pub confidence: String,
+    pub expected_roi: f64,
+    pub fee_drag: f64,          // Coûts d'exécution estimés, en fraction de la mise
+    pub gas_drag: f64,
+    pub slippage_estimate: f64,
+    pub net_edge: f64,          // expected_roi moins les coûts
+    pub stake_amount: f64,
+    pub pnl_expected: f64,
+    pub filled_price: Option<f64>, // None si le signal n'a pas été tradé
+    pub filled_size: f64,
+}
+
+// Marchés analysés sans opportunité (phase 3) et pourquoi
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkippedMarkets {
+    pub counts: BTreeMap<String, usize>,  // Raison -> nombre de marchés
+    pub markets: BTreeMap<String, String>, // Id du marché -> raison
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleReport {
+    pub success: bool,
+    pub mode: String,               // simulation, real ou signal-sink
+    pub markets_analyzed: usize,
+    pub sources_reachable: usize,
+    pub opportunities: usize,
+    pub skipped: SkippedMarkets,
+    pub stale_sources: Vec<String>, // Sources sans succès depuis SOURCE_STALE_SECS, triées par URL
+    pub signals: Vec<ReportSignal>, // Signaux produits pendant ce cycle
+    pub trades: usize,              // Trades exécutés (ou intentions émises vers le sink)
+    pub balance: f64,
+    pub expected_pnl: f64,
+    pub timings_ms: PhaseTimings,
+    pub errors: Vec<String>,
+}
+
+impl CycleReport {
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| format!("Rapport JSON: {}", e))?;
+        std::fs::write(path, json + "\n").map_err(|e| format!("Écriture du rapport {}: {}", path, e))
+    }
+}
@@ -0,0 +1,259 @@
+// Ordres passifs au repos et échéance d'amélioration de prix (--passive-deadline)
+// Un ordre posé au bid pour capter le spread attend son remplissage, éventuellement partiel sur
+// plusieurs cycles (GTC): le cumul rempli et son prix moyen suivent l'ordre jusqu'à ce qu'il soit
+// entièrement rempli. Passé l'échéance, le reste est annulé puis remplacé à un prix marketable
+// si l'edge tient toujours, sinon annulé pour de bon.
+// Les instants sont passés en paramètre (secondes Unix), ce qui rend l'horloge simulable
+
+// Reste en dessous duquel un ordre est considéré entièrement rempli (USDC)
+pub const MIN_REMAINING: f64 = 0.01;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestingOrder {
+    pub order_id: String,
+    pub market_id: String,
+    pub side: String,     // Token de la vue (YES ou NO)
+    pub price: f64,       // Prix limite passif
+    pub size: f64,        // Montant de l'ordre (USDC)
+    pub filled_size: f64, // Cumul rempli (USDC)
+    pub filled_shares: f64, // Cumul rempli (tokens), pour le prix moyen
+    pub posted_at: f64,
+    pub signal_index: usize, // Signal à l'origine de l'ordre
+}
+
+impl RestingOrder {
+    pub fn age(&self, now: f64) -> f64 {
+        (now - self.posted_at).max(0.0)
+    }
+
+    // Montant encore au repos (USDC)
+    pub fn remaining(&self) -> f64 {
+        (self.size - self.filled_size).max(0.0)
+    }
+
+    pub fn is_filled(&self) -> bool {
+        self.remaining() < MIN_REMAINING
+    }
+
+    // Prix moyen des remplissages cumulés: USDC payés par token reçu
+    pub fn average_price(&self) -> Option<f64> {
+        (self.filled_shares > 0.0).then(|| self.filled_size / self.filled_shares)
+    }
+}
+
+// Cotation courante d'un ordre échu: edge net et prix marketable de sa vue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quote {
+    pub edge: f64,
+    pub marketable_price: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeadlineAction {
+    Reprice { order: RestingOrder, quote: Quote }, // Annulé et remplacé au prix marketable
+    Cancel { order: RestingOrder, edge: Option<f64> }, // Edge passé sous le seuil, ou vue disparue
+}
+
+#[derive(Debug, Clone)]
+pub struct RestingOrders {
+    deadline_secs: f64, // Repos maximal avant conversion en ordre agressif, infini pour un GTC sans échéance
+    min_edge: f64,      // Edge net minimal pour traverser le spread à l'échéance
+    orders: Vec<RestingOrder>,
+    next_id: u64,
+}
+
+impl RestingOrders {
+    pub fn new(deadline_secs: f64, min_edge: f64) -> Self {
+        Self { deadline_secs: deadline_secs.max(0.0), min_edge, orders: Vec::new(), next_id: 0 }
+    }
+
+    pub fn deadline_secs(&self) -> f64 {
+        self.deadline_secs
+    }
+
+    // Enregistrer un ordre posé; renvoie son identifiant
+    pub fn post(&mut self, market_id: &str, side: &str, price: f64, size: f64, now: f64, signal_index: usize) -> String {
+        self.next_id += 1;
+        let order_id = format!("passive-{}", self.next_id);
+        self.orders.push(RestingOrder {
+            order_id: order_id.clone(),
+            market_id: market_id.to_string(),
+            side: side.to_string(),
+            price,
+            size,
+            filled_size: 0.0,
+            filled_shares: 0.0,
+            posted_at: now,
+            signal_index,
+        });
+        order_id
+    }
+
+    // Un ordre de cette vue travaille déjà: un nouveau signal ne la double pas
+    pub fn is_resting(&self, market_id: &str, side: &str) -> bool {
+        self.orders.iter().any(|order| order.market_id == market_id && order.side == side)
+    }
+
+    pub fn open_orders(&self) -> &[RestingOrder] {
+        &self.orders
+    }
+
+    // Remplissage partiel d'un ordre au repos (size USDC au prix price), cumulé à ses remplissages
+    // précédents; l'ordre entièrement rempli est clos et retiré du registre. Renvoie l'état à jour
+    pub fn fill(&mut self, order_id: &str, size: f64, price: f64) -> Option<RestingOrder> {
+        let position = self.orders.iter().position(|order| order.order_id == order_id)?;
+        let order = &mut self.orders[position];
+        let size = size.clamp(0.0, order.remaining());
+        if size > 0.0 && price > 0.0 {
+            order.filled_size += size;
+            order.filled_shares += size / price;
+        }
+        let updated = order.clone();
+        if updated.is_filled() {
+            self.orders.remove(position);
+        }
+        Some(updated)
+    }
+
+    // Ordres d'un marché résolu: expirés, retirés du registre avec leur cumul rempli
+    pub fn expire_market(&mut self, market_id: &str) -> Vec<RestingOrder> {
+        let (expired, open): (Vec<RestingOrder>, Vec<RestingOrder>) = std::mem::take(&mut self.orders)
+            .into_iter()
+            .partition(|order| order.market_id == market_id);
+        self.orders = open;
+        expired
+    }
+
+    // Tous les ordres au repos retirés du registre (arrêt d'urgence)
+    pub fn cancel_all(&mut self) -> Vec<RestingOrder> {
+        std::mem::take(&mut self.orders)
+    }
+
+    // Ordres au repos depuis au moins l'échéance, retirés du registre dans l'ordre de pose:
+    // leur reste est remplacé au prix marketable si l'edge courant atteint le seuil, annulé sinon
+    // quote: cotation courante de la vue d'un ordre, None si elle n'est plus soutenue
+    pub fn review(&mut self, now: f64, quote: impl Fn(&RestingOrder) -> Option<Quote>) -> Vec<DeadlineAction> {
+        let (expired, resting): (Vec<RestingOrder>, Vec<RestingOrder>) = std::mem::take(&mut self.orders)
+            .into_iter()
+            .partition(|order| order.age(now) >= self.deadline_secs);
+        self.orders = resting;
+        expired.into_iter()
+            .map(|order| match quote(&order) {
+                Some(quote) if quote.edge >= self.min_edge => DeadlineAction::Reprice { order, quote },
+                current => DeadlineAction::Cancel { order, edge: current.map(|quote| quote.edge) },
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settlement::OpenPosition;
+
+    fn close(actual: f64, expected: f64) -> bool {
+        (actual - expected).abs() < 1e-9
+    }
+
+    #[test]
+    fn an_order_filled_40_then_60_percent_ends_fully_filled_at_its_average_price() {
+        // Ordre GTC sans échéance: il reste au repos jusqu'à son remplissage complet
+        let mut book = RestingOrders::new(f64::INFINITY, 0.0);
+        let order_id = book.post("fx-crypto-1", "YES", 0.40, 100.0, 1_000.0, 0);
+
+        // Cycle 1: 40% rempli à 0.40, l'ordre reste ouvert pour le reste
+        let first = book.fill(&order_id, 40.0, 0.40).expect("ordre ouvert");
+        assert!(close(first.filled_size, 40.0) && close(first.remaining(), 60.0));
+        assert!(!first.is_filled());
+        assert!(close(first.average_price().unwrap(), 0.40));
+        assert_eq!(book.open_orders().len(), 1);
+        let mut position = OpenPosition {
+            market_id: first.market_id.clone(), holds_yes: true, shares: 40.0 / 0.40, entry_price: 0.40,
+            opened_at: 1_000.0, order_id: Some(order_id.clone()),
+        };
+
+        // Cycle 2: les 60% restants remplis à 0.38, l'ordre est clos
+        let second = book.fill(&order_id, 60.0, 0.38).expect("ordre encore ouvert au cycle 2");
+        position.add_fill(60.0 / 0.38, 0.38);
+        assert!(second.is_filled());
+        assert!(close(second.filled_size, 100.0) && close(second.remaining(), 0.0));
+        assert!(book.open_orders().is_empty(), "ordre entièrement rempli retiré du registre");
+        assert!(book.fill(&order_id, 1.0, 0.38).is_none());
+
+        // Prix moyen: USDC payés / tokens reçus sur les deux remplissages
+        let shares = 40.0 / 0.40 + 60.0 / 0.38;
+        assert!(close(second.average_price().unwrap(), 100.0 / shares));
+        assert!(close(position.shares, shares));
+        assert!(close(position.entry_price, second.average_price().unwrap()));
+        assert!(close(position.cost(), 100.0));
+    }
+
+    #[test]
+    fn an_order_is_capped_at_its_size_and_expires_with_its_market() {
+        let mut book = RestingOrders::new(f64::INFINITY, 0.0);
+        let capped = book.post("fx-economy-1", "NO", 0.43, 10.0, 0.0, 0);
+        let expiring = book.post("fx-politics-1", "YES", 0.41, 20.0, 0.0, 1);
+
+        // Un remplissage au-delà du reste ne dépasse pas la taille de l'ordre
+        let filled = book.fill(&capped, 25.0, 0.43).unwrap();
+        assert!(close(filled.filled_size, 10.0) && filled.is_filled());
+
+        // Marché résolu: l'ordre expire avec son cumul partiel
+        book.fill(&expiring, 5.0, 0.41);
+        let expired = book.expire_market("fx-politics-1");
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].order_id, expiring);
+        assert!(close(expired[0].filled_size, 5.0) && close(expired[0].remaining(), 15.0));
+        assert!(book.open_orders().is_empty());
+    }
+
+    #[test]
+    fn a_resting_order_past_its_deadline_is_repriced() {
+        // Horloge simulée: les instants sont des secondes passées explicitement
+        let mut book = RestingOrders::new(30.0, 0.02);
+        let order_id = book.post("fx-crypto-1", "YES", 0.34, 50.0, 1_000.0, 3);
+        assert!(book.is_resting("fx-crypto-1", "YES"));
+        assert!(!book.is_resting("fx-crypto-1", "NO"));
+
+        let quote = |_: &RestingOrder| Some(Quote { edge: 0.05, marketable_price: 0.36 });
+        // Avant l'échéance: l'ordre reste au repos
+        assert!(book.review(1_029.0, quote).is_empty());
+        assert!(book.is_resting("fx-crypto-1", "YES"));
+
+        // À l'échéance: annulé et remplacé au prix marketable, retiré du registre
+        let actions = book.review(1_030.0, quote);
+        assert_eq!(actions.len(), 1);
+        match &actions[0] {
+            DeadlineAction::Reprice { order, quote } => {
+                assert_eq!(order.order_id, order_id);
+                assert_eq!((order.price, order.size, order.signal_index), (0.34, 50.0, 3));
+                assert_eq!(order.age(1_030.0), 30.0);
+                assert_eq!(quote.marketable_price, 0.36);
+            }
+            other => panic!("remplacement attendu: {:?}", other),
+        }
+        assert!(!book.is_resting("fx-crypto-1", "YES"));
+    }
+
+    #[test]
+    fn a_decayed_or_vanished_edge_cancels_the_order() {
+        let mut book = RestingOrders::new(10.0, 0.02);
+        book.post("decayed", "YES", 0.40, 10.0, 0.0, 0);
+        book.post("vanished", "NO", 0.60, 10.0, 0.0, 1);
+        book.post("young", "YES", 0.50, 10.0, 5.0, 2);
+
+        let actions = book.review(12.0, |order| match order.market_id.as_str() {
+            "decayed" => Some(Quote { edge: 0.015, marketable_price: 0.42 }),
+            _ => None,
+        });
+        let cancelled: Vec<(&str, Option<f64>)> = actions.iter()
+            .map(|action| match action {
+                DeadlineAction::Cancel { order, edge } => (order.market_id.as_str(), *edge),
+                other => panic!("annulation attendue: {:?}", other),
+            })
+            .collect();
+        assert_eq!(cancelled, vec![("decayed", Some(0.015)), ("vanished", None)]);
+        // L'ordre posé plus tard n'a pas atteint son échéance
+        assert!(book.is_resting("young", "YES"));
+    }
+}
@@ -0,0 +1,77 @@
+// Budget de nouvelles tentatives partagé par toutes les requêtes d'un cycle
+// Les réessais (sources en échec, ordres CLOB indisponibles) puisent dans un même budget remis
+// à zéro à chaque cycle: une avalanche d'échecs ne peut pas repousser la fin du cycle.
+// Budget épuisé: les échecs restants sont acceptés sans nouvelle tentative.
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[derive(Debug, Default)]
+pub struct RetryBudget {
+    limit: u32,
+    used: AtomicU32,
+}
+
+impl RetryBudget {
+    pub fn new(limit: u32) -> Self {
+        RetryBudget { limit, used: AtomicU32::new(0) }
+    }
+
+    // Réserver une nouvelle tentative, false si le budget du cycle est épuisé
+    pub fn try_spend(&self) -> bool {
+        self.used
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| (used < self.limit).then_some(used + 1))
+            .is_ok()
+    }
+
+    // Nouveau cycle: budget complet
+    pub fn reset(&self) {
+        self.used.store(0, Ordering::SeqCst);
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    pub fn used(&self) -> u32 {
+        self.used.load(Ordering::SeqCst)
+    }
+}
+
+// Ordre des réessais: requêtes les plus prometteuses d'abord (score décroissant), puis par clé
+pub fn retry_order(mut candidates: Vec<(String, f64)>) -> Vec<String> {
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    candidates.into_iter().map(|(key, _)| key).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_spending_never_exceeds_the_budget() {
+        let budget = RetryBudget::new(5);
+        let granted: usize = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| scope.spawn(|| (0..10).filter(|_| budget.try_spend()).count()))
+                .collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+        });
+        assert_eq!(granted, 5);
+        assert_eq!(budget.used(), 5);
+        assert!(!budget.try_spend());
+
+        // Nouveau cycle: budget complet
+        budget.reset();
+        assert!(budget.try_spend());
+        assert!(!RetryBudget::new(0).try_spend(), "budget nul: aucun réessai");
+    }
+
+    #[test]
+    fn most_promising_requests_are_retried_first() {
+        let order = retry_order(vec![
+            ("https://b.example".to_string(), 0.5),
+            ("https://oracle.example".to_string(), 1.9),
+            ("https://a.example".to_string(), 0.5),
+        ]);
+        assert_eq!(order, ["https://oracle.example", "https://a.example", "https://b.example"]);
+    }
+}
@@ -0,0 +1,42 @@
+// Contrôle de risque pré-trade fourni par l'utilisateur de la bibliothèque (VaR externe,
+// corrélations...): chaque trade est soumis au hook avant exécution, un veto l'écarte du cycle.
+// Sans hook, tous les trades sont approuvés.
+use futures::future::BoxFuture;
+use std::fmt;
+
+// Trade sur le point d'être exécuté
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProposedTrade {
+    pub market_id: String,
+    pub action: String,     // BUY / SELL
+    pub token_side: String, // YES / NO
+    pub size: f64,          // Mise en USDC
+    pub price: f64,         // Prix demandé du token
+    pub exposure: f64,      // USDC déjà engagés: positions ouvertes et trades du cycle
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskDecision {
+    Approve,
+    Veto(String), // Raison affichée et journalisée
+}
+
+pub trait RiskHook: Send + Sync {
+    fn approve<'a>(&'a self, trade: &'a ProposedTrade) -> BoxFuture<'a, RiskDecision>;
+}
+
+// Hook par défaut: aucun contrôle supplémentaire
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApproveAll;
+
+impl RiskHook for ApproveAll {
+    fn approve<'a>(&'a self, _trade: &'a ProposedTrade) -> BoxFuture<'a, RiskDecision> {
+        Box::pin(async { RiskDecision::Approve })
+    }
+}
+
+impl fmt::Debug for dyn RiskHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RiskHook")
+    }
+}
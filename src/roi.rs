@@ -0,0 +1,142 @@
+// ROI d'un pari directionnel sur un marché binaire
+// Un pari NO est l'achat du token NO (prix 1 - p): le calcul est fait sur le token acheté,
+// ce qui donne le même résultat qu'un pari YES sur le marché complémentaire.
+
+// Rattrapage du marché pendant notre temps d'action (probabilité par seconde)
+pub const DEFAULT_CATCHUP_SPEED: f64 = 0.025;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoiEstimate {
+    pub roi: f64,         // Rendement net de frais sur le prix payé
+    pub spent_price: f64, // Prix payé pour le token acheté (YES ou NO)
+    pub target_price: f64, // Prix attendu du token acheté après le mouvement
+}
+
+// yes_price: probabilité YES actuelle
+// bet_on_yes: sens du pari (information positive -> YES)
+// expected_move: variation relative attendue de la probabilité YES, dans le sens du pari
+// catchup_speed × action_time: déplacement du marché contre nous avant notre exécution
+// fee: frais taker, prélevés sur le profit net uniquement
+pub fn directional_roi(yes_price: f64, bet_on_yes: bool, expected_move: f64,
+                       catchup_speed: f64, action_time_secs: f64, fee: f64) -> RoiEstimate {
+    let shift = catchup_speed * action_time_secs;
+    let (entry_yes, target_yes) = if bet_on_yes {
+        (yes_price + shift, (yes_price * (1.0 + expected_move)).min(1.0))
+    } else {
+        (yes_price - shift, (yes_price * (1.0 - expected_move)).max(0.0))
+    };
+    let entry_yes = entry_yes.clamp(0.01, 0.99);
+
+    // Prix du token effectivement acheté puis revendu
+    let (spent_price, target_price) = if bet_on_yes {
+        (entry_yes, target_yes)
+    } else {
+        (1.0 - entry_yes, 1.0 - target_yes)
+    };
+
+    let gross_roi = (target_price - spent_price) / spent_price;
+    let roi = if gross_roi > 0.0 { gross_roi * (1.0 - fee) } else { gross_roi };
+    RoiEstimate { roi, spent_price, target_price }
+}
+
+// ROI net du spread (--spread-roi): le token est payé au ask et revendu au bid après le
+// mouvement, le spread étant supposé inchangé. Par rapport au mid, l'entrée et la sortie
+// perdent chacune un demi-spread: l'edge est net du spread complet.
+// ask / bid: meilleurs prix du token acheté (YES si bet_on_yes, NO sinon)
+pub fn spread_crossing_roi(ask: f64, bid: f64, bet_on_yes: bool, expected_move: f64, fee: f64) -> RoiEstimate {
+    let half_spread = (ask - bid.min(ask)) / 2.0;
+    let mid = ask - half_spread;
+    let yes_mid = if bet_on_yes { mid } else { 1.0 - mid };
+    let at_mid = directional_roi(yes_mid, bet_on_yes, expected_move, 0.0, 0.0, 0.0);
+
+    let spent_price = ask;
+    let target_price = (at_mid.target_price - half_spread).max(0.0);
+    let gross_roi = (target_price - spent_price) / spent_price;
+    let roi = if gross_roi > 0.0 { gross_roi * (1.0 - fee) } else { gross_roi };
+    RoiEstimate { roi, spent_price, target_price }
+}
+
+// ROI directionnel: un pari YES comme un pari NO sur un mouvement favorable est gagnant, le prix
+// payé se dégrade dans le sens du pari, et un pari NO équivaut à un pari YES sur le marché
+// complémentaire.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEE: f64 = 0.02;
+
+    const ACTION_TIME_SECS: f64 = 0.4;
+
+     // Rattrapage de 0.01 avant exécution
+
+    #[test]
+    fn favorable_moves_yield_positive_roi_on_both_sides() {
+        // YES à 0.40, l'information pousse à la hausse de 20%
+        let yes = directional_roi(0.40, true, 0.20, DEFAULT_CATCHUP_SPEED, ACTION_TIME_SECS, FEE);
+        assert!(yes.roi > 0.0, "pari YES sur hausse: {:?}", yes);
+
+        // YES à 0.60, l'information pousse à la baisse de 20%: le token NO s'apprécie
+        let no = directional_roi(0.60, false, 0.20, DEFAULT_CATCHUP_SPEED, ACTION_TIME_SECS, FEE);
+        assert!(no.roi > 0.0, "pari NO sur baisse: {:?}", no);
+    }
+
+    #[test]
+    fn spent_price_moves_against_the_bet() {
+        let shift = DEFAULT_CATCHUP_SPEED * ACTION_TIME_SECS;
+
+        let yes = directional_roi(0.40, true, 0.20, DEFAULT_CATCHUP_SPEED, ACTION_TIME_SECS, FEE);
+        assert!((yes.spent_price - (0.40 + shift)).abs() < 1e-12, "YES payé {}", yes.spent_price);
+
+        // NO: la probabilité YES baisse avant notre ordre, le token NO coûte plus que 1 - p
+        let no = directional_roi(0.60, false, 0.20, DEFAULT_CATCHUP_SPEED, ACTION_TIME_SECS, FEE);
+        assert!((no.spent_price - (0.40 + shift)).abs() < 1e-12, "NO payé {}", no.spent_price);
+    }
+
+    #[test]
+    fn no_bet_matches_yes_bet_on_the_complementary_market() {
+        // YES 0.40 -> 0.50 (+25%) équivaut à NO sur YES 0.60 -> 0.50 (-1/6)
+        let yes = directional_roi(0.40, true, 0.25, DEFAULT_CATCHUP_SPEED, ACTION_TIME_SECS, FEE);
+        let no = directional_roi(0.60, false, 0.40 * 0.25 / 0.60, DEFAULT_CATCHUP_SPEED, ACTION_TIME_SECS, FEE);
+        assert!((yes.roi - no.roi).abs() < 1e-9, "YES {:?} / NO {:?}", yes, no);
+        assert!((yes.spent_price - no.spent_price).abs() < 1e-12);
+        assert!((yes.target_price - no.target_price).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fees_apply_to_profit_only() {
+        // Mouvement nul: le rattrapage seul fait perdre, sans frais sur la perte
+        let loss = directional_roi(0.50, false, 0.0, DEFAULT_CATCHUP_SPEED, ACTION_TIME_SECS, FEE);
+        let shift = DEFAULT_CATCHUP_SPEED * ACTION_TIME_SECS;
+        assert!((loss.roi - (0.50 - (0.50 + shift)) / (0.50 + shift)).abs() < 1e-12, "{:?}", loss);
+
+        let gain = directional_roi(0.50, true, 0.20, 0.0, 0.0, FEE);
+        assert!((gain.roi - 0.20 * (1.0 - FEE)).abs() < 1e-12, "{:?}", gain);
+    }
+
+    #[test]
+    fn a_wide_spread_cuts_roi_against_the_mid() {
+        // Livre 0.40 / 0.60 (mid 0.50), hausse attendue de 30%: 0.50 -> 0.65 au mid
+        let mid = directional_roi(0.50, true, 0.30, 0.0, 0.0, FEE);
+        let spread = spread_crossing_roi(0.60, 0.40, true, 0.30, FEE);
+        assert!((mid.roi - 0.30 * (1.0 - FEE)).abs() < 1e-12, "{:?}", mid);
+        // Payé 0.60 au ask, revendu 0.55 au bid après le mouvement: le trade perd
+        assert!((spread.spent_price - 0.60).abs() < 1e-12);
+        assert!((spread.target_price - 0.55).abs() < 1e-12);
+        assert!(spread.roi < 0.0 && mid.roi - spread.roi > 0.35, "mid {:?} / spread {:?}", mid, spread);
+
+        // Sans spread, les deux calculs coïncident
+        let tight = spread_crossing_roi(0.50, 0.50, true, 0.30, FEE);
+        assert!((tight.roi - mid.roi).abs() < 1e-12);
+    }
+
+    #[test]
+    fn spread_roi_on_a_no_bet_matches_the_complementary_yes_bet() {
+        // Vue NO sur un livre NO 0.50 / 0.60 (YES au mid 0.45, -20% attendu): NO 0.55 -> 0.64 au mid,
+        // comme un pari YES sur un livre identique qui monterait de 0.09
+        let no = spread_crossing_roi(0.60, 0.50, false, 0.20, FEE);
+        let yes = spread_crossing_roi(0.60, 0.50, true, 0.09 / 0.55, FEE);
+        assert!((no.roi - yes.roi).abs() < 1e-9, "NO {:?} / YES {:?}", no, yes);
+        assert!((no.target_price - (0.64 - 0.05)).abs() < 1e-9, "{:?}", no);
+        assert!(no.roi < directional_roi(0.45, false, 0.20, 0.0, 0.0, FEE).roi);
+    }
+}
@@ -0,0 +1,153 @@
+// Cache LRU des ROI côté Rust, devant l'appel FFI au core C++
+// Même clé que le cache C++ (prix, frais, vitesse de rattrapage, temps d'action), arrondie:
+// six décimales comme std::to_string pour les prix et taux, la milliseconde pour le temps d'action.
+// Borné: au-delà de la capacité, l'entrée la moins récemment utilisée est évincée
+use std::collections::HashMap;
+
+// Même taille que MAX_CACHE_SIZE côté C++
+pub const DEFAULT_ROI_CACHE_CAPACITY: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoiKey {
+    price: i64,
+    fee: i64,
+    catchup_speed: i64,
+    action_time_ms: i64,
+}
+
+impl RoiKey {
+    pub fn new(price: f64, fee: f64, catchup_speed: f64, action_time_secs: f64) -> Self {
+        let micro = |value: f64| (value * 1e6).round() as i64;
+        Self {
+            price: micro(price),
+            fee: micro(fee),
+            catchup_speed: micro(catchup_speed),
+            action_time_ms: (action_time_secs * 1e3).round() as i64,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RoiCache {
+    capacity: usize,
+    entries: HashMap<RoiKey, (f64, u64)>, // clé -> (ROI, dernier usage)
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl RoiCache {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity: capacity.max(1), entries: HashMap::new(), clock: 0, hits: 0, misses: 0 }
+    }
+
+    // ROI en cache pour ces entrées arrondies, sinon calculé par `compute` et mémorisé
+    pub fn get_or_compute(&mut self, price: f64, fee: f64, catchup_speed: f64, action_time_secs: f64,
+                          compute: impl FnOnce() -> f64) -> f64 {
+        let key = RoiKey::new(price, fee, catchup_speed, action_time_secs);
+        self.clock += 1;
+        if let Some((roi, last_used)) = self.entries.get_mut(&key) {
+            *last_used = self.clock;
+            self.hits += 1;
+            return *roi;
+        }
+
+        self.misses += 1;
+        let roi = compute();
+        if self.entries.len() >= self.capacity {
+            self.evict_least_recently_used();
+        }
+        self.entries.insert(key, (roi, self.clock));
+        roi
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some(oldest) = self.entries.iter().min_by_key(|(_, (_, last_used))| *last_used).map(|(key, _)| *key) {
+            self.entries.remove(&oldest);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    // (servis depuis le cache, calculés)
+    pub fn stats(&self) -> (u64, u64) {
+        (self.hits, self.misses)
+    }
+}
+
+impl Default for RoiCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROI_CACHE_CAPACITY)
+    }
+}
+
+// Cache LRU des ROI: des entrées identiques (après arrondi) sont servies sans recalcul, et la
+// capacité est respectée en évinçant l'entrée la moins récemment utilisée.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const FEE: f64 = 0.02;
+
+    const SPEED: f64 = 0.025;
+
+    fn roi_for(price: f64) -> f64 {
+        (1.0 - price) / price
+    }
+
+    #[test]
+    fn repeated_inputs_hit_the_cache() {
+        let mut cache = RoiCache::new(10);
+        let computations = Cell::new(0);
+        let compute = |price: f64| {
+            computations.set(computations.get() + 1);
+            roi_for(price)
+        };
+
+        let first = cache.get_or_compute(0.42, FEE, SPEED, 0.010, || compute(0.42));
+        for _ in 0..4 {
+            assert_eq!(cache.get_or_compute(0.42, FEE, SPEED, 0.010, || compute(0.42)), first);
+        }
+        // Différences sous la précision de la clé: même entrée
+        assert_eq!(cache.get_or_compute(0.42 + 1e-9, FEE, SPEED, 0.0101, || compute(0.42)), first);
+
+        assert_eq!(computations.get(), 1);
+        assert_eq!(cache.stats(), (5, 1));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn distinct_inputs_are_cached_separately() {
+        let mut cache = RoiCache::new(10);
+        cache.get_or_compute(0.42, FEE, SPEED, 0.010, || roi_for(0.42));
+        cache.get_or_compute(0.42, 0.0, SPEED, 0.010, || roi_for(0.42));
+        cache.get_or_compute(0.42, FEE, SPEED, 0.020, || roi_for(0.42));
+        cache.get_or_compute(0.43, FEE, SPEED, 0.010, || roi_for(0.43));
+        assert_eq!(cache.stats(), (0, 4));
+        assert_eq!(cache.len(), 4);
+    }
+
+    #[test]
+    fn cache_evicts_the_least_recently_used_entry_beyond_capacity() {
+        let mut cache = RoiCache::new(2);
+        cache.get_or_compute(0.10, FEE, SPEED, 0.010, || roi_for(0.10));
+        cache.get_or_compute(0.20, FEE, SPEED, 0.010, || roi_for(0.20));
+        // 0.10 redevient la plus récente: 0.20 sera évincée
+        cache.get_or_compute(0.10, FEE, SPEED, 0.010, || roi_for(0.10));
+        cache.get_or_compute(0.30, FEE, SPEED, 0.010, || roi_for(0.30));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.stats(), (1, 3));
+
+        // 0.10 et 0.30 sont encore servies, 0.20 doit être recalculée
+        let recomputed = Cell::new(false);
+        cache.get_or_compute(0.10, FEE, SPEED, 0.010, || { recomputed.set(true); roi_for(0.10) });
+        cache.get_or_compute(0.30, FEE, SPEED, 0.010, || { recomputed.set(true); roi_for(0.30) });
+        assert!(!recomputed.get(), "entrées récentes évincées à tort");
+        cache.get_or_compute(0.20, FEE, SPEED, 0.010, || { recomputed.set(true); roi_for(0.20) });
+        assert!(recomputed.get(), "l'entrée la moins récemment utilisée aurait dû être évincée");
+        assert_eq!(cache.len(), 2);
+    }
+}
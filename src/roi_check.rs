@@ -0,0 +1,108 @@
+// Vérification croisée du ROI (--verify-roi): le ROI du core C++ est recalculé en Rust pour
+// chaque signal, et un écart au-delà de la tolérance est signalé. Test différentiel à
+// l'exécution: une dérive de formule de l'un ou l'autre côté apparaît dans les journaux.
+
+// Écart toléré entre les deux ROI: le cache C++ est indexé sur le prix à 6 décimales,
+// un ROI servi depuis le cache peut donc différer légèrement du ROI au prix exact
+pub const DEFAULT_ROI_TOLERANCE: f64 = 1e-3;
+
+// Paramètres de calculate_real_roi (polymarket_core.cpp). Le core ignore ceux passés à l'appel
+// et utilise ses globaux, fixés par configure_roi_params au démarrage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoreRoiParams {
+    pub fee: f64,           // GLOBAL_FEE: frais sur le profit
+    pub catchup_speed: f64, // GLOBAL_CATCHUP_SPEED
+    pub action_time: f64,   // GLOBAL_ACTION_TIME (secondes)
+    pub fixed_cost: f64,    // GLOBAL_FIXED_COST: coût fixe par token
+    pub confidence: f64,    // π: probabilité subjective de YES
+}
+
+// Valeurs initiales des globaux du core, avant configure_roi_params
+impl Default for CoreRoiParams {
+    fn default() -> Self {
+        Self { fee: 0.03, catchup_speed: 0.8, action_time: 0.025, fixed_cost: 0.0005, confidence: 0.55 }
+    }
+}
+
+impl CoreRoiParams {
+    // Paramètres transmis par configure_roi_params, le reste inchangé côté core
+    pub fn configured(fee: f64, catchup_speed: f64, action_time: f64) -> Self {
+        Self { fee, catchup_speed, action_time, ..Self::default() }
+    }
+}
+
+// Équivalent Rust de calculate_real_roi: même formule, mêmes opérations dans le même ordre
+pub fn core_roi(current_price: f64, params: &CoreRoiParams) -> f64 {
+    let CoreRoiParams { fee, catchup_speed, action_time, fixed_cost: g, confidence: pi_yes } = *params;
+    // Sous 50% on parie YES, sinon NO
+    let bet_on_yes = current_price < 0.5;
+    let p = if bet_on_yes {
+        current_price + (catchup_speed * action_time)
+    } else {
+        (1.0 - current_price) + (catchup_speed * action_time)
+    };
+    // Bornes du prix d'achat, comme le core (un NaN les traverse)
+    let p = p.clamp(0.05, 0.95);
+
+    let expected_profit = if bet_on_yes {
+        pi_yes * (1.0 - p) * (1.0 - fee) - (1.0 - pi_yes) * p - g
+    } else {
+        (1.0 - pi_yes) * (1.0 - p) * (1.0 - fee) - pi_yes * p - g
+    };
+    expected_profit / (p + g)
+}
+
+// Avertissement si les deux ROI divergent au-delà de la tolérance (ou si l'un n'est pas fini)
+pub fn divergence(current_price: f64, cpp_roi: f64, rust_roi: f64, tolerance: f64) -> Option<String> {
+    let gap = (cpp_roi - rust_roi).abs();
+    if gap <= tolerance {
+        return None;
+    }
+    Some(format!("ROI divergent au prix {:.6}: C++ {:.6} / Rust {:.6} (écart {:.6} > {})",
+                 current_price, cpp_roi, rust_roi, gap, tolerance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Core « dérivé »: la confiance π codée en dur a changé d'un côté seulement
+    fn drifted_core(current_price: f64, params: &CoreRoiParams) -> f64 {
+        core_roi(current_price, &CoreRoiParams { confidence: 0.56, ..*params })
+    }
+
+    #[test]
+    fn a_divergent_core_triggers_the_warning() {
+        let params = CoreRoiParams::configured(0.02, 0.20, 0.001);
+        for price in [0.27, 0.43, 0.62] {
+            let rust_roi = core_roi(price, &params);
+            // Même formule des deux côtés: aucun avertissement
+            assert_eq!(divergence(price, rust_roi, rust_roi, DEFAULT_ROI_TOLERANCE), None);
+
+            let warning = divergence(price, drifted_core(price, &params), rust_roi, DEFAULT_ROI_TOLERANCE)
+                .expect("avertissement attendu");
+            assert!(warning.contains("ROI divergent"), "{}", warning);
+            assert!(warning.contains(&format!("{:.6}", price)), "{}", warning);
+        }
+
+        // Sous la tolérance: écart toléré; valeur non finie (remplacée côté C++ ou non): signalée
+        assert_eq!(divergence(0.43, 0.2625, 0.2620, DEFAULT_ROI_TOLERANCE), None);
+        assert!(divergence(0.43, f64::NAN, 0.2620, DEFAULT_ROI_TOLERANCE).is_some());
+    }
+
+    #[test]
+    fn the_rust_port_follows_the_core_formula() {
+        // Valeurs initiales du core: p = 0.30 + 0.8 × 0.025 = 0.32, pari YES
+        let defaults = CoreRoiParams::default();
+        let p = 0.32;
+        let expected = (0.55 * (1.0 - p) * 0.97 - 0.45 * p - 0.0005) / (p + 0.0005);
+        assert!((core_roi(0.30, &defaults) - expected).abs() < 1e-12);
+
+        // Au-dessus de 50%, pari NO sur 1 - prix; prix d'achat borné à [0.05, 0.95]
+        let configured = CoreRoiParams::configured(0.02, 0.0, 0.0);
+        let p = 0.30;
+        let expected = (0.45 * (1.0 - p) * 0.98 - 0.55 * p - 0.0005) / (p + 0.0005);
+        assert!((core_roi(0.70, &configured) - expected).abs() < 1e-12);
+        assert_eq!(core_roi(0.0, &configured), core_roi(0.04, &configured));
+    }
+}
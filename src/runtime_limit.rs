@@ -0,0 +1,109 @@
+// Durée maximale d'exécution (--max-runtime): le bot s'arrête seul une fois la durée écoulée
+// Le cycle en cours se termine toujours; la pause qui suit est raccourcie au temps restant et
+// aucun nouveau cycle ne démarre après l'échéance. Le temps écoulé est passé en argument:
+// la boucle principale fournit l'horloge réelle, les tests une horloge simulée.
+use std::time::Duration;
+
+// "90s", "10m", "2h", "1h30m", "500ms" ou un nombre de secondes
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let invalid = || format!("--max-runtime: durée attendue (ex: 90s, 10m, 1h30m), reçu '{}'", text);
+    let text = text.trim();
+    if let Ok(secs) = text.parse::<f64>() {
+        return (secs.is_finite() && secs > 0.0).then(|| Duration::from_secs_f64(secs)).ok_or_else(invalid);
+    }
+
+    let mut total = 0.0;
+    let mut rest = text;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit() && c != '.').ok_or_else(invalid)?;
+        let value: f64 = rest[..digits].parse().map_err(|_| invalid())?;
+        let unit_len = rest[digits..].find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len() - digits);
+        let unit_secs = match &rest[digits..digits + unit_len] {
+            "ms" => 0.001,
+            "s" => 1.0,
+            "m" => 60.0,
+            "h" => 3600.0,
+            _ => return Err(invalid()),
+        };
+        total += value * unit_secs;
+        rest = &rest[digits + unit_len..];
+    }
+    (total > 0.0).then(|| Duration::from_secs_f64(total)).ok_or_else(invalid)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RuntimeLimit {
+    limit: Duration,
+}
+
+impl RuntimeLimit {
+    pub fn new(limit: Duration) -> Self {
+        RuntimeLimit { limit }
+    }
+
+    pub fn limit(&self) -> Duration {
+        self.limit
+    }
+
+    pub fn expired(&self, elapsed: Duration) -> bool {
+        elapsed >= self.limit
+    }
+
+    // Pause avant le cycle suivant, bornée au temps restant; None si la durée est écoulée
+    pub fn pause_within(&self, pause: Duration, elapsed: Duration) -> Option<Duration> {
+        let remaining = self.limit.checked_sub(elapsed).filter(|remaining| !remaining.is_zero())?;
+        Some(pause.min(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_accept_units_and_combinations() {
+        assert_eq!(parse_duration("10m"), Ok(Duration::from_secs(600)));
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("45"), Ok(Duration::from_secs(45)));
+        for invalid in ["", "0", "-5", "10x", "m10", "ten minutes", "0s"] {
+            assert!(parse_duration(invalid).is_err(), "'{}' refusé", invalid);
+        }
+    }
+
+    // Boucle principale rejouée sur une horloge simulée: chaque cycle dure 4 min, pause de 3 min
+    fn cycles_before_exit(limit: Duration, cycle_duration: Duration, pause: Duration) -> (u32, Duration) {
+        let limit = RuntimeLimit::new(limit);
+        let mut clock = Duration::ZERO;
+        let mut cycles = 0;
+        loop {
+            cycles += 1;
+            clock += cycle_duration; // le cycle en cours se termine toujours
+            let Some(pause) = limit.pause_within(pause, clock) else {
+                return (cycles, clock);
+            };
+            clock += pause;
+            if limit.expired(clock) {
+                return (cycles, clock);
+            }
+        }
+    }
+
+    #[test]
+    fn the_loop_exits_once_the_runtime_has_elapsed() {
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+
+        // t=4 fin du cycle 1, pause 3 -> t=7, fin du cycle 2 à t=11 > 10: arrêt sans pause
+        assert_eq!(cycles_before_exit(minutes(10), minutes(4), minutes(3)), (2, minutes(11)));
+        // t=4, pause 3 -> 7, cycle -> 11, pause réduite au temps restant (1) -> 12: arrêt à l'échéance
+        assert_eq!(cycles_before_exit(minutes(12), minutes(4), minutes(3)), (2, minutes(12)));
+        // Un cycle plus long que la durée est terminé avant l'arrêt
+        assert_eq!(cycles_before_exit(minutes(1), minutes(4), minutes(3)), (1, minutes(4)));
+
+        let limit = RuntimeLimit::new(minutes(10));
+        assert_eq!(limit.pause_within(minutes(3), minutes(9)), Some(minutes(1)));
+        assert_eq!(limit.pause_within(minutes(3), minutes(10)), None);
+        assert!(!limit.expired(minutes(9)) && limit.expired(minutes(10)));
+    }
+}
@@ -0,0 +1,163 @@
+// Planificateur des tâches de maintenance périodiques
+// Cadencé par les cycles de la boucle principale: une tâche de période N
+// s'exécute aux cycles N, 2N, 3N... Les tâches calées sur l'heure (remise à zéro
+// quotidienne) suivent le temps réel écoulé, quelle que soit la durée des cycles
+// (pause adaptative). Pas d'état global, tout est porté par le Scheduler
+use std::time::{Duration, Instant};
+
+enum Cadence {
+    Cycles(u64),
+    Wall { period: Duration, last: Option<Instant> }, // Dernière exécution, ou premier tick
+}
+
+struct Job<C> {
+    name: &'static str,
+    cadence: Cadence,
+    action: Box<dyn FnMut(&mut C) + Send>,
+}
+
+pub struct Scheduler<C> {
+    jobs: Vec<Job<C>>,
+    cycle: u64,
+}
+
+impl<C> Scheduler<C> {
+    pub fn new() -> Self {
+        Self { jobs: Vec::new(), cycle: 0 }
+    }
+
+    // Enregistrer une tâche exécutée tous les `period` cycles (minimum 1)
+    pub fn every<F>(&mut self, name: &'static str, period: u64, action: F) -> &mut Self
+    where
+        F: FnMut(&mut C) + Send + 'static,
+    {
+        self.jobs.push(Job { name, cadence: Cadence::Cycles(period.max(1)), action: Box::new(action) });
+        self
+    }
+
+    // Enregistrer une tâche exécutée dès que `period` s'est écoulé depuis sa dernière exécution
+    // (depuis le premier tick pour la première)
+    pub fn every_interval<F>(&mut self, name: &'static str, period: Duration, action: F) -> &mut Self
+    where
+        F: FnMut(&mut C) + Send + 'static,
+    {
+        self.jobs.push(Job { name, cadence: Cadence::Wall { period, last: None }, action: Box::new(action) });
+        self
+    }
+
+    // Avancer d'un cycle et exécuter les tâches échues, renvoie leurs noms
+    pub fn tick(&mut self, context: &mut C) -> Vec<&'static str> {
+        self.tick_at(context, Instant::now())
+    }
+
+    // tick à l'instant `now` (horloge fournie par l'appelant)
+    pub fn tick_at(&mut self, context: &mut C, now: Instant) -> Vec<&'static str> {
+        self.cycle += 1;
+        let mut fired = Vec::new();
+        for job in &mut self.jobs {
+            let due = match &mut job.cadence {
+                Cadence::Cycles(period) => self.cycle.is_multiple_of(*period),
+                Cadence::Wall { period, last } => {
+                    let since = *last.get_or_insert(now);
+                    let due = now.saturating_duration_since(since) >= *period;
+                    if due {
+                        *last = Some(now);
+                    }
+                    due
+                }
+            };
+            if due {
+                (job.action)(context);
+                fired.push(job.name);
+            }
+        }
+        fired
+    }
+}
+
+impl<C> Default for Scheduler<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Pause adaptative entre deux cycles: raccourcie après un cycle qui a produit des
+// signaux de trading (réagir vite à une actualité), allongée après un cycle calme
+// (économiser le quota des APIs), toujours bornée par [floor, ceiling]
+const ACTIVE_PAUSE_FACTOR: f64 = 0.5;
+const QUIET_PAUSE_FACTOR: f64 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptivePause {
+    floor_secs: f64,
+    ceiling_secs: f64,
+    current_secs: f64,
+}
+
+impl AdaptivePause {
+    pub fn new(initial_secs: f64, floor_secs: f64, ceiling_secs: f64) -> Self {
+        let ceiling_secs = ceiling_secs.max(floor_secs);
+        Self { floor_secs, ceiling_secs, current_secs: initial_secs.clamp(floor_secs, ceiling_secs) }
+    }
+
+    pub fn current_secs(&self) -> f64 {
+        self.current_secs
+    }
+
+    // Ajuster la pause selon le nombre de signaux BUY/SELL du cycle, renvoie la nouvelle pause
+    pub fn after_cycle(&mut self, trading_signals: usize) -> f64 {
+        let factor = if trading_signals > 0 { ACTIVE_PAUSE_FACTOR } else { QUIET_PAUSE_FACTOR };
+        self.current_secs = (self.current_secs * factor).clamp(self.floor_secs, self.ceiling_secs);
+        self.current_secs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_period_3_job_fires_on_cycles_3_and_6() {
+        let mut scheduler = Scheduler::new();
+        scheduler.every("every_3", 3, |runs: &mut Vec<u64>| runs.push(0));
+        let mut runs = Vec::new();
+        let fired_on: Vec<u64> = (1..=7).filter(|_| !scheduler.tick(&mut runs).is_empty()).collect();
+        assert_eq!(fired_on, vec![3, 6]);
+        assert_eq!(runs.len(), 2);
+    }
+
+    #[test]
+    fn interval_jobs_follow_elapsed_time_not_cycles() {
+        let day = Duration::from_secs(86_400);
+        let mut scheduler = Scheduler::new();
+        scheduler.every_interval("daily_reset", day, |resets: &mut u32| *resets += 1);
+        let start = Instant::now();
+        let mut resets = 0;
+
+        // Cycles rapprochés (pause raccourcie): aucun reset avant 24h, quel que soit leur nombre
+        for minute in 0..(24 * 60) {
+            scheduler.tick_at(&mut resets, start + Duration::from_secs(minute * 60));
+        }
+        assert_eq!(resets, 0);
+        assert_eq!(scheduler.tick_at(&mut resets, start + day), vec!["daily_reset"]);
+
+        // Cycles espacés: un seul reset par tranche de 24h écoulée
+        assert!(scheduler.tick_at(&mut resets, start + day + Duration::from_secs(3_600)).is_empty());
+        scheduler.tick_at(&mut resets, start + day * 2);
+        assert_eq!(resets, 2);
+    }
+
+    #[test]
+    fn trading_signals_shorten_the_pause_and_a_quiet_cycle_lengthens_it() {
+        let mut pause = AdaptivePause::new(10.0, 2.0, 60.0);
+        assert_eq!(pause.after_cycle(3), 5.0);
+        assert_eq!(pause.after_cycle(0), 7.5);
+
+        // Bornes tenues dans les deux sens
+        let mut pause = AdaptivePause::new(10.0, 2.0, 60.0);
+        (0..10).for_each(|_| { pause.after_cycle(1); });
+        assert_eq!(pause.current_secs(), 2.0);
+        (0..20).for_each(|_| { pause.after_cycle(0); });
+        assert_eq!(pause.current_secs(), 60.0);
+    }
+}
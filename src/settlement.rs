@@ -0,0 +1,148 @@
+// Règlement des positions à la résolution du marché
+// Un marché résolu paie 1.0 par token gagnant et 0.0 par token perdant: le PnL final d'une
+// position est calculé sur ce prix de règlement, pas sur le dernier prix observé.
+use serde_json::Value;
+
+// Issue d'un marché binaire résolu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Yes,
+    No,
+}
+
+impl Outcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Yes => "YES",
+            Outcome::No => "NO",
+        }
+    }
+}
+
+// Position ouverte sur un token du marché
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenPosition {
+    pub market_id: String,
+    pub holds_yes: bool, // Token détenu: YES, sinon NO
+    pub shares: f64,
+    pub entry_price: f64, // Prix moyen d'entrée
+    pub opened_at: f64,
+    pub order_id: Option<String>, // Ordre GTC dont les remplissages successifs s'y cumulent
+}
+
+impl OpenPosition {
+    pub fn cost(&self) -> f64 {
+        self.shares * self.entry_price
+    }
+
+    // Remplissage supplémentaire de l'ordre de la position: tokens ajoutés au prix d'entrée moyen
+    pub fn add_fill(&mut self, shares: f64, price: f64) {
+        let cost = self.cost() + shares * price;
+        self.shares += shares;
+        if self.shares > 0.0 {
+            self.entry_price = cost / self.shares;
+        }
+    }
+
+    // Valeur au règlement: 1.0 par token si l'issue est celle du token détenu, sinon 0.0
+    pub fn settlement_value(&self, outcome: Outcome) -> f64 {
+        let wins = (outcome == Outcome::Yes) == self.holds_yes;
+        if wins { self.shares } else { 0.0 }
+    }
+
+    // PnL réalisé au règlement: valeur de règlement moins coût d'entrée
+    pub fn settlement_pnl(&self, outcome: Outcome) -> f64 {
+        self.settlement_value(outcome) - self.cost()
+    }
+}
+
+// Issue d'un marché lue dans sa réponse Gamma / data-api / CLOB, None tant qu'il n'est pas résolu.
+// Résolu: `resolved: true`, `umaResolutionStatus: "resolved"` ou `closed: true`; le gagnant vient
+// de `tokens[].winner` (CLOB) ou de `outcomePrices` à 1/0 (Gamma, tableau ou chaîne JSON)
+pub fn resolved_outcome(market: &Value) -> Option<Outcome> {
+    let resolved = market["resolved"].as_bool() == Some(true)
+        || market["umaResolutionStatus"].as_str().is_some_and(|status| status.eq_ignore_ascii_case("resolved"))
+        || market["closed"].as_bool() == Some(true);
+    if !resolved {
+        return None;
+    }
+    winner_from_tokens(market).or_else(|| winner_from_prices(market))
+}
+
+fn winner_from_tokens(market: &Value) -> Option<Outcome> {
+    market["tokens"].as_array()?.iter()
+        .find(|token| token["winner"].as_bool() == Some(true))
+        .and_then(|token| outcome_named(token["outcome"].as_str()?))
+}
+
+fn winner_from_prices(market: &Value) -> Option<Outcome> {
+    let list = |key: &str| -> Option<Vec<Value>> {
+        match &market[key] {
+            Value::Array(values) => Some(values.clone()),
+            Value::String(raw) => serde_json::from_str(raw).ok(),
+            _ => None,
+        }
+    };
+    let prices: Vec<f64> = list("outcomePrices")?.iter()
+        .map(|price| price.as_f64().or_else(|| price.as_str()?.trim().parse().ok()))
+        .collect::<Option<_>>()?;
+    let outcomes = list("outcomes").unwrap_or_else(|| vec![Value::from("Yes"), Value::from("No")]);
+    if prices.len() != 2 || outcomes.len() != 2 {
+        return None;
+    }
+    // Règlement net seulement: un marché fermé encore coté entre 0 et 1 n'est pas résolu
+    let winner = prices.iter().position(|price| *price >= 0.99)?;
+    if prices[1 - winner] > 0.01 {
+        return None;
+    }
+    outcome_named(outcomes[winner].as_str()?)
+}
+
+fn outcome_named(name: &str) -> Option<Outcome> {
+    match name.trim().to_lowercase().as_str() {
+        "yes" => Some(Outcome::Yes),
+        "no" => Some(Outcome::No),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKET: &str = "settle-btc-100k";
+
+    fn yes_position(shares: f64, entry_price: f64) -> OpenPosition {
+        OpenPosition { market_id: MARKET.to_string(), holds_yes: true, shares, entry_price, opened_at: 0.0, order_id: None }
+    }
+
+    #[test]
+    fn resolution_is_read_from_gamma_and_clob_shapes() {
+        // Gamma: outcomePrices encodé en chaîne JSON
+        let gamma = serde_json::json!({ "closed": true, "umaResolutionStatus": "resolved",
+                                        "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "[\"1\", \"0\"]" });
+        assert_eq!(resolved_outcome(&gamma), Some(Outcome::Yes));
+
+        // CLOB: token gagnant marqué winner
+        let clob = serde_json::json!({ "closed": true, "tokens": [
+            { "outcome": "Yes", "winner": false }, { "outcome": "No", "winner": true } ] });
+        assert_eq!(resolved_outcome(&clob), Some(Outcome::No));
+
+        // Marché ouvert, ou fermé mais pas encore réglé (prix intermédiaires): pas d'issue
+        let open = serde_json::json!({ "closed": false, "outcomePrices": ["1", "0"] });
+        assert_eq!(resolved_outcome(&open), None);
+        let pending = serde_json::json!({ "closed": true, "outcomePrices": ["0.62", "0.38"] });
+        assert_eq!(resolved_outcome(&pending), None);
+    }
+
+    #[test]
+    fn resolved_yes_pays_a_yes_position_in_full() {
+        let position = yes_position(100.0, 0.40);
+        assert_eq!(position.settlement_value(Outcome::Yes), 100.0);
+        assert!((position.settlement_pnl(Outcome::Yes) - 60.0).abs() < 1e-9);
+        assert!((position.settlement_pnl(Outcome::No) + 40.0).abs() < 1e-9);
+
+        let no_position = OpenPosition { holds_yes: false, ..yes_position(50.0, 0.30) };
+        assert!((no_position.settlement_pnl(Outcome::No) - 35.0).abs() < 1e-9);
+    }
+}
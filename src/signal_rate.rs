@@ -0,0 +1,86 @@
+// Garde-fou de débit de signaux
+// Un moteur de décision sain produit quelques signaux par minute; des centaines trahissent un bug
+// (doublons, boucle). Au-delà de MAX_SIGNALS_PER_MINUTE sur une fenêtre glissante, le moteur est
+// suspendu jusqu'au redémarrage: tous les signaux passent en MONITOR et une alerte est émise.
+// Les instants sont passés en paramètre (secondes Unix), ce qui rend l'horloge simulable.
+use std::collections::VecDeque;
+
+pub const WINDOW_SECS: f64 = 60.0;
+
+#[derive(Debug, Clone)]
+pub struct SignalRateMonitor {
+    max_per_window: usize,  // 0 = garde-fou désactivé
+    recent: VecDeque<f64>,  // Instants des signaux de la fenêtre
+    halted_at: Option<f64>, // Déclenchement, None tant que le débit reste normal
+}
+
+impl SignalRateMonitor {
+    pub fn new(max_per_minute: usize) -> Self {
+        SignalRateMonitor { max_per_window: max_per_minute, recent: VecDeque::new(), halted_at: None }
+    }
+
+    // Enregistrer un signal; true uniquement pour celui qui dépasse le plafond
+    pub fn record(&mut self, now: f64) -> bool {
+        if self.max_per_window == 0 {
+            return false;
+        }
+        while self.recent.front().is_some_and(|t| now - t >= WINDOW_SECS) {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(now);
+        if self.halted_at.is_none() && self.recent.len() > self.max_per_window {
+            self.halted_at = Some(now);
+            return true;
+        }
+        false
+    }
+
+    // Signaux dans la fenêtre se terminant à `now`
+    pub fn rate(&self, now: f64) -> usize {
+        self.recent.iter().filter(|t| now - *t < WINDOW_SECS).count()
+    }
+
+    pub fn max_per_minute(&self) -> usize {
+        self.max_per_window
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted_at.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exceeding_the_rate_trips_a_latching_halt() {
+        let mut monitor = SignalRateMonitor::new(3);
+        assert!((0..3).all(|i| !monitor.record(1000.0 + i as f64)), "jusqu'au plafond: pas de halte");
+        assert!(!monitor.is_halted());
+        assert!(monitor.record(1003.0), "quatrième signal dans la minute: halte");
+        assert!(monitor.is_halted());
+        assert!(!monitor.record(1004.0), "alerte émise une seule fois");
+
+        // La halte persiste même quand le débit retombe
+        assert!(!monitor.record(2000.0));
+        assert_eq!(monitor.rate(2000.0), 1);
+        assert!(monitor.is_halted());
+    }
+
+    #[test]
+    fn signals_spread_over_the_window_stay_below_the_ceiling() {
+        let mut monitor = SignalRateMonitor::new(3);
+        // Un signal toutes les 25s: jamais plus de 3 dans une fenêtre de 60s
+        for i in 0..20 {
+            assert!(!monitor.record(i as f64 * 25.0));
+        }
+        assert!(!monitor.is_halted());
+        assert_eq!(monitor.rate(19.0 * 25.0), 3);
+        assert_eq!(monitor.rate(19.0 * 25.0 + WINDOW_SECS), 0);
+
+        let mut disabled = SignalRateMonitor::new(0);
+        assert!((0..1000).all(|_| !disabled.record(0.0)), "plafond 0: garde-fou désactivé");
+        assert!(!disabled.is_halted());
+    }
+}
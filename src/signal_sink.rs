@@ -0,0 +1,142 @@
+// Sortie des signaux BUY/SELL sous forme d'intentions d'ordre, sans exécution
+// Permet d'utiliser le bot comme simple générateur de signaux pour un OMS externe
+// Schéma JSON documenté dans le README (section "Signal Sink")
+use reqwest::Client;
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+pub const ORDER_INTENT_SCHEMA: &str = "polymarket-bot/order-intent/v1";
+pub const ORDER_INTENT_VENUE: &str = "polymarket-clob";
+pub const ORDER_INTENT_TTL_SECS: u64 = 30;
+pub const DEFAULT_SIGNAL_SINK_FILE: &str = "order_intents.jsonl";
+
+// Intention d'ordre indépendante de l'exchange (champs inspirés de FIX NewOrderSingle)
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderIntent {
+    pub schema: &'static str,
+    pub intent_id: String,
+    pub market_id: String,
+    pub side: String,               // "BUY" ou "SELL"
+    pub outcome: String,            // "YES" ou "NO"
+    pub token_id: Option<String>,   // Token CLOB si connu
+    pub size: f64,                  // Montant en USDC
+    pub limit_price: f64,           // Prix exécutable au moment du signal
+    pub order_type: &'static str,   // Toujours "LIMIT"
+    pub time_in_force: &'static str, // "GTD": valable jusqu'à expire_time
+    pub venue: &'static str,
+    pub ttl_secs: u64,
+    pub transact_time: String,      // ISO 8601 UTC
+    pub expire_time: String,        // transact_time + ttl_secs
+    pub confidence: String,
+    pub expected_roi: f64,
+    pub net_edge: f64,              // expected_roi moins frais, gas et slippage estimés
+    pub source: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignalSink {
+    Stdout,
+    File(String),
+    Http(String),
+}
+
+impl SignalSink {
+    // Construire un sink à partir de --signal-sink et de sa cible éventuelle
+    pub fn from_options(kind: &str, path: Option<&str>, url: Option<&str>) -> Result<Self, String> {
+        match kind {
+            "stdout" => Ok(SignalSink::Stdout),
+            "file" => Ok(SignalSink::File(path.unwrap_or(DEFAULT_SIGNAL_SINK_FILE).to_string())),
+            "http" => url
+                .map(|u| SignalSink::Http(u.to_string()))
+                .ok_or_else(|| "--signal-sink http nécessite --signal-sink-url <url>".to_string()),
+            other => Err(format!("--signal-sink: cible inconnue '{}' (attendu: stdout, file, http)", other)),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            SignalSink::Stdout => "stdout".to_string(),
+            SignalSink::File(path) => format!("file ({})", path),
+            SignalSink::Http(url) => format!("http ({})", url),
+        }
+    }
+
+    // Émettre une intention: une ligne JSON sur stdout/fichier, un POST JSON en HTTP
+    pub async fn emit(&self, intent: &OrderIntent, client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            SignalSink::Stdout => {
+                println!("{}", serde_json::to_string(intent)?);
+            }
+            SignalSink::File(path) => {
+                let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+                writeln!(file, "{}", serde_json::to_string(intent)?)?;
+            }
+            SignalSink::Http(url) => {
+                let response = client.post(url).json(intent).send().await?;
+                if !response.status().is_success() {
+                    return Err(format!("Sink HTTP {}: {}", url, response.status()).into());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intent() -> OrderIntent {
+        OrderIntent {
+            schema: ORDER_INTENT_SCHEMA,
+            intent_id: "m1-SELL-1700000000000".to_string(),
+            market_id: "m1".to_string(),
+            side: "SELL".to_string(),
+            outcome: "YES".to_string(),
+            token_id: Some("101".to_string()),
+            size: 25.0,
+            limit_price: 0.39,
+            order_type: "LIMIT",
+            time_in_force: "GTD",
+            venue: ORDER_INTENT_VENUE,
+            ttl_secs: ORDER_INTENT_TTL_SECS,
+            transact_time: "2023-11-14T22:13:20.000Z".to_string(),
+            expire_time: "2023-11-14T22:13:50.000Z".to_string(),
+            confidence: "medium".to_string(),
+            expected_roi: 0.12,
+            net_edge: 0.1,
+            source: "https://www.federalreserve.gov/feeds/press_all.xml".to_string(),
+        }
+    }
+
+    #[test]
+    fn sink_options_pick_the_target_and_its_default() {
+        assert_eq!(SignalSink::from_options("stdout", None, None), Ok(SignalSink::Stdout));
+        assert_eq!(SignalSink::from_options("file", None, None), Ok(SignalSink::File(DEFAULT_SIGNAL_SINK_FILE.to_string())));
+        assert_eq!(SignalSink::from_options("file", Some("out.jsonl"), None), Ok(SignalSink::File("out.jsonl".to_string())));
+        assert_eq!(SignalSink::from_options("http", None, Some("http://oms/intents")), Ok(SignalSink::Http("http://oms/intents".to_string())));
+        assert!(SignalSink::from_options("http", None, None).unwrap_err().contains("--signal-sink-url"));
+        assert!(SignalSink::from_options("kafka", None, None).unwrap_err().contains("cible inconnue 'kafka'"));
+    }
+
+    #[tokio::test]
+    async fn the_file_sink_appends_one_json_line_per_intent() {
+        let path = std::env::temp_dir().join(format!("polymarket-signal-sink-unit-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let sink = SignalSink::File(path.to_str().unwrap().to_string());
+        let client = Client::new();
+        sink.emit(&intent(), &client).await.unwrap();
+        sink.emit(&intent(), &client).await.unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let lines: Vec<serde_json::Value> = written.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["schema"], ORDER_INTENT_SCHEMA);
+        assert_eq!(lines[0]["intent_id"], "m1-SELL-1700000000000");
+        assert_eq!((lines[0]["side"].as_str(), lines[0]["outcome"].as_str()), (Some("SELL"), Some("YES")));
+        assert_eq!(lines[0]["token_id"], "101");
+        assert_eq!(lines[0]["limit_price"], 0.39);
+    }
+}
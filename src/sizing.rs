@@ -0,0 +1,70 @@
+// Dimensionnement unique des positions
+// Simulation, exécution réelle et conseil du core C++ partent de la même mise:
+// 1% du capital, ajusté selon le ROI et la confiance, borné à [0.5%, 5%] du capital,
+// puis réduit de la volatilité du marché.
+
+pub const BASE_FRACTION: f64 = 0.01;
+pub const MIN_FRACTION: f64 = 0.005;
+pub const MAX_FRACTION: f64 = 0.05;
+
+pub fn size_position(capital: f64, roi: f64, confidence: &str, volatility: f64) -> f64 {
+    if capital <= 0.0 || !capital.is_finite() {
+        return 0.0;
+    }
+    let mut size = capital * BASE_FRACTION;
+
+    // Ajustement selon le ROI
+    if roi > 0.10 {
+        size *= 1.5;
+    } else if roi > 0.05 {
+        size *= 1.2;
+    } else if roi < 0.02 {
+        size *= 0.5;
+    }
+
+    // Ajustement selon la confiance
+    size *= match confidence {
+        "high" => 1.3,
+        "medium" => 1.0,
+        "low" => 0.7,
+        _ => 0.5,
+    };
+
+    // Limites de sécurité, puis marché volatil -> mise réduite d'autant
+    let bounded = size.clamp(capital * MIN_FRACTION, capital * MAX_FRACTION);
+    let volatility = if volatility.is_finite() { volatility.clamp(0.0, 1.0) } else { 0.0 };
+    bounded * (1.0 - volatility)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_inputs_give_the_same_stake() {
+        let cases = [(4000.0, 0.45, "medium", 0.02), (4000.0, 0.07, "high", 0.0), (250.0, 0.01, "low", 0.1)];
+        for (capital, roi, confidence, volatility) in cases {
+            let first = size_position(capital, roi, confidence, volatility);
+            let second = size_position(capital, roi, confidence, volatility);
+            assert_eq!(first.to_bits(), second.to_bits(), "mise instable pour {:?}", (capital, roi, confidence, volatility));
+        }
+    }
+
+    #[test]
+    fn stake_follows_roi_confidence_bounds_and_volatility() {
+        // 1% du capital, ROI > 10% -> x1.5, confiance haute -> x1.3
+        assert!((size_position(4000.0, 0.2, "high", 0.0) - 78.0).abs() < 1e-9);
+        // Confiance moyenne, ROI entre 2% et 5%: mise de base
+        assert!((size_position(4000.0, 0.03, "medium", 0.0) - 40.0).abs() < 1e-9);
+        // Plancher à 0.5% du capital
+        assert!((size_position(4000.0, 0.01, "unknown", 0.0) - 20.0).abs() < 1e-9);
+        // Volatilité de 10% -> mise réduite de 10%
+        assert!((size_position(4000.0, 0.03, "medium", 0.1) - 36.0).abs() < 1e-9);
+        // Capital nul ou invalide: aucune mise
+        assert_eq!(size_position(0.0, 0.2, "high", 0.0), 0.0);
+        assert_eq!(size_position(f64::NAN, 0.2, "high", 0.0), 0.0);
+        // Volatilité invalide ignorée, jamais de mise négative
+        assert!((size_position(4000.0, 0.03, "medium", f64::NAN) - 40.0).abs() < 1e-9);
+        assert_eq!(size_position(4000.0, 0.03, "medium", 2.0), 0.0);
+    }
+}
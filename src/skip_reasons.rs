@@ -0,0 +1,98 @@
+// Raisons pour lesquelles un marché n'a produit aucune opportunité pendant la phase 3
+// Chaque marché analysé sans opportunité reçoit une seule raison, la première rencontrée dans
+// l'ordre du pipeline: filtre, liquidité, source de résolution, sources configurées, sources
+// joignables, pertinence.
+// Le décompte est remis à zéro à chaque cycle et repris dans le rapport (--report-json).
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SkipReason {
+    Filtered,           // Domaine désactivé (--only-domain) ou marché sur la denylist
+    LowLiquidity,       // Liquidité connue inférieure à MIN_MARKET_LIQUIDITY
+    NoResolutionSource, // --missing-source require: aucune source de résolution identifiable
+    NoRelevantSource,   // Aucune source configurée pour le domaine du marché
+    SourceUnavailable,  // Sources configurées, mais aucune n'a répondu ce cycle
+    BelowThreshold,     // Sources lues, pertinence toujours sous le seuil du domaine
+}
+
+impl SkipReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::Filtered => "filtered",
+            SkipReason::LowLiquidity => "low_liquidity",
+            SkipReason::NoResolutionSource => "no_resolution_source",
+            SkipReason::NoRelevantSource => "no_relevant_source",
+            SkipReason::SourceUnavailable => "source_unavailable",
+            SkipReason::BelowThreshold => "below_threshold",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SkipTally {
+    markets: BTreeMap<String, SkipReason>,
+}
+
+impl SkipTally {
+    pub fn clear(&mut self) {
+        self.markets.clear();
+    }
+
+    // Un marché n'a qu'une raison: la dernière enregistrée remplace la précédente
+    pub fn record(&mut self, market_id: &str, reason: SkipReason) {
+        self.markets.insert(market_id.to_string(), reason);
+    }
+
+    pub fn len(&self) -> usize {
+        self.markets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.markets.is_empty()
+    }
+
+    // Nombre de marchés par raison, raisons absentes omises
+    pub fn counts(&self) -> BTreeMap<SkipReason, usize> {
+        let mut counts = BTreeMap::new();
+        for reason in self.markets.values() {
+            *counts.entry(*reason).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    // Marchés ignorés et leur raison, par id
+    pub fn markets(&self) -> &BTreeMap<String, SkipReason> {
+        &self.markets
+    }
+
+    // "below_threshold=2, no_relevant_source=1"
+    pub fn summary(&self) -> String {
+        self.counts().iter()
+            .map(|(reason, count)| format!("{}={}", reason.as_str(), count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_market_keeps_a_single_reason() {
+        let mut tally = SkipTally::default();
+        tally.record("m1", SkipReason::BelowThreshold);
+        tally.record("m2", SkipReason::NoRelevantSource);
+        tally.record("m3", SkipReason::BelowThreshold);
+        tally.record("m2", SkipReason::Filtered);
+
+        assert_eq!(tally.len(), 3);
+        assert_eq!(tally.markets().get("m2"), Some(&SkipReason::Filtered), "dernière raison retenue");
+        assert_eq!(tally.counts().get(&SkipReason::BelowThreshold), Some(&2));
+        assert_eq!(tally.counts().get(&SkipReason::NoRelevantSource), None);
+        assert_eq!(tally.summary(), "filtered=1, below_threshold=2");
+
+        tally.clear();
+        assert!(tally.is_empty() && tally.summary().is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+// Lissage exponentiel (EMA) du prix d'un marché
+// Sert à la comparaison avec la fair value: un tick isolé ne doit pas déclencher de réaction,
+// un mouvement soutenu si. L'exécution reste sur le dernier prix brut.
+
+// Coefficient par défaut: 1.0 = pas de lissage (la dernière valeur est reprise telle quelle)
+pub const NO_SMOOTHING: f64 = 1.0;
+
+// EMA d'une série chronologique: s = alpha * x + (1 - alpha) * s_prec, initialisée sur la première valeur
+pub fn ema(values: &[f64], alpha: f64) -> Option<f64> {
+    let alpha = alpha.clamp(0.0, 1.0);
+    let (first, rest) = values.split_first()?;
+    Some(rest.iter().fold(*first, |smoothed, value| alpha * value + (1.0 - alpha) * smoothed))
+}
+
+// Prix lissé à partir de l'historique (timestamp, prix), trié par ordre chronologique
+pub fn smoothed_price(history: &[(f64, f64)], alpha: f64) -> Option<f64> {
+    let mut points = history.to_vec();
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let prices: Vec<f64> = points.iter().map(|(_, price)| *price).collect();
+    ema(&prices, alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ema_dampens_a_single_outlier_tick() {
+        // Prix stable à 0.50 puis un tick isolé à 0.80
+        let mut history: Vec<(f64, f64)> = (0..10).map(|i| (i as f64, 0.50)).collect();
+        history.push((10.0, 0.80));
+
+        let raw = history.last().unwrap().1;
+        let smoothed = smoothed_price(&history, 0.2).expect("historique non vide");
+        assert!((smoothed - 0.56).abs() < 1e-9, "EMA attendue 0.56, obtenu {}", smoothed);
+        assert!((smoothed - 0.50).abs() < (raw - 0.50).abs() / 4.0,
+                "le tick isolé doit être amorti: brut {} / lissé {}", raw, smoothed);
+    }
+
+    #[test]
+    fn ema_follows_a_sustained_move() {
+        let mut prices = vec![0.50; 10];
+        prices.extend(std::iter::repeat_n(0.80, 20));
+        let smoothed = ema(&prices, 0.2).expect("série non vide");
+        assert!(smoothed > 0.79, "mouvement soutenu non suivi: {}", smoothed);
+    }
+
+    #[test]
+    fn alpha_one_keeps_the_raw_price() {
+        let history = [(2.0, 0.80), (0.0, 0.50), (1.0, 0.50)];
+        assert_eq!(smoothed_price(&history, NO_SMOOTHING), Some(0.80));
+        assert_eq!(smoothed_price(&[], 0.5), None);
+    }
+}
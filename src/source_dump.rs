@@ -0,0 +1,45 @@
+// Export des résultats de surveillance des sources (--dump-sources)
+// Un répertoire par cycle, un fichier JSON par source: ce que la source a renvoyé et quels
+// mots-clés ont été reconnus, élément par élément, pour déboguer la pertinence.
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+// Répertoire d'un cycle: <dir>/cycle-<n>
+pub fn cycle_dir(dir: &Path, cycle: u64) -> PathBuf {
+    dir.join(format!("cycle-{}", cycle))
+}
+
+// Nom de fichier d'une source: URL sans schéma, caractères hors [A-Za-z0-9.-] remplacés par '_'
+pub fn file_name(url: &str) -> String {
+    let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let name: String = without_scheme.trim_end_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    format!("{}.json", name)
+}
+
+// Écrire un fichier par source (url, enregistrement), renvoie le répertoire du cycle
+pub fn write_cycle(dir: &Path, cycle: u64, records: &[(String, Value)]) -> std::io::Result<PathBuf> {
+    let cycle_dir = cycle_dir(dir, cycle);
+    std::fs::create_dir_all(&cycle_dir)?;
+    for (url, record) in records {
+        let content = serde_json::to_string_pretty(record)?;
+        std::fs::write(cycle_dir.join(file_name(url)), content + "\n")?;
+    }
+    Ok(cycle_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEC_SOURCE: &str = "https://www.sec.gov/news/pressreleases.rss";
+
+    #[test]
+    fn file_names_are_filesystem_safe() {
+        assert_eq!(file_name(SEC_SOURCE), "www.sec.gov_news_pressreleases.rss.json");
+        assert_eq!(file_name("https://www.coindesk.com/arc/outboundfeeds/rss/"), "www.coindesk.com_arc_outboundfeeds_rss.json");
+        assert_eq!(file_name("https://api.example.com/v1?q=a&key="), "api.example.com_v1_q_a_key_.json");
+    }
+}
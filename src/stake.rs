@@ -0,0 +1,155 @@
+// Unité d'une mise: montant notionnel (USDC) ou quantité de tokens
+// Les livres sont des niveaux (prix, tokens) et le CLOB prend des tailles en tokens, alors que
+// le sizing produit des dollars (stake_amount des signaux). Chaque conversion passe par ce type
+// pour qu'un prix moyen, un ROI ou une taille d'ordre porte toujours sur la bonne quantité.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stake {
+    Dollars(f64), // USDC dépensés (achat) ou reçus (vente)
+    Shares(f64),  // Tokens achetés ou vendus
+}
+
+impl Stake {
+    pub fn amount(&self) -> f64 {
+        match self {
+            Stake::Dollars(amount) | Stake::Shares(amount) => *amount,
+        }
+    }
+
+    // Même unité, autre montant
+    pub fn with_amount(&self, amount: f64) -> Stake {
+        match self {
+            Stake::Dollars(_) => Stake::Dollars(amount),
+            Stake::Shares(_) => Stake::Shares(amount),
+        }
+    }
+
+    // Tokens correspondant à la mise au prix donné (0 sans prix valide)
+    pub fn shares_at(&self, price: f64) -> f64 {
+        match self {
+            Stake::Shares(shares) => *shares,
+            Stake::Dollars(dollars) if price > 0.0 => dollars / price,
+            Stake::Dollars(_) => 0.0,
+        }
+    }
+
+    // Montant notionnel de la mise au prix donné
+    pub fn dollars_at(&self, price: f64) -> f64 {
+        match self {
+            Stake::Dollars(dollars) => *dollars,
+            Stake::Shares(shares) => shares * price,
+        }
+    }
+}
+
+// Remplissage d'une mise en traversant un livre
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookFill {
+    pub shares: f64,     // Tokens remplis
+    pub notional: f64,   // USDC payés (asks) ou reçus (bids)
+    pub unfilled: Stake, // Reliquat au-delà de la profondeur du livre, dans l'unité de la mise
+}
+
+impl BookFill {
+    pub fn average_price(&self) -> Option<f64> {
+        (self.shares > 0.0).then(|| self.notional / self.shares)
+    }
+
+    // Reliquat valorisé à un prix de repli (pire niveau plus slippage)
+    pub fn completed_at(self, price: f64) -> BookFill {
+        if self.unfilled.amount() <= 0.0 || price <= 0.0 {
+            return self;
+        }
+        BookFill {
+            shares: self.shares + self.unfilled.shares_at(price),
+            notional: self.notional + self.unfilled.dollars_at(price),
+            unfilled: self.unfilled.with_amount(0.0),
+        }
+    }
+}
+
+// Traverser les niveaux (prix, tokens), meilleur prix en premier, jusqu'à remplir la mise
+pub fn walk_book(levels: &[(f64, f64)], stake: Stake) -> BookFill {
+    let mut remaining = stake.amount().max(0.0);
+    let (mut shares, mut notional) = (0.0, 0.0);
+
+    for &(price, volume) in levels {
+        if remaining <= 0.0 {
+            break;
+        }
+        let level = match stake {
+            Stake::Shares(_) => remaining.min(volume),
+            Stake::Dollars(_) if price > 0.0 => (remaining / price).min(volume),
+            Stake::Dollars(_) => continue,
+        };
+        shares += level;
+        notional += level * price;
+        remaining = match stake {
+            Stake::Shares(_) => remaining - level,
+            Stake::Dollars(_) => (remaining - level * price).max(0.0),
+        };
+    }
+
+    BookFill { shares, notional, unfilled: stake.with_amount(remaining) }
+}
+
+// Mise en dollars vs en tokens: conversions et traversée du livre dans la bonne unité
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn a_ten_dollar_stake_at_half_buys_twenty_shares() {
+        assert!(close(Stake::Dollars(10.0).shares_at(0.5), 20.0));
+        assert!(close(Stake::Shares(20.0).dollars_at(0.5), 10.0));
+        assert_eq!(Stake::Dollars(10.0).shares_at(0.0), 0.0, "sans prix, aucune conversion");
+
+        let fill = walk_book(&[(0.5, 100.0)], Stake::Dollars(10.0));
+        assert!(close(fill.shares, 20.0));
+        assert!(close(fill.notional, 10.0));
+        assert_eq!(fill.unfilled, Stake::Dollars(0.0));
+        // Payé 10 USDC pour 20 tokens à 1 USDC la résolution: ROI de 100% sur les dollars engagés
+        assert!(close(fill.shares / fill.notional - 1.0, 1.0));
+    }
+
+    #[test]
+    fn roi_is_computed_on_dollars_spent_across_levels() {
+        let asks = [(0.5, 10.0), (0.6, 100.0)];
+
+        // 10 USDC: 10 tokens à 0.5 (5 USDC) puis 5 USDC à 0.6 (8.33 tokens)
+        let dollars = walk_book(&asks, Stake::Dollars(10.0));
+        assert!(close(dollars.shares, 10.0 + 5.0 / 0.6));
+        assert!(close(dollars.notional, 10.0));
+        assert!(close(dollars.average_price().unwrap(), 10.0 / (10.0 + 5.0 / 0.6)));
+        assert!(close(dollars.shares / dollars.notional - 1.0, (10.0 + 5.0 / 0.6) / 10.0 - 1.0));
+
+        // Lue comme 10 tokens, la même mise ne coûterait que 5 USDC et afficherait 100% de ROI
+        let shares = walk_book(&asks, Stake::Shares(10.0));
+        assert!(close(shares.shares, 10.0));
+        assert!(close(shares.notional, 5.0));
+        assert!(shares.shares / shares.notional > dollars.shares / dollars.notional);
+    }
+
+    #[test]
+    fn the_unfilled_remainder_stays_in_the_stake_unit() {
+        let asks = [(0.4, 10.0)];
+
+        let fill = walk_book(&asks, Stake::Dollars(10.0));
+        assert!(close(fill.notional, 4.0));
+        assert_eq!(fill.unfilled, Stake::Dollars(6.0));
+        // Reliquat valorisé au prix de repli: 6 USDC à 0.6 = 10 tokens de plus
+        let completed = fill.completed_at(0.6);
+        assert!(close(completed.shares, 20.0));
+        assert!(close(completed.notional, 10.0));
+        assert!(close(completed.average_price().unwrap(), 0.5));
+
+        let fill = walk_book(&asks, Stake::Shares(25.0));
+        assert_eq!(fill.unfilled, Stake::Shares(15.0));
+        assert!(close(fill.completed_at(0.6).notional, 4.0 + 15.0 * 0.6));
+        assert_eq!(walk_book(&[], Stake::Dollars(0.0)).average_price(), None);
+    }
+}
@@ -0,0 +1,71 @@
+// Identifiants de corrélation des logs
+// Chaque cycle reçoit un cycle_id, chaque opportunité (marché + source) un identifiant stable:
+// `grep 'market=<id>' polymarket.log` suit un marché de la récupération à l'exécution.
+
+// Identifiant d'un run: instant de démarrage du bot (ms, hexadécimal)
+pub fn run_id(run_started_ms: u64) -> String {
+    format!("{:x}", run_started_ms)
+}
+
+// Identifiant d'un cycle: identifiant du run et numéro du cycle
+pub fn cycle_id(run_started_ms: u64, cycle: u64) -> String {
+    format!("{}-{}", run_id(run_started_ms), cycle)
+}
+
+// Identifiant d'une opportunité, identique d'un cycle et d'un run à l'autre (FNV-1a 64 bits)
+pub fn opportunity_id(market_id: &str, source_url: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in market_id.bytes().chain([0u8]).chain(source_url.bytes()) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("opp-{:016x}", hash)
+}
+
+// Champs de corrélation d'un enregistrement: cycle, puis marché et opportunité s'ils sont connus
+#[derive(Debug, Clone, Default)]
+pub struct TraceFields<'a> {
+    pub market_id: Option<&'a str>,
+    pub opportunity_id: Option<String>,
+}
+
+impl<'a> TraceFields<'a> {
+    pub fn market(market_id: &'a str) -> Self {
+        TraceFields { market_id: Some(market_id), opportunity_id: None }
+    }
+
+    pub fn opportunity(market_id: &'a str, source_url: &str) -> Self {
+        TraceFields { market_id: Some(market_id), opportunity_id: Some(opportunity_id(market_id, source_url)) }
+    }
+}
+
+// Ligne de log: "<horodatage> - cycle=<id> [market=<id>] [opp=<id>] - <message>"
+pub fn format_record(timestamp: &str, cycle_id: &str, fields: &TraceFields, message: &str) -> String {
+    let mut record = format!("{} - cycle={}", timestamp, cycle_id);
+    if let Some(market_id) = fields.market_id {
+        record.push_str(&format!(" market={}", market_id));
+    }
+    if let Some(opportunity_id) = &fields.opportunity_id {
+        record.push_str(&format!(" opp={}", opportunity_id));
+    }
+    record.push_str(" - ");
+    record.push_str(message);
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opportunity_ids_are_stable_and_distinct() {
+        let id = opportunity_id("fx-crypto-1", "https://www.sec.gov/news/pressreleases.rss");
+        assert_eq!(id, opportunity_id("fx-crypto-1", "https://www.sec.gov/news/pressreleases.rss"));
+        assert_ne!(id, opportunity_id("fx-crypto-1", "https://www.coindesk.com/arc/outboundfeeds/rss/"));
+        assert_ne!(opportunity_id("ab", "c"), opportunity_id("a", "bc"), "séparateur marché/source");
+
+        let record = format_record("2026-01-10 14:00:00", "abc-2", &TraceFields::opportunity("fx-crypto-1", "src"), "Signal: BUY");
+        assert_eq!(record, format!("2026-01-10 14:00:00 - cycle=abc-2 market=fx-crypto-1 opp={} - Signal: BUY",
+                                   opportunity_id("fx-crypto-1", "src")));
+    }
+}
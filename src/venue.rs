@@ -0,0 +1,228 @@
+// Contraintes de la venue pour un marché, lues sur le CLOB (GET /markets/{condition_id})
+// Gamma ne les fournit pas toujours: sans elles, le bot suppose un tick de 0.01 et ne
+// connaît pas la taille minimale réelle d'un ordre.
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+// Tick supposé sans contraintes CLOB connues
+pub const DEFAULT_TICK_SIZE: f64 = 0.01;
+
+// Tolérance (en ticks) sous laquelle un prix est considéré déjà sur le tick
+const TICK_EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+impl OrderSide {
+    pub fn opposite(&self) -> OrderSide {
+        match self {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+        }
+    }
+}
+
+// Sens d'arrondi d'un prix au tick avant qu'il ne devienne un ordre
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PriceRounding {
+    #[default]
+    Marketable, // Achat arrondi au-dessus, vente au-dessous: l'ordre reste exécutable (défaut)
+    Passive,    // Achat arrondi au-dessous, vente au-dessus: jamais plus cher que le prix visé
+    Nearest,    // Tick le plus proche, quel que soit le sens
+}
+
+impl PriceRounding {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "marketable" => Ok(PriceRounding::Marketable),
+            "passive" => Ok(PriceRounding::Passive),
+            "nearest" => Ok(PriceRounding::Nearest),
+            _ => Err(format!("--price-rounding: mode inconnu '{}' (attendu: marketable, passive, nearest)", name)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceRounding::Marketable => "marketable",
+            PriceRounding::Passive => "passive",
+            PriceRounding::Nearest => "nearest",
+        }
+    }
+
+    // Prix aligné sur le tick dans le sens du mode, dans [tick, 1 - tick]
+    pub fn round(&self, price: f64, tick: f64, side: OrderSide) -> f64 {
+        match self {
+            PriceRounding::Marketable => round_price(price, tick, side),
+            PriceRounding::Passive => round_price(price, tick, side.opposite()),
+            PriceRounding::Nearest => ((price / tick).round() * tick).clamp(tick, 1.0 - tick),
+        }
+    }
+}
+
+// Arrondi conservateur pour le sens de l'ordre, dans [tick, 1 - tick]: au-dessus pour un achat
+// (l'ordre reste exécutable au ask), au-dessous pour une vente. Un prix déjà sur le tick est inchangé.
+pub fn round_price(price: f64, tick: f64, side: OrderSide) -> f64 {
+    let ticks = price / tick;
+    let rounded = match side {
+        OrderSide::Buy => (ticks - TICK_EPSILON).ceil(),
+        OrderSide::Sell => (ticks + TICK_EPSILON).floor(),
+    };
+    (rounded * tick).clamp(tick, 1.0 - tick)
+}
+
+// Prix d'ordre écrit avec autant de décimales que le tick (0.01 -> "0.65", 0.001 -> "0.644")
+pub fn format_price(price: f64, tick: f64) -> String {
+    let decimals = (-tick.log10() - TICK_EPSILON).ceil().max(0.0) as usize;
+    format!("{:.*}", decimals, price)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct VenueConstraints {
+    #[serde(default, alias = "negRisk")]
+    pub neg_risk: bool,
+    #[serde(alias = "minimum_tick_size", alias = "orderPriceMinTickSize", deserialize_with = "number_or_string")]
+    pub tick_size: f64,
+    // En tokens (shares), pas en USDC
+    #[serde(alias = "minimum_order_size", alias = "orderMinSize", deserialize_with = "number_or_string")]
+    pub min_order_size: f64,
+    #[serde(default = "accepting_by_default", alias = "acceptingOrders")]
+    pub accepting_orders: bool,
+    // Frais taker propres au marché, en points de base (None = barème global)
+    #[serde(default, alias = "taker_base_fee", alias = "takerBaseFee", deserialize_with = "optional_number_or_string")]
+    pub fee_bps: Option<f64>,
+}
+
+fn accepting_by_default() -> bool {
+    true
+}
+
+// Le CLOB renvoie certains nombres sous forme de chaînes ("0.01")
+fn number_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Number(n) => n.as_f64().ok_or_else(|| serde::de::Error::custom("nombre invalide")),
+        Value::String(s) => s.trim().parse().map_err(serde::de::Error::custom),
+        other => Err(serde::de::Error::custom(format!("nombre attendu, reçu {}", other))),
+    }
+}
+
+fn optional_number_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    number_or_string(deserializer).map(Some)
+}
+
+impl VenueConstraints {
+    pub fn from_json(market: &Value) -> Result<Self, String> {
+        let constraints = Self::deserialize(market).map_err(|e| format!("métadonnées de marché invalides: {}", e))?;
+        if constraints.tick_size.is_nan() || constraints.tick_size <= 0.0 || constraints.tick_size >= 1.0 {
+            return Err(format!("tick invalide: {}", constraints.tick_size));
+        }
+        if constraints.min_order_size.is_nan() || constraints.min_order_size < 0.0 {
+            return Err(format!("taille minimale invalide: {}", constraints.min_order_size));
+        }
+        if let Some(fee_bps) = constraints.fee_bps.filter(|bps| !(0.0..=10_000.0).contains(bps)) {
+            return Err(format!("frais invalides: {} bps", fee_bps));
+        }
+        Ok(constraints)
+    }
+
+    // Raison du refus d'un ordre de `shares` tokens, None s'il respecte les contraintes
+    pub fn reject_reason(&self, shares: f64) -> Option<String> {
+        if !self.accepting_orders {
+            Some("le marché n'accepte pas d'ordres".to_string())
+        } else if shares < self.min_order_size {
+            Some(format!("{:.2} tokens < minimum {:.2}", shares, self.min_order_size))
+        } else {
+            None
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let fee = self.fee_bps.map_or_else(|| "barème global".to_string(), |bps| format!("{} bps", bps));
+        format!("tick {} | ordre min {} tokens | neg risk {} | ordres {} | frais taker {}",
+                self.tick_size, self.min_order_size, if self.neg_risk { "oui" } else { "non" },
+                if self.accepting_orders { "acceptés" } else { "suspendus" }, fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_json(relative: &str) -> serde_json::Value {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(relative);
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("lecture de la fixture")).expect("fixture JSON")
+    }
+
+    #[test]
+    fn data_api_market_fixture_deserializes_into_venue_constraints() {
+        let constraints = VenueConstraints::from_json(&load_json("tests/fixtures/data_api_market.json"))
+            .expect("réponse CLOB valide");
+        assert_eq!(constraints, VenueConstraints {
+            neg_risk: true,
+            tick_size: 0.001,
+            min_order_size: 5.0,
+            accepting_orders: true,
+            fee_bps: Some(0.0),
+        });
+        assert!((round_price(0.6437, constraints.tick_size, OrderSide::Buy) - 0.644).abs() < 1e-12);
+        assert_eq!(constraints.reject_reason(4.9), Some("4.90 tokens < minimum 5.00".to_string()));
+        assert_eq!(constraints.reject_reason(5.0), None);
+    }
+
+    #[test]
+    fn gamma_field_names_and_defaults_are_accepted() {
+        let constraints = VenueConstraints::from_json(&serde_json::json!({
+            "orderPriceMinTickSize": 0.01,
+            "orderMinSize": "15",
+            "negRisk": false
+        })).expect("champs Gamma");
+        assert_eq!(constraints.tick_size, 0.01);
+        assert_eq!(constraints.min_order_size, 15.0);
+        assert!(constraints.accepting_orders, "ordres acceptés par défaut");
+        assert_eq!(constraints.fee_bps, None, "barème global sans frais propres");
+
+        let paused = VenueConstraints::from_json(&serde_json::json!({
+            "minimum_tick_size": 0.01, "minimum_order_size": 5, "accepting_orders": false
+        })).expect("marché suspendu");
+        assert_eq!(paused.reject_reason(100.0), Some("le marché n'accepte pas d'ordres".to_string()));
+    }
+
+    #[test]
+    fn order_prices_round_toward_the_marketable_side_of_the_tick() {
+        // Entre deux ticks: achat au-dessus (reste exécutable au ask), vente au-dessous
+        assert!((round_price(0.6437, 0.01, OrderSide::Buy) - 0.65).abs() < 1e-12);
+        assert!((round_price(0.6437, 0.01, OrderSide::Sell) - 0.64).abs() < 1e-12);
+        // Sur le tick, malgré l'erreur de représentation (0.1 + 0.54 = 0.6400000000000001): inchangé
+        for price in [0.64, 0.1 + 0.54, 0.65 - 1e-13] {
+            let expected = if price > 0.645 { 0.65 } else { 0.64 };
+            assert!((round_price(price, 0.01, OrderSide::Buy) - expected).abs() < 1e-12, "{}", price);
+            assert!((round_price(price, 0.01, OrderSide::Sell) - expected).abs() < 1e-12, "{}", price);
+        }
+        // Bornes: jamais 0 ni 1
+        assert!((round_price(0.999, 0.01, OrderSide::Buy) - 0.99).abs() < 1e-12);
+        assert!((round_price(0.004, 0.01, OrderSide::Sell) - 0.01).abs() < 1e-12);
+
+        // Modes configurables (--price-rounding)
+        assert_eq!(PriceRounding::default(), PriceRounding::Marketable);
+        let passive = PriceRounding::parse("passive").unwrap();
+        assert!((passive.round(0.6437, 0.01, OrderSide::Buy) - 0.64).abs() < 1e-12);
+        assert!((passive.round(0.6437, 0.01, OrderSide::Sell) - 0.65).abs() < 1e-12);
+        let nearest = PriceRounding::parse("Nearest").unwrap();
+        assert!((nearest.round(0.6437, 0.01, OrderSide::Sell) - 0.64).abs() < 1e-12);
+        assert!(PriceRounding::parse("up").is_err());
+
+        // Prix écrit au nombre de décimales du tick
+        assert_eq!(format_price(round_price(0.6437, 0.01, OrderSide::Buy), 0.01), "0.65");
+        assert_eq!(format_price(round_price(0.6437, 0.001, OrderSide::Sell), 0.001), "0.643");
+    }
+
+    #[test]
+    fn invalid_metadata_is_rejected() {
+        assert!(VenueConstraints::from_json(&serde_json::json!({ "minimum_order_size": 5 })).is_err());
+        assert!(VenueConstraints::from_json(&serde_json::json!({ "minimum_tick_size": 0, "minimum_order_size": 5 })).is_err());
+        assert!(VenueConstraints::from_json(&serde_json::json!({ "minimum_tick_size": "abc", "minimum_order_size": 5 })).is_err());
+        assert!(VenueConstraints::from_json(&serde_json::json!({ "minimum_tick_size": 0.01, "minimum_order_size": 5, "taker_base_fee": -1 })).is_err());
+    }
+}
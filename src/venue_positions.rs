@@ -0,0 +1,66 @@
+// Positions détenues à la venue (data-api Polymarket /positions), reprises au démarrage
+// Après un redémarrage en mode réel, le bot ne connaît plus les tokens qu'il détient: ils sont
+// relus depuis la venue pour que les règlements et le suivi des positions en tiennent compte
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+pub const DATA_API_POSITIONS: &str = "https://data-api.polymarket.com/positions";
+pub const POSITIONS_PAGE_SIZE: usize = 500; // Maximum accepté par la data-api
+
+// Position d'un wallet sur un token, telle que renvoyée par la venue
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct VenuePosition {
+    #[serde(alias = "conditionId")]
+    pub condition_id: String,
+    #[serde(default)]
+    pub asset: String, // Token CLOB détenu
+    #[serde(deserialize_with = "number_or_string")]
+    pub size: f64,     // Tokens détenus
+    #[serde(alias = "avgPrice", deserialize_with = "number_or_string")]
+    pub avg_price: f64, // Prix d'entrée moyen
+    #[serde(default)]
+    pub outcome: Option<String>,
+    #[serde(default, alias = "outcomeIndex")]
+    pub outcome_index: Option<u64>,
+}
+
+impl VenuePosition {
+    // Token détenu: premier token (YES) ou second (NO); l'index prime sur le libellé
+    pub fn holds_yes(&self) -> Result<bool, String> {
+        match (self.outcome_index, self.outcome.as_deref().map(|o| o.trim().to_lowercase())) {
+            (Some(0), _) => Ok(true),
+            (Some(1), _) => Ok(false),
+            (None, Some(outcome)) if outcome == "yes" => Ok(true),
+            (None, Some(outcome)) if outcome == "no" => Ok(false),
+            _ => Err(format!("issue inconnue pour {} ({:?}, index {:?})", self.condition_id, self.outcome, self.outcome_index)),
+        }
+    }
+}
+
+fn number_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Number(n) => n.as_f64().ok_or_else(|| serde::de::Error::custom("nombre invalide")),
+        Value::String(s) => s.trim().parse().map_err(serde::de::Error::custom),
+        other => Err(serde::de::Error::custom(format!("nombre attendu, reçu {}", other))),
+    }
+}
+
+// Page de positions: tableau JSON; chaque entrée invalide est rapportée sans rejeter les autres
+// Les positions vides (taille nulle, déjà rachetées) sont ignorées
+pub fn parse_positions(page: &Value) -> Result<Vec<Result<VenuePosition, String>>, String> {
+    let entries = page.as_array().ok_or_else(|| "réponse /positions: tableau attendu".to_string())?;
+    Ok(entries.iter()
+        .map(|entry| {
+            let position = VenuePosition::deserialize(entry).map_err(|e| format!("position invalide: {}", e))?;
+            if !position.size.is_finite() || position.size < 0.0 {
+                return Err(format!("taille invalide pour {}: {}", position.condition_id, position.size));
+            }
+            if !(position.avg_price >= 0.0 && position.avg_price <= 1.0) {
+                return Err(format!("prix d'entrée invalide pour {}: {}", position.condition_id, position.avg_price));
+            }
+            position.holds_yes()?;
+            Ok(position)
+        })
+        .filter(|position| !matches!(position, Ok(p) if p.size == 0.0))
+        .collect())
+}
@@ -0,0 +1,101 @@
+// Historique de prix avec volume optionnel, et volatilité pondérée par le volume
+// Un mouvement sur un volume minuscule est du bruit, un mouvement sur un gros volume
+// est un signal: chaque variation pèse le plus faible volume de ses deux points, de sorte
+// qu'un pic isolé sur un volume minuscule pèse peu, à l'aller comme au retour.
+// Sans aucun volume connu, toutes les variations pèsent autant (volatilité classique).
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PricePoint {
+    pub timestamp: f64,
+    pub price: f64,
+    pub volume: Option<f64>, // Volume échangé sur le point, si la source le fournit
+}
+
+impl PricePoint {
+    pub fn new(timestamp: f64, price: f64) -> Self {
+        Self { timestamp, price, volume: None }
+    }
+}
+
+// Vue (timestamp, prix) de l'historique, pour les calculs qui ignorent le volume
+pub fn price_series(history: &[PricePoint]) -> Vec<(f64, f64)> {
+    history.iter().map(|point| (point.timestamp, point.price)).collect()
+}
+
+// Écart-type pondéré des variations relatives successives, None avec moins de 2 points
+// Un point sans volume reçoit le volume moyen des points connus (poids neutre)
+pub fn weighted_volatility(history: &[PricePoint]) -> Option<f64> {
+    let known: Vec<f64> = history.iter().filter_map(|point| point.volume).filter(|v| *v > 0.0).collect();
+    let neutral = if known.is_empty() { 1.0 } else { known.iter().sum::<f64>() / known.len() as f64 };
+
+    let changes: Vec<(f64, f64)> = history.windows(2)
+        .filter(|pair| pair[0].price > 0.0)
+        .map(|pair| {
+            let change = (pair[1].price - pair[0].price).abs() / pair[0].price;
+            let volume = |point: &PricePoint| point.volume.filter(|v| *v >= 0.0).unwrap_or(neutral);
+            let weight = volume(&pair[0]).min(volume(&pair[1]));
+            (change, weight)
+        })
+        .collect();
+    let total_weight: f64 = changes.iter().map(|(_, weight)| weight).sum();
+    if changes.is_empty() || total_weight <= 0.0 {
+        return None;
+    }
+
+    let mean = changes.iter().map(|(change, weight)| change * weight).sum::<f64>() / total_weight;
+    let variance = changes.iter().map(|(change, weight)| weight * (change - mean).powi(2)).sum::<f64>() / total_weight;
+    Some(variance.sqrt())
+}
+
+// Volatilité pondérée par le volume: une variation sur un volume minuscule pèse moins qu'une
+// variation sur un gros volume, et sans volume la volatilité reste classique
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(timestamp: f64, price: f64, volume: Option<f64>) -> PricePoint {
+        PricePoint { timestamp, price, volume }
+    }
+
+    // Marché stable à 0.50 (variations de 1%) avec un pic à 0.60 puis retour
+    fn history_with_spike(spike_volume: f64) -> Vec<PricePoint> {
+        vec![
+            point(0.0, 0.500, Some(1000.0)),
+            point(1.0, 0.505, Some(1000.0)),
+            point(2.0, 0.500, Some(1000.0)),
+            point(3.0, 0.600, Some(spike_volume)),
+            point(4.0, 0.505, Some(1000.0)),
+            point(5.0, 0.500, Some(1000.0)),
+        ]
+    }
+
+    #[test]
+    fn low_volume_outlier_contributes_less_to_the_volatility() {
+        let thin_spike = weighted_volatility(&history_with_spike(5.0)).expect("historique suffisant");
+        let heavy_spike = weighted_volatility(&history_with_spike(1000.0)).expect("historique suffisant");
+        assert!(thin_spike < heavy_spike / 2.0, "pic sur faible volume {:.4} vs gros volume {:.4}", thin_spike, heavy_spike);
+    }
+
+    #[test]
+    fn without_volumes_every_move_weighs_the_same() {
+        let unweighted: Vec<PricePoint> = history_with_spike(5.0).iter()
+            .map(|p| point(p.timestamp, p.price, None))
+            .collect();
+        let same_volume = history_with_spike(1000.0);
+        let a = weighted_volatility(&unweighted).unwrap();
+        let b = weighted_volatility(&same_volume).unwrap();
+        assert!((a - b).abs() < 1e-12, "{} vs {}", a, b);
+
+        // Écart-type classique des variations relatives
+        let changes: Vec<f64> = unweighted.windows(2).map(|w| (w[1].price - w[0].price).abs() / w[0].price).collect();
+        let mean = changes.iter().sum::<f64>() / changes.len() as f64;
+        let std = (changes.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / changes.len() as f64).sqrt();
+        assert!((a - std).abs() < 1e-12, "{} vs {}", a, std);
+    }
+
+    #[test]
+    fn short_history_has_no_volatility() {
+        assert_eq!(weighted_volatility(&[]), None);
+        assert_eq!(weighted_volatility(&[PricePoint::new(0.0, 0.5)]), None);
+    }
+}
@@ -0,0 +1,252 @@
+// Maintenance des orderbooks à partir des messages du canal "market" du WebSocket CLOB,
+// et rejeu d'un flux enregistré pour reproduire la microstructure d'un marché hors-ligne
+//
+// Enregistrement: une ligne JSON par message reçu, {"t_ms": <ms depuis le début>, "msg": <message brut>}
+// - "book": snapshot complet d'un token (remplace le livre)
+// - "price_change": niveaux modifiés (taille 0 = niveau retiré), format "changes" ou "price_changes"
+use futures::{SinkExt, StreamExt};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::io::Write;
+use std::time::{Duration, Instant};
+use tokio_tungstenite::tungstenite::Message;
+
+// Canal "market" du WebSocket CLOB
+pub const CLOB_WS_MARKET: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+// Le serveur ferme une connexion muette: "PING" texte à intervalle régulier, réponse "PONG"
+const WS_PING_INTERVAL: Duration = Duration::from_secs(10);
+
+// Prix stockés en ticks entiers pour que les mises à jour retrouvent exactement leur niveau
+const PRICE_TICKS: f64 = 10_000.0;
+
+fn to_ticks(price: f64) -> u64 {
+    (price * PRICE_TICKS).round() as u64
+}
+
+fn from_ticks(ticks: u64) -> f64 {
+    ticks as f64 / PRICE_TICKS
+}
+
+// Le CLOB envoie prix et tailles sous forme de chaînes
+fn number(value: &Value) -> Option<f64> {
+    value.as_f64().or_else(|| value.as_str().and_then(|v| v.parse().ok()))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveBook {
+    bids: BTreeMap<u64, f64>, // ticks -> taille
+    asks: BTreeMap<u64, f64>,
+}
+
+impl LiveBook {
+    // Niveaux (prix, taille), meilleurs prix en premier
+    pub fn bids(&self) -> Vec<(f64, f64)> {
+        self.bids.iter().rev().map(|(ticks, size)| (from_ticks(*ticks), *size)).collect()
+    }
+
+    pub fn asks(&self) -> Vec<(f64, f64)> {
+        self.asks.iter().map(|(ticks, size)| (from_ticks(*ticks), *size)).collect()
+    }
+
+    fn replace(&mut self, bids: &Value, asks: &Value) {
+        self.bids = Self::levels(bids);
+        self.asks = Self::levels(asks);
+    }
+
+    fn levels(levels: &Value) -> BTreeMap<u64, f64> {
+        levels.as_array().map(|array| {
+            array.iter()
+                .filter_map(|level| Some((to_ticks(number(&level["price"])?), number(&level["size"])?)))
+                .filter(|(_, size)| *size > 0.0)
+                .collect()
+        }).unwrap_or_default()
+    }
+
+    // side: "BUY" met à jour les bids, "SELL" les asks
+    fn update(&mut self, side: &str, price: f64, size: f64) {
+        let levels = match side.to_uppercase().as_str() {
+            "BUY" => &mut self.bids,
+            "SELL" => &mut self.asks,
+            _ => return,
+        };
+        if size > 0.0 {
+            levels.insert(to_ticks(price), size);
+        } else {
+            levels.remove(&to_ticks(price));
+        }
+    }
+}
+
+// Livres maintenus par asset id
+#[derive(Debug, Default)]
+pub struct BookCache {
+    books: HashMap<String, LiveBook>,
+}
+
+impl BookCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn book(&self, asset_id: &str) -> Option<&LiveBook> {
+        self.books.get(asset_id)
+    }
+
+    pub fn asset_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.books.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    // Appliquer un message du canal market (objet ou tableau d'événements)
+    // Renvoie le nombre d'événements pris en compte
+    pub fn apply(&mut self, message: &Value) -> usize {
+        if let Some(events) = message.as_array() {
+            return events.iter().map(|event| self.apply(event)).sum();
+        }
+
+        match message["event_type"].as_str() {
+            Some("book") => {
+                let Some(asset_id) = message["asset_id"].as_str() else { return 0 };
+                // Les anciens messages nomment les côtés buys/sells
+                let bids = if message["bids"].is_null() { &message["buys"] } else { &message["bids"] };
+                let asks = if message["asks"].is_null() { &message["sells"] } else { &message["asks"] };
+                self.books.entry(asset_id.to_string()).or_default().replace(bids, asks);
+                1
+            }
+            Some("price_change") => {
+                let mut applied = 0;
+                // Format par asset: {"asset_id", "changes": [{price, side, size}]}
+                if let (Some(asset_id), Some(changes)) = (message["asset_id"].as_str(), message["changes"].as_array()) {
+                    let book = self.books.entry(asset_id.to_string()).or_default();
+                    for change in changes {
+                        if let (Some(price), Some(size), Some(side)) = (number(&change["price"]), number(&change["size"]), change["side"].as_str()) {
+                            book.update(side, price, size);
+                            applied += 1;
+                        }
+                    }
+                }
+                // Format multi-assets: {"price_changes": [{asset_id, price, side, size}]}
+                if let Some(changes) = message["price_changes"].as_array() {
+                    for change in changes {
+                        if let (Some(asset_id), Some(price), Some(size), Some(side)) =
+                            (change["asset_id"].as_str(), number(&change["price"]), number(&change["size"]), change["side"].as_str()) {
+                            self.books.entry(asset_id.to_string()).or_default().update(side, price, size);
+                            applied += 1;
+                        }
+                    }
+                }
+                applied
+            }
+            _ => 0,
+        }
+    }
+}
+
+// Message enregistré: instant de réception (ms depuis le début) et contenu brut
+#[derive(Debug, Clone)]
+pub struct RecordedMessage {
+    pub t_ms: u64,
+    pub message: Value,
+}
+
+// Lire un enregistrement JSON Lines; "msg" peut être le texte brut reçu ou le JSON déjà décodé
+pub fn load_recording(path: &str) -> Result<Vec<RecordedMessage>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Lecture de l'enregistrement WS {}: {}", path, e))?;
+    content.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let record: Value = serde_json::from_str(line)
+                .map_err(|e| format!("{}:{} invalide: {}", path, index + 1, e))?;
+            let message = match &record["msg"] {
+                Value::String(raw) => serde_json::from_str(raw)
+                    .map_err(|e| format!("{}:{} message brut invalide: {}", path, index + 1, e))?,
+                other => other.clone(),
+            };
+            Ok(RecordedMessage { t_ms: record["t_ms"].as_u64().unwrap_or(0), message })
+        })
+        .collect()
+}
+
+// Écriture d'un enregistrement au format relu par load_recording, trame texte brute dans "msg"
+pub struct Recorder<W: Write> {
+    out: W,
+    started: Instant,
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(out: W) -> Self {
+        Recorder { out, started: Instant::now() }
+    }
+
+    // Une ligne par message, écrite aussitôt: un enregistrement interrompu reste lisible
+    pub fn record(&mut self, raw: &str) -> std::io::Result<()> {
+        let t_ms = self.started.elapsed().as_millis() as u64;
+        writeln!(self.out, "{}", serde_json::json!({"t_ms": t_ms, "msg": raw}))?;
+        self.out.flush()
+    }
+}
+
+// Abonnement du canal market aux tokens donnés
+pub fn subscription(asset_ids: &[String]) -> Value {
+    serde_json::json!({"assets_ids": asset_ids, "type": "market"})
+}
+
+// Enregistrer le flux du canal market jusqu'à sa fermeture ou `stop`: chaque trame JSON est écrite
+// puis appliquée au cache (les "PONG" ne sont pas enregistrés). Renvoie le nombre de trames écrites
+pub async fn record<W: Write>(
+    url: &str,
+    asset_ids: &[String],
+    recorder: &mut Recorder<W>,
+    cache: &mut BookCache,
+    stop: impl Future<Output = ()>,
+) -> Result<usize, String> {
+    let (mut stream, _) = tokio_tungstenite::connect_async(url).await
+        .map_err(|e| format!("Connexion WS {}: {}", url, e))?;
+    stream.send(Message::Text(subscription(asset_ids).to_string())).await
+        .map_err(|e| format!("Abonnement WS {}: {}", url, e))?;
+
+    let mut ping = tokio::time::interval_at(tokio::time::Instant::now() + WS_PING_INTERVAL, WS_PING_INTERVAL);
+    let mut recorded = 0;
+    tokio::pin!(stop);
+    loop {
+        tokio::select! {
+            _ = &mut stop => break,
+            _ = ping.tick() => {
+                stream.send(Message::Text("PING".to_string())).await
+                    .map_err(|e| format!("Ping WS {}: {}", url, e))?;
+            }
+            frame = stream.next() => match frame {
+                Some(Ok(Message::Text(text))) => {
+                    let Ok(message) = serde_json::from_str::<Value>(&text) else { continue };
+                    recorder.record(&text).map_err(|e| format!("Écriture de l'enregistrement WS: {}", e))?;
+                    cache.apply(&message);
+                    recorded += 1;
+                }
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(format!("Flux WS {}: {}", url, e)),
+            },
+        }
+    }
+    Ok(recorded)
+}
+
+// Rejouer un enregistrement dans un cache de livres
+// speed: 1 = cadence enregistrée, 10 = dix fois plus vite, 0 = sans attente
+pub async fn replay(messages: &[RecordedMessage], speed: f64, cache: &mut BookCache) -> usize {
+    let mut applied = 0;
+    let mut previous_t_ms = messages.first().map(|m| m.t_ms).unwrap_or(0);
+    for recorded in messages {
+        if speed > 0.0 && recorded.t_ms > previous_t_ms {
+            let wait_ms = (recorded.t_ms - previous_t_ms) as f64 / speed;
+            tokio::time::sleep(Duration::from_secs_f64(wait_ms / 1000.0)).await;
+        }
+        previous_t_ms = previous_t_ms.max(recorded.t_ms);
+        applied += cache.apply(&recorded.message);
+    }
+    applied
+}
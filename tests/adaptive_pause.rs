@@ -0,0 +1,24 @@
+// Pause adaptative: la pause suivante dépend du nombre de signaux BUY/SELL du cycle
+// (réglage de la pause testé dans src/scheduler.rs); ici, ce que chaque fixture en produit
+mod common;
+
+// Signaux BUY/SELL du rapport d'un cycle de la fixture, bornes de pause par défaut
+fn trading_signals(fixture: &str) -> usize {
+    let name = std::path::Path::new(fixture).file_stem().unwrap().to_string_lossy().to_string();
+    let work_dir = common::WorkDir::new(&format!("pause-{}", name));
+    let output = common::fixture_run(&work_dir, fixture)
+        .env_remove("CYCLE_PAUSE_MIN_SECS")
+        .env_remove("CYCLE_PAUSE_MAX_SECS")
+        .run();
+    output.report().signals.iter().filter(|s| s.action == "BUY" || s.action == "SELL").count()
+}
+
+#[test]
+fn the_simulated_cycle_trades_and_shortens_the_next_interval() {
+    assert!(trading_signals("tests/fixtures/simulation_cycle.json") > 0, "la fixture doit produire des signaux BUY/SELL");
+}
+
+#[test]
+fn the_quiet_cycle_trades_nothing_and_lengthens_the_next_interval() {
+    assert_eq!(trading_signals("tests/fixtures/quiet_cycle.json"), 0, "la fixture ne doit produire aucun signal BUY/SELL");
+}
@@ -0,0 +1,94 @@
+// --cheapest-route: une vue baissière s'exprime en achetant NO au best ask du livre NO,
+// ou en vendant YES au best bid du livre YES (équivalent NO à 1 - bid), selon la voie
+// la moins chère. Sans l'option, le token de la vue est toujours acheté.
+mod common;
+
+use common::Signal;
+use std::path::Path;
+
+const MARKET: &str = "route-fed-raise";
+const NO_BEST_ASK: f64 = 0.64;
+
+// Marché de no_side_price (information négative), avec les bids YES donnés
+fn fixture(yes_bids: &[(f64, f64)]) -> serde_json::Value {
+    serde_json::json!({
+        "markets": [{
+            "id": MARKET,
+            "question": "Will the Fed raise rates at the next FOMC meeting?",
+            "description": "Resolves on the FOMC statement published on federalreserve.gov",
+            "domain": "economy",
+            "probability": 0.4,
+            "resolution_source": "federalreserve.gov",
+            "clob_token_ids": ["route-fed-yes", "route-fed-no"]
+        }],
+        "sources": {
+            "https://www.federalreserve.gov/feeds/press_all.xml": {
+                "content_type": "application/rss+xml",
+                "body": "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>Federal Reserve Press Releases</title><item><title>Federal Reserve issues FOMC statement</title><description>The Committee decided to cut the target range for the federal funds rate.</description></item></channel></rss>"
+            }
+        },
+        "orderbooks": {
+            "route-fed-yes": { "bids": yes_bids, "asks": [[0.71, 200.0], [0.72, 400.0]] },
+            "route-fed-no": { "bids": [[0.27, 150.0], [0.26, 300.0]], "asks": [[NO_BEST_ASK, 150.0], [0.66, 300.0]] }
+        }
+    })
+}
+
+// Un cycle sur la fixture, avec les options données en plus de --fixture/--seed/--once
+fn launch(work_dir: &Path, yes_bids: &[(f64, f64)], args: &[&str]) -> Vec<Signal> {
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture(yes_bids).to_string()).expect("écriture de la fixture");
+    common::run_fixture(work_dir, &fixture_path, args)
+}
+
+fn run_cycle(name: &str, yes_bids: &[(f64, f64)], cheapest_route: bool) -> Signal {
+    let work_dir = common::WorkDir::new(&format!("route-{}", name));
+    let args: &[&str] = if cheapest_route { &["--cheapest-route"] } else { &[] };
+    let signals = launch(&work_dir, yes_bids, args);
+    signals.iter().find(|signal| signal.market_id == MARKET).cloned()
+        .unwrap_or_else(|| panic!("aucun signal pour {}: {:?}", MARKET, signals))
+}
+
+#[test]
+fn bearish_view_routes_through_the_no_book_when_it_is_cheaper() {
+    // Vendre YES à 0.30 revient à payer NO 0.70: le livre NO (0.64) est meilleur
+    let signal = run_cycle("no-book", &[(0.30, 200.0), (0.29, 400.0)], true);
+    assert_eq!(signal.token_side, "NO", "{:?}", signal);
+    assert_eq!(signal.route.as_deref(), Some("buy"), "{:?}", signal);
+    assert!((signal.executable_price - NO_BEST_ASK).abs() < 1e-9, "{:?}", signal);
+}
+
+#[test]
+fn bearish_view_sells_yes_when_the_yes_bid_is_cheaper() {
+    // Vendre YES à 0.39 revient à payer NO 0.61 < 0.64
+    let signal = run_cycle("yes-book", &[(0.39, 200.0), (0.38, 400.0)], true);
+    assert_eq!(signal.token_side, "NO", "{:?}", signal);
+    assert_eq!(signal.route.as_deref(), Some("sell_opposite"), "{:?}", signal);
+    assert!((signal.executable_price - 0.61).abs() < 1e-9, "{:?}", signal);
+}
+
+#[test]
+fn without_the_option_the_view_token_is_bought() {
+    let signal = run_cycle("default", &[(0.39, 200.0), (0.38, 400.0)], false);
+    // Sortie par défaut inchangée: pas de champ route sans l'option
+    assert!(signal.route.is_none(), "{:?}", signal);
+    assert!((signal.executable_price - NO_BEST_ASK).abs() < 1e-9, "{:?}", signal);
+}
+
+#[test]
+fn the_order_intent_names_the_side_actually_traded() {
+    // Voie vente YES: l'intention émise vers le sink est un SELL du token YES au bid YES,
+    // identifiée par ce côté, pas par l'action de la vue (achat NO)
+    let work_dir = common::WorkDir::new("route-intent");
+    let intents_path = work_dir.join("intents.jsonl");
+    launch(&work_dir, &[(0.39, 200.0), (0.38, 400.0)], &["--cheapest-route", "--signal-sink", "file", "--signal-sink-path", intents_path.to_str().unwrap()]);
+
+    let intents: Vec<serde_json::Value> = std::fs::read_to_string(&intents_path).expect("lecture des intentions")
+        .lines().map(|line| serde_json::from_str(line).expect("intention JSON")).collect();
+    let intent = intents.iter().find(|intent| intent["market_id"] == MARKET)
+        .unwrap_or_else(|| panic!("aucune intention pour {}: {:?}", MARKET, intents));
+    assert_eq!((intent["side"].as_str(), intent["outcome"].as_str()), (Some("SELL"), Some("YES")), "{}", intent);
+    assert_eq!(intent["token_id"], "route-fed-yes", "{}", intent);
+    assert!((intent["limit_price"].as_f64().unwrap() - 0.39).abs() < 1e-9, "{}", intent);
+    assert!(intent["intent_id"].as_str().unwrap().starts_with(&format!("{}-SELL-", MARKET)), "{}", intent);
+}
@@ -0,0 +1,65 @@
+// Mode réel: le bot réagit aux erreurs du CLOB selon leur nature. Solde insuffisant ou page
+// HTML: plus aucun ordre du cycle; indisponibilité temporaire: le même ordre est reposté;
+// ordre invalide: abandonné, les suivants partent
+mod common;
+
+use common::clob_mock::{filled_response, real_bot, ClobMock};
+use polymarket_bot::CycleReport;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+async fn run_against(name: &str, clob: &ClobMock) -> CycleReport {
+    let work_dir = common::WorkDir::new(name);
+    common::isolate_env();
+    let mut bot = real_bot(&work_dir, clob).build().expect("configuration");
+    bot.run_cycle().await
+}
+
+// Nombre d'ordres que le cycle de la fixture poste quand tout est rempli
+async fn orders_in_cycle(name: &str) -> usize {
+    let clob = ClobMock::filling();
+    let report = run_against(&format!("{}-baseline", name), &clob).await;
+    assert!(report.trades >= 2, "la fixture doit produire au moins deux ordres: {}", report.trades);
+    report.trades
+}
+
+#[tokio::test]
+async fn insufficient_balance_stops_the_cycle() {
+    orders_in_cycle("clob-errors-balance").await;
+    let clob = ClobMock::start(|_, _| (400, r#"{"error":"not enough balance / allowance"}"#.to_string()));
+    let report = run_against("clob-errors-balance", &clob).await;
+    assert_eq!(clob.orders().len(), 1);
+    assert_eq!(report.trades, 0);
+}
+
+#[tokio::test]
+async fn blocked_html_responses_stop_the_cycle() {
+    let clob = ClobMock::start(|_, _| (403, "<html><body>Attention Required! | Cloudflare</body></html>".to_string()));
+    let report = run_against("clob-errors-blocked", &clob).await;
+    assert_eq!(clob.orders().len(), 1);
+    assert_eq!(report.trades, 0);
+}
+
+#[tokio::test]
+async fn temporarily_unavailable_orders_are_reposted() {
+    let expected = orders_in_cycle("clob-errors-retry").await;
+    // Première tentative refusée (503), les suivantes remplies
+    let attempts = AtomicUsize::new(0);
+    let clob = ClobMock::start(move |_, order| match attempts.fetch_add(1, Ordering::SeqCst) {
+        0 => (503, r#"{"error":"service temporarily unavailable"}"#.to_string()),
+        _ => (200, filled_response(order, 1.0).to_string()),
+    });
+    let report = run_against("clob-errors-retry", &clob).await;
+    let orders = clob.orders();
+    assert_eq!(report.trades, expected);
+    assert_eq!(orders.len(), expected + 1);
+    assert_eq!(orders[0]["order"]["tokenId"], orders[1]["order"]["tokenId"]);
+}
+
+#[tokio::test]
+async fn invalid_orders_are_skipped_and_the_next_ones_sent() {
+    let expected = orders_in_cycle("clob-errors-invalid").await;
+    let clob = ClobMock::start(|_, _| (400, r#"{"error":"invalid order: order size below minimum"}"#.to_string()));
+    let report = run_against("clob-errors-invalid", &clob).await;
+    assert_eq!(clob.orders().len(), expected);
+    assert_eq!(report.trades, 0);
+}
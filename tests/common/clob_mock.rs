@@ -0,0 +1,168 @@
+// Faux CLOB local pour les tests du mode réel: chaque POST est enregistré (chemin, corps JSON)
+// et reçoit la réponse choisie par le test; le bot y est branché par BotBuilder::clob_endpoint
+use polymarket_bot::{ApiCredentials, Bot, BotBuilder, Fixture};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+// Clé de test bien connue (compte #0 des nœuds de développement), jamais financée
+pub const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+pub const ADDRESS: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+pub const API_SECRET: &str = "c2hhZG93LXJlYWwtdGVzdC1zZWNyZXQ=";
+
+pub fn credentials() -> ApiCredentials {
+    ApiCredentials { key: "api-key".into(), secret: API_SECRET.into(), passphrase: "pass".into() }
+}
+
+// Solde du compte servi par défaut: SIMULATED_BALANCE par défaut
+pub const DEFAULT_BALANCE_USDC: f64 = 4000.0;
+
+// Réponse de GET /balance-allowance: solde USDC en unités à 6 décimales
+pub fn balance_response(usdc: f64) -> String {
+    serde_json::json!({"balance": format!("{:.0}", usdc * 1e6), "allowance": "0"}).to_string()
+}
+
+// Token CLOB numérique d'un token de la fixture du cycle simulé (fx-economy-1 n'en a pas)
+pub fn token_id(market_id: &str, yes: bool) -> &'static str {
+    match (market_id, yes) {
+        ("fx-politics-1", true) => "1101",
+        ("fx-politics-1", false) => "1102",
+        ("fx-crypto-1", true) => "1201",
+        ("fx-crypto-1", false) => "1202",
+        ("fx-economy-1", true) => "1301",
+        _ => "1302",
+    }
+}
+
+// Fixture du cycle simulé avec des tokens CLOB numériques, comme sur la venue: un ordre signé
+// porte un token id; fx-economy-1 reçoit ses tokens, son livre NO déduit du YES par parité
+pub fn write_signed_fixture(dir: &Path) -> PathBuf {
+    let mut fixture: Value = serde_json::from_str(&std::fs::read_to_string(super::manifest_path(super::FIXTURE)).unwrap()).unwrap();
+    let renamed = |token: &str| match token {
+        "fx-pol-yes" => "1101", "fx-pol-no" => "1102", "fx-eth-yes" => "1201", "fx-eth-no" => "1202", "fx-economy-1" => "1301", other => other,
+    }.to_string();
+    for market in fixture["markets"].as_array_mut().unwrap() {
+        let id = market["id"].as_str().unwrap().to_string();
+        market["clob_token_ids"] = serde_json::json!([token_id(&id, true), token_id(&id, false)]);
+    }
+    let mut books: serde_json::Map<String, Value> = fixture["orderbooks"].as_object().unwrap().iter()
+        .map(|(token, book)| (renamed(token), book.clone())).collect();
+    let mirror = |levels: &Value| Value::Array(levels.as_array().unwrap().iter()
+        .map(|level| serde_json::json!([((1.0 - level[0].as_f64().unwrap()) * 100.0).round() / 100.0, level[1]])).collect());
+    let economy = books["1301"].clone();
+    books.insert("1302".into(), serde_json::json!({"bids": mirror(&economy["asks"]), "asks": mirror(&economy["bids"])}));
+    fixture["orderbooks"] = Value::Object(books);
+    let path = dir.join("signed_fixture.json");
+    std::fs::write(&path, fixture.to_string()).unwrap();
+    path
+}
+
+// Bot du mode réel sur la fixture signée, ordres signés par la clé de test et postés à `clob`
+pub fn real_bot(work_dir: &Path, clob: &ClobMock) -> BotBuilder {
+    let fixture = Fixture::load(write_signed_fixture(work_dir).to_str().unwrap()).expect("fixture");
+    Bot::builder().work_dir(work_dir).fixture(fixture).seed(42)
+        .real_mode(true).clob_endpoint(clob.url())
+        .private_key(PRIVATE_KEY).api_credentials(credentials())
+}
+
+#[derive(Clone)]
+pub struct ClobMock {
+    url: String,
+    posts: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+impl ClobMock {
+    // `respond` reçoit le chemin et le corps de chaque POST, renvoie (statut, corps de réponse);
+    // GET /balance-allowance répond le capital par défaut de la simulation, les mises ne changent pas
+    pub fn start(respond: impl Fn(&str, &Value) -> (u16, String) + Send + 'static) -> Self {
+        Self::start_with_get(respond, |path| match path.starts_with("/balance-allowance?") {
+            true => (200, balance_response(DEFAULT_BALANCE_USDC)),
+            false => (404, r#"{"error":"not found"}"#.to_string()),
+        })
+    }
+
+    // Comme start, et `respond_get` reçoit le chemin (avec la requête) de chaque GET
+    pub fn start_with_get(respond: impl Fn(&str, &Value) -> (u16, String) + Send + 'static,
+                          respond_get: impl Fn(&str) -> (u16, String) + Send + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("port libre");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let posts = Arc::new(Mutex::new(Vec::new()));
+        let recorded = posts.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { return };
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap_or(0);
+                        }
+                    }
+                }
+                let mut body = vec![0u8; content_length];
+                let _ = reader.read_exact(&mut body);
+                let mut parts = request_line.split_whitespace();
+                let (method, path) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default().to_string());
+                let (status, response) = if method == "POST" {
+                    let body: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+                    let response = respond(&path, &body);
+                    recorded.lock().unwrap().push((path, body));
+                    response
+                } else if method == "GET" {
+                    respond_get(&path)
+                } else {
+                    (404, r#"{"error":"not found"}"#.to_string())
+                };
+                let head = format!("HTTP/1.1 {} MOCK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                                   status, response.len());
+                let mut stream = reader.into_inner();
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        ClobMock { url, posts }
+    }
+
+    // Chaque ordre entièrement rempli à son prix limite
+    pub fn filling() -> Self {
+        Self::start(|_, order| (200, filled_response(order, 1.0).to_string()))
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    // Corps des ordres postés, dans l'ordre d'arrivée
+    pub fn orders(&self) -> Vec<Value> {
+        self.posts.lock().unwrap().iter().map(|(_, body)| body.clone()).collect()
+    }
+}
+
+// Ordre signé tel que posté: (token, côté, tokens, prix limite), d'après les montants en 6 décimales
+pub fn order_terms(request: &Value) -> (String, String, f64, f64) {
+    let order = &request["order"];
+    let units = |key: &str| order[key].as_str().and_then(|v| v.parse::<u64>().ok()).map_or(f64::NAN, |v| v as f64 / 1e6);
+    let side = order["side"].as_str().unwrap_or_default().to_lowercase();
+    let (usdc, tokens) = if side == "buy" { (units("makerAmount"), units("takerAmount")) } else { (units("takerAmount"), units("makerAmount")) };
+    (order["tokenId"].as_str().unwrap_or_default().to_string(), side, tokens, usdc / tokens)
+}
+
+// Réponse d'ordre accepté: `fraction` des tokens remplie au prix limite (makingAmount / takingAmount)
+pub fn filled_response(order: &Value, fraction: f64) -> Value {
+    let (_, side, tokens, price) = order_terms(order);
+    let (tokens, usdc) = (tokens * fraction, tokens * fraction * price);
+    let (making, taking) = if side == "buy" { (usdc, tokens) } else { (tokens, usdc) };
+    serde_json::json!({"success": true, "orderID": "0xmock", "status": "matched",
+                       "makingAmount": format!("{:.6}", making), "takingAmount": format!("{:.6}", taking)})
+}
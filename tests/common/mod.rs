@@ -0,0 +1,266 @@
+// Préparation partagée des tests d'intégration: répertoire de travail isolé, variables
+// d'environnement neutralisées, lancement du binaire
+#![allow(dead_code)]
+
+pub mod clob_mock;
+
+use polymarket_bot::CycleReport;
+use serde::Deserialize;
+use std::io::Write;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::Once;
+
+pub const FIXTURE: &str = "tests/fixtures/simulation_cycle.json";
+
+// Variables du processus conservées pour le bot: toutes les autres (réglages numériques, clés
+// d'API, wallet, AWS_*...) sont retirées, de sorte qu'une variable ajoutée au bot ne puisse pas
+// fuir de la machine vers un cycle de fixture
+const KEPT_ENV_VARS: &[&str] = &["PATH", "HOME", "USER", "TMPDIR", "TMP", "TEMP", "SYSTEMROOT", "LANG", "LC_ALL", "LD_LIBRARY_PATH"];
+
+fn is_kept(var: &str) -> bool {
+    KEPT_ENV_VARS.contains(&var) || var.starts_with("CARGO") || var.starts_with("RUST")
+}
+
+// Chemin d'un fichier du dépôt (fixtures, golden)
+pub fn manifest_path(relative: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
+}
+
+// Répertoire temporaire propre à un test, supprimé à la fin du test (échec compris).
+// Hors de l'arbre du projet pour que le binaire ne charge pas un .env local.
+pub struct WorkDir(PathBuf);
+
+impl WorkDir {
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("polymarket-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).expect("création du répertoire de travail");
+        WorkDir(dir)
+    }
+}
+
+impl Deref for WorkDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for WorkDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+// Bot::builder().work_dir(&work_dir): journaux et fichiers du bot écrits dans le répertoire du test
+impl From<&WorkDir> for PathBuf {
+    fn from(dir: &WorkDir) -> PathBuf {
+        dir.0.clone()
+    }
+}
+
+impl Drop for WorkDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+// Environnement du processus de test neutralisé une seule fois, avant tout Bot construit en
+// bibliothèque: chaque test du fichier l'appelle en premier, aucun ne modifie l'environnement ensuite
+pub fn isolate_env() {
+    static ISOLATE: Once = Once::new();
+    ISOLATE.call_once(|| {
+        for (var, _) in std::env::vars_os() {
+            if !var.to_str().is_some_and(is_kept) {
+                std::env::remove_var(var);
+            }
+        }
+    });
+}
+
+// Binaire lancé dans le répertoire de travail, sans pause entre cycles ni entrée standard;
+// seules les variables conservées sont transmises, un test ajoute les siennes avec .env()
+pub fn bot_command(work_dir: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_polymarket-bot"));
+    command.env_clear();
+    for (var, value) in std::env::vars_os() {
+        if var.to_str().is_some_and(is_kept) {
+            command.env(var, value);
+        }
+    }
+    command
+        .current_dir(work_dir)
+        .env("CYCLE_PAUSE_MIN_SECS", "0")
+        .env("CYCLE_PAUSE_MAX_SECS", "0")
+        .stdin(Stdio::null());
+    command
+}
+
+// Signal tel qu'écrit par --signals-out (instantané stable, trié par marché puis source)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Signal {
+    pub market_id: String,
+    pub source: String,
+    pub action: String,
+    pub confidence: String,
+    pub relevance_score: f64,
+    pub information_value: bool,
+    pub information_strength: f64,
+    pub token_side: String,
+    pub reference_price: f64,
+    pub executable_price: f64,
+    pub spent_price: f64,
+    pub expected_roi: f64,
+    pub net_edge: f64,
+    pub stake_amount: f64,
+    pub filled_price: Option<f64>,
+    pub filled_size: f64,
+    pub slippage_bps: Option<f64>,
+    pub trigger_title: Option<String>,
+    pub trigger_published_at: Option<String>,
+    pub trigger_link: Option<String>,
+    pub news_age_secs: Option<f64>,
+    #[serde(default)]
+    pub route: Option<String>, // Seulement avec --cheapest-route
+}
+
+// Signaux d'un marché, dans l'ordre de l'instantané
+pub fn signals_for<'a>(signals: &'a [Signal], market_id: &str) -> Vec<&'a Signal> {
+    signals.iter().filter(|signal| signal.market_id == market_id).collect()
+}
+
+// Lancement du binaire avec --signals-out, et --once (avec --report-json) sauf si .cycles(n)
+// ou .unbounded() est donné: signaux et rapport sont relus pour le test
+pub struct BotRun {
+    command: Command,
+    work_dir: PathBuf,
+    limit: CycleLimit,
+    stdin: Option<String>,
+}
+
+enum CycleLimit {
+    Once,
+    Cycles(u64),
+    Unbounded,
+}
+
+pub fn bot_run(work_dir: &Path) -> BotRun {
+    BotRun { command: bot_command(work_dir), work_dir: work_dir.to_path_buf(), limit: CycleLimit::Once, stdin: None }
+}
+
+// Sur une fixture avec --seed 42; une fixture relative est lue depuis la racine du dépôt
+pub fn fixture_run(work_dir: &Path, fixture: impl AsRef<Path>) -> BotRun {
+    bot_run(work_dir).arg("--fixture").arg(manifest_path(&fixture.as_ref().to_string_lossy())).args(["--seed", "42"])
+}
+
+// Résultat d'un lancement: signaux du dernier cycle, rapport JSON (avec --once), stdout
+pub struct RunOutput {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub signals: Vec<Signal>,
+    pub report: Option<CycleReport>,
+}
+
+impl RunOutput {
+    pub fn report(&self) -> &CycleReport {
+        self.report.as_ref().unwrap_or_else(|| panic!("aucun rapport JSON (--once seulement):\n{}", self.stdout))
+    }
+
+    pub fn signals_for(&self, market_id: &str) -> Vec<&Signal> {
+        signals_for(&self.signals, market_id)
+    }
+}
+
+impl BotRun {
+    pub fn arg(mut self, arg: impl AsRef<std::ffi::OsStr>) -> Self {
+        self.command.arg(arg);
+        self
+    }
+
+    pub fn args<S: AsRef<std::ffi::OsStr>>(mut self, args: impl IntoIterator<Item = S>) -> Self {
+        self.command.args(args);
+        self
+    }
+
+    pub fn env(mut self, name: &str, value: impl AsRef<std::ffi::OsStr>) -> Self {
+        self.command.env(name, value);
+        self
+    }
+
+    pub fn env_remove(mut self, name: &str) -> Self {
+        self.command.env_remove(name);
+        self
+    }
+
+    pub fn cycles(mut self, cycles: u64) -> Self {
+        self.limit = CycleLimit::Cycles(cycles);
+        self
+    }
+
+    // Texte écrit sur l'entrée standard (tube, jamais un terminal) au lieu de l'entrée fermée
+    pub fn stdin(mut self, input: &str) -> Self {
+        self.stdin = Some(input.to_string());
+        self
+    }
+
+    // Ni --once ni --cycles: le bot doit s'arrêter de lui-même (--max-runtime...)
+    pub fn unbounded(mut self) -> Self {
+        self.limit = CycleLimit::Unbounded;
+        self
+    }
+
+    // Lancement sans vérifier le code de sortie
+    pub fn output(mut self) -> RunOutput {
+        let signals_path = signals_path(&self.work_dir);
+        let report_path = report_path(&self.work_dir);
+        let _ = std::fs::remove_file(&signals_path);
+        let _ = std::fs::remove_file(&report_path);
+        self.command.arg("--signals-out").arg(&signals_path);
+        match self.limit {
+            CycleLimit::Once => self.command.arg("--once").arg("--report-json").arg(&report_path),
+            CycleLimit::Cycles(cycles) => self.command.arg("--cycles").arg(cycles.to_string()),
+            CycleLimit::Unbounded => &mut self.command,
+        };
+        let output = match &self.stdin {
+            None => self.command.output().expect("lancement du bot"),
+            Some(input) => {
+                let mut child = self.command.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped())
+                    .spawn().expect("lancement du bot");
+                child.stdin.take().expect("stdin").write_all(input.as_bytes()).expect("écriture stdin");
+                child.wait_with_output().expect("fin du bot")
+            }
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let signals = std::fs::read_to_string(&signals_path).ok()
+            .map(|json| serde_json::from_str(&json).unwrap_or_else(|e| panic!("signaux JSON: {}\n{}", e, json)))
+            .unwrap_or_default();
+        let report = std::fs::read_to_string(&report_path).ok()
+            .map(|json| serde_json::from_str(&json).unwrap_or_else(|e| panic!("rapport JSON: {}\n{}", e, json)));
+        RunOutput { status: output.status, stdout, signals, report }
+    }
+
+    // Lancement qui doit réussir
+    pub fn run(self) -> RunOutput {
+        let output = self.output();
+        assert!(output.status.success(), "le cycle a échoué: {}\n{}", output.status, output.stdout);
+        output
+    }
+}
+
+// Fichier --signals-out d'un lancement, tel qu'écrit par le bot
+pub fn signals_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("signals.json")
+}
+
+// Fichier --report-json d'un lancement --once
+pub fn report_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("report.json")
+}
+
+// Un cycle de fixture réussi (--once) et ses signaux
+pub fn run_fixture(work_dir: &Path, fixture: impl AsRef<Path>, args: &[&str]) -> Vec<Signal> {
+    fixture_run(work_dir, fixture).args(args).run().signals
+}
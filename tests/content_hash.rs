@@ -0,0 +1,16 @@
+// Empreinte du contenu des sources (--hash-sources): un corps inchangé depuis le cycle précédent
+// n'est pas reclassé, sa classification reprise donne les mêmes décisions
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn reused_classifications_give_the_same_signals_on_the_next_cycle() {
+    let work_dir = common::WorkDir::new("content-hash");
+    let classified = common::fixture_run(&work_dir, FIXTURE).cycles(2).run();
+    let hashed = common::fixture_run(&work_dir, FIXTURE).cycles(2).arg("--hash-sources").run();
+
+    // Second cycle: le corps rejoué à l'identique n'est pas reclassé, les signaux sont inchangés
+    assert!(!hashed.signals.is_empty());
+    assert_eq!(hashed.signals, classified.signals);
+}
@@ -0,0 +1,28 @@
+// Comparaison de deux instantanés de cycle (--snapshot-dir, --diff-cycles)
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn cli_writes_snapshots_and_prints_their_diff() {
+    let work_dir = common::WorkDir::new("cycle-diff");
+    common::fixture_run(&work_dir, FIXTURE).cycles(2).args(["--snapshot-dir", "snapshots"]).run();
+    let load = |cycle: u64| -> serde_json::Value {
+        let path = work_dir.join(format!("snapshots/cycle-{}.json", cycle));
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("instantané {} absent: {}", path.display(), e)))
+            .expect("instantané JSON")
+    };
+    let first = load(1);
+    assert_eq!(first["markets"]["fx-crypto-1"], 0.35);
+    assert!(!first["signals"].as_object().unwrap().is_empty() && !first["opportunities"].as_array().unwrap().is_empty());
+
+    // Cycle 2 modifié à la main: un prix a bougé
+    let mut second = load(2);
+    second["markets"]["fx-economy-1"] = serde_json::json!(0.61);
+    std::fs::write(work_dir.join("snapshots/cycle-2.json"), serde_json::to_string_pretty(&second).unwrap()).unwrap();
+
+    // Le prix modifié est listé avant / après; aucun cycle lancé, donc aucun rapport
+    let diff = common::bot_run(&work_dir).args(["--diff-cycles", "1", "2", "--snapshot-dir", "snapshots"]).run();
+    assert!(diff.stdout.lines().any(|line| line.contains("fx-economy-1: 0.5800 -> 0.6100")), "{}", diff.stdout);
+    assert!(diff.report.is_none() && diff.signals.is_empty());
+}
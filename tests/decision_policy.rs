@@ -0,0 +1,19 @@
+// Politique de décision côté Rust: les seuils (ROI minimum, pertinence minimum) décident
+// entre BUY, SELL et MONITOR, et se règlent par DECISION_* sans toucher au core C++
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn configured_thresholds_drive_the_signals_of_a_cycle() {
+    let work_dir = common::WorkDir::new("decision");
+
+    // ROI minimum de 10000%: aucun signal de la fixture ne passe les seuils
+    let signals = common::fixture_run(&work_dir, FIXTURE)
+        .env("DECISION_BUY_MIN_ROI", "100")
+        .env("DECISION_SELL_MIN_ROI", "100")
+        .run()
+        .signals;
+    assert!(!signals.is_empty(), "la fixture produit des signaux");
+    assert!(signals.iter().all(|s| s.action == "MONITOR"), "{:#?}", signals);
+}
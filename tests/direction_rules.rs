@@ -0,0 +1,42 @@
+// --direction-rules: le sens de l'information (YES ou NO) vient de règles en données,
+// marché × source × mot-clé; les règles historiques restent le jeu par défaut
+mod common;
+
+use common::{Signal, FIXTURE};
+use std::path::Path;
+
+const MARKET: &str = "fx-politics-1";
+
+const NEWSAPI: &str = "https://newsapi.org/v2/everything?domains=whitehouse.gov,reuters.com,bbc.com&apiKey=";
+
+fn run_cycle(work_dir: &Path, rules: Option<&str>) -> Vec<Signal> {
+    let mut run = common::fixture_run(work_dir, FIXTURE);
+    if let Some(rules) = rules {
+        let rules_path = work_dir.join("rules.json");
+        std::fs::write(&rules_path, rules).expect("écriture des règles");
+        run = run.arg("--direction-rules").arg(&rules_path);
+    }
+    run.run().signals
+}
+
+fn newsapi_side(signals: &[Signal]) -> Option<&str> {
+    signals.iter().find(|s| s.market_id == MARKET && s.source == NEWSAPI).map(|s| s.token_side.as_str())
+}
+
+#[test]
+fn a_custom_rule_flips_the_direction_of_a_matching_source() {
+    let work_dir = common::WorkDir::new("direction-rules");
+    let builtin = run_cycle(&work_dir, None);
+    let flipped = run_cycle(&work_dir, Some(r#"[
+        {"market_pattern": "fx-politics", "source_pattern": "newsapi", "keyword": "campaign", "direction": "negative", "weight": 2.0}
+    ]"#));
+    // Mot-clé absent de la source: la règle ne s'applique pas
+    let unmatched = run_cycle(&work_dir, Some(r#"[
+        {"market_pattern": "fx-politics", "source_pattern": "newsapi", "keyword": "impeachment", "direction": "negative"}
+    ]"#));
+
+    // Règles intégrées: aucune ne vise ce marché, les mots-clés affirmés donnent YES
+    assert_eq!(newsapi_side(&builtin), Some("YES"), "{:?}", builtin);
+    assert_eq!(newsapi_side(&flipped), Some("NO"), "{:?}", flipped);
+    assert_eq!(newsapi_side(&unmatched), Some("YES"), "{:?}", unmatched);
+}
@@ -0,0 +1,34 @@
+// Seuils de pertinence par domaine (DOMAIN_RELEVANCE_THRESHOLDS)
+mod common;
+
+use common::{RunOutput, FIXTURE};
+
+// Un cycle --once: signaux et rapport JSON
+fn run_cycle(work_dir: &std::path::Path, domain_thresholds: Option<&str>) -> RunOutput {
+    let mut run = common::fixture_run(work_dir, FIXTURE);
+    if let Some(config) = domain_thresholds {
+        run = run.env("DOMAIN_RELEVANCE_THRESHOLDS", config);
+    }
+    run.run()
+}
+
+#[test]
+fn raising_the_crypto_threshold_only_suppresses_crypto_opportunities() {
+    let work_dir = common::WorkDir::new("domain-relevance");
+
+    let default = run_cycle(&work_dir, None);
+    let strict = run_cycle(&work_dir, Some("crypto=0.95"));
+
+    // Seuils par défaut: le marché crypto passe
+    assert!(!default.signals_for("fx-crypto-1").is_empty(), "signal crypto attendu avec les seuils par défaut");
+    assert!(!default.report().skipped.markets.contains_key("fx-crypto-1"));
+
+    // Seuil crypto relevé: plus d'opportunité crypto, marché compté sous le seuil
+    assert!(strict.signals_for("fx-crypto-1").is_empty(), "{:?}", strict.signals);
+    assert_eq!(strict.report().skipped.markets["fx-crypto-1"], "below_threshold");
+    assert!(strict.report().opportunities < default.report().opportunities);
+
+    // Le marché économie n'est pas concerné
+    assert!(!default.signals_for("fx-economy-1").is_empty());
+    assert_eq!(strict.signals_for("fx-economy-1"), default.signals_for("fx-economy-1"));
+}
@@ -0,0 +1,48 @@
+// --dump-sources: après chaque cycle, un fichier JSON par source surveillée avec ce qu'elle a
+// renvoyé et les éléments où des mots-clés ont été reconnus
+mod common;
+
+use common::FIXTURE;
+
+const SEC_SOURCE: &str = "https://www.sec.gov/news/pressreleases.rss";
+
+#[test]
+fn every_monitored_source_is_dumped_each_cycle() {
+    let work_dir = common::WorkDir::new("dump");
+    let dump_dir = work_dir.join("sources");
+
+    common::fixture_run(&work_dir, FIXTURE).cycles(2).arg("--dump-sources").arg(&dump_dir).run();
+
+    let fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    let sources: Vec<&String> = fixture["sources"].as_object().expect("sources de la fixture").keys().collect();
+
+    // Un fichier JSON par source et par cycle, sous <dump-dir>/cycle-<n>
+    let records = |cycle: u64| -> Vec<serde_json::Value> {
+        let cycle_dir = dump_dir.join(format!("cycle-{}", cycle));
+        std::fs::read_dir(&cycle_dir).unwrap_or_else(|e| panic!("{} absent: {}", cycle_dir.display(), e))
+            .map(|entry| serde_json::from_str(&std::fs::read_to_string(entry.unwrap().path()).unwrap()).expect("enregistrement JSON"))
+            .collect()
+    };
+    for cycle in 1..=2u64 {
+        let records = records(cycle);
+        assert_eq!(records.len(), sources.len(), "{:?}", records);
+        for url in &sources {
+            let record = records.iter().find(|r| r["url"] == url.as_str()).unwrap_or_else(|| panic!("{} absent du cycle {}", url, cycle));
+            assert_eq!(record["cycle"], cycle);
+            for field in ["status", "content_length", "fetch_duration_secs", "found_keywords", "matched_items", "items_total"] {
+                assert!(!record[field].is_null(), "champ {} manquant pour {}", field, url);
+            }
+        }
+    }
+
+    // Source SEC: l'élément annonçant l'approbation est listé avec ses mots-clés et leur sentiment
+    let sec = records(1).into_iter().find(|r| r["url"] == SEC_SOURCE).expect("source SEC");
+    assert_eq!(sec["status"], "success");
+    assert!(sec["content_length"].as_u64().unwrap() > 0);
+    let item = &sec["matched_items"][0];
+    assert_eq!(item["title"], "SEC approves spot Ethereum ETF applications");
+    assert!(item["keywords"].as_array().unwrap().iter()
+        .any(|found| found["keyword"] == "etf" && found["sentiment"] == "affirmed"), "mots-clés de l'élément: {}", item);
+}
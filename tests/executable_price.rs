@@ -0,0 +1,37 @@
+// Prix de référence (Gamma: dernier trade / midpoint) et prix exécutable (CLOB) séparés sur le
+// signal: l'achat d'un token se fait à son best ask, le ROI est calculé sur ce prix
+mod common;
+
+use polymarket_bot::{Bot, Fixture};
+
+async fn signal(name: &str, fixture: &str, market: &str) -> serde_json::Value {
+    let work_dir = common::WorkDir::new(name);
+    common::isolate_env();
+    let fixture = Fixture::load(common::manifest_path(fixture).to_str().unwrap()).expect("fixture");
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(fixture).seed(42).build().expect("configuration");
+    bot.run_cycle().await;
+    let signals = bot.signals_snapshot();
+    signals.as_array().unwrap().iter()
+        .find(|signal| signal["market_id"] == market && signal["action"] != "MONITOR").cloned()
+        .unwrap_or_else(|| panic!("aucun signal pour {}: {}", market, signals))
+}
+
+#[tokio::test]
+async fn yes_buy_uses_the_yes_ask_not_the_gamma_price() {
+    // fx-crypto-1: Gamma 0.35, livre YES 0.34 / 0.36
+    let signal = signal("executable-yes", common::FIXTURE, "fx-crypto-1").await;
+    assert_eq!(signal["token_side"], "YES", "{}", signal);
+    assert_ne!(signal["reference_price"], signal["executable_price"], "{}", signal);
+    assert_eq!(signal["executable_price"], 0.36, "{}", signal);
+    assert_eq!(signal["spent_price"], 0.36, "ROI sur le prix payé au livre: {}", signal);
+}
+
+#[tokio::test]
+async fn no_buy_uses_the_no_token_ask() {
+    // ns-fed-raise: Gamma YES 0.40 (NO 0.60 en référence), livre NO 0.61 / 0.64
+    let signal = signal("executable-no", "tests/fixtures/no_side_price.json", "ns-fed-raise").await;
+    assert_eq!(signal["token_side"], "NO", "{}", signal);
+    assert_eq!(signal["executable_price"], 0.64, "{}", signal);
+    assert_ne!(signal["reference_price"], signal["executable_price"], "{}", signal);
+    assert_eq!(signal["spent_price"], 0.64, "{}", signal);
+}
@@ -0,0 +1,53 @@
+// Livre de repli: centré sur la probabilité du marché en simulation, jamais fabriqué en mode réel
+mod common;
+
+use common::FIXTURE;
+use polymarket_bot::{Bot, Fixture};
+use std::path::Path;
+
+const UNBOOKED: &str = "fx-crypto-1"; // Livres fx-eth-yes / fx-eth-no retirés de la fixture
+const HALF_SPREAD: f64 = 0.01; // Demi-spread du livre de repli
+
+// (prix de référence, prix exécutable) des signaux d'un marché
+fn signal_prices(snapshot: &serde_json::Value, market_id: &str) -> Vec<(f64, f64)> {
+    snapshot.as_array().unwrap().iter()
+        .filter(|s| s["market_id"] == market_id)
+        .map(|s| (s["reference_price"].as_f64().unwrap(), s["executable_price"].as_f64().unwrap()))
+        .collect()
+}
+
+#[tokio::test]
+async fn simulation_trades_the_fallback_book_and_real_mode_skips() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let work_dir = common::WorkDir::new("fallback-book");
+    common::isolate_env();
+
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(manifest_dir.join(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    let books = fixture["orderbooks"].as_object_mut().unwrap();
+    books.remove("fx-eth-yes");
+    books.remove("fx-eth-no");
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    let run = |real_mode: bool| {
+        let fixture = Fixture::load(fixture_path.to_str().unwrap()).expect("fixture");
+        Bot::builder().work_dir(&work_dir).fixture(fixture).seed(42).real_mode(real_mode).build().expect("configuration")
+    };
+    let mut simulation = run(false);
+    simulation.run_cycle().await;
+    let mut real = run(true);
+    real.run_cycle().await;
+
+    // Simulation: acheté au ask du livre de repli, un demi-spread au-dessus du prix de référence
+    let simulated = signal_prices(&simulation.signals_snapshot(), UNBOOKED);
+    assert_eq!(simulated.len(), 1);
+    let (reference_price, executable_price) = simulated[0];
+    assert!((executable_price - (reference_price + HALF_SPREAD)).abs() < 1e-6, "{:?}", simulated);
+
+    // Mode réel: marché ignoré, les marchés avec livre gardent leurs signaux
+    let real_signals = real.signals_snapshot();
+    assert!(signal_prices(&real_signals, UNBOOKED).is_empty(), "{}", real_signals);
+    assert_eq!(signal_prices(&real_signals, "fx-economy-1").len(), 1, "{}", real_signals);
+}
@@ -0,0 +1,41 @@
+// Barème --fee-tier: à ROI brut identique (même fixture, même graine),
+// le barème sans frais donne un ROI net supérieur au barème standard
+mod common;
+
+use common::FIXTURE;
+use std::collections::HashMap;
+
+// ROI attendu par (marché, source) pour un cycle avec le barème donné
+fn expected_rois(fee_tier: &str) -> HashMap<(String, String), f64> {
+    let work_dir = common::WorkDir::new(&format!("fee-{}", fee_tier.replace(':', "_")));
+    common::run_fixture(&work_dir, FIXTURE, &["--fee-tier", fee_tier]).into_iter()
+        .map(|s| ((s.market_id, s.source), s.expected_roi))
+        .collect()
+}
+
+#[test]
+fn zero_tier_yields_higher_net_roi_than_standard() {
+    let zero = expected_rois("zero");
+    let standard = expected_rois("standard");
+    assert!(!zero.is_empty(), "la fixture doit produire des signaux");
+    assert_eq!(zero.len(), standard.len(), "mêmes signaux attendus quel que soit le barème");
+
+    for (key, zero_roi) in &zero {
+        let standard_roi = standard.get(key).unwrap_or_else(|| panic!("signal {:?} absent avec le barème standard", key));
+        assert!(*standard_roi > 0.0, "ROI brut positif attendu pour {:?}", key);
+        assert!(zero_roi > standard_roi,
+                "{:?}: ROI net sans frais ({}) doit dépasser le ROI net standard ({})", key, zero_roi, standard_roi);
+    }
+}
+
+#[test]
+fn custom_tier_is_parsed_and_invalid_tier_rejected() {
+    let custom = expected_rois("custom:0:200");
+    let standard = expected_rois("standard");
+    assert_eq!(custom, standard, "custom:0:200 doit être équivalent au barème standard");
+
+    // Barème inconnu refusé à la lecture des options: aucun cycle lancé
+    let work_dir = common::WorkDir::new("fee-vip");
+    let refused = common::fixture_run(&work_dir, FIXTURE).args(["--fee-tier", "vip"]).output();
+    assert!(refused.report.is_none() && refused.signals.is_empty(), "un barème inconnu doit être refusé:\n{}", refused.stdout);
+}
@@ -0,0 +1,41 @@
+// Fair value des marchés de taux de la Fed à partir des observations FRED (FEDFUNDS)
+mod common;
+
+use common::FIXTURE;
+
+const FRED_SOURCE: &str = "https://api.stlouisfed.org/fred/series/observations?series_id=FEDFUNDS&api_key=";
+
+// Réponse FRED: observations non triées, une valeur manquante
+fn fred_body(values: &[(&str, &str)]) -> String {
+    let observations: Vec<serde_json::Value> = values.iter()
+        .map(|(date, value)| serde_json::json!({"realtime_start": "2024-07-01", "date": date, "value": value}))
+        .collect();
+    serde_json::json!({"units": "lin", "count": observations.len(), "observations": observations}).to_string()
+}
+
+fn economy_reference_price(work_dir: &std::path::Path, fred_values: &[(&str, &str)]) -> f64 {
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    fixture["sources"][FRED_SOURCE]["body"] = serde_json::Value::String(fred_body(fred_values));
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    // Le prix de référence du signal économie suit la fair value FRED
+    common::run_fixture(work_dir, &fixture_path, &[]).into_iter()
+        .find(|s| s.market_id == "fx-economy-1")
+        .map(|s| s.reference_price)
+        .expect("signal fx-economy-1 attendu")
+}
+
+#[test]
+fn a_fred_cut_raises_the_cut_market_fair_value_in_a_cycle() {
+    let work_dir = common::WorkDir::new("fred");
+
+    let flat = economy_reference_price(&work_dir, &[("2024-05-01", "5.33"), ("2024-06-01", "5.33")]);
+    let cut = economy_reference_price(&work_dir, &[("2024-05-01", "5.33"), ("2024-06-01", "5.08")]);
+
+    // Cote du marché dans la fixture: 0.58
+    assert!((flat - 0.58).abs() < 1e-9, "taux stable: cote du marché ({})", flat);
+    assert!(cut > flat, "baisse de 25 pb: fair value du marché 'cut' relevée ({} vs {})", cut, flat);
+}
@@ -0,0 +1,22 @@
+// Ordre de financement des signaux: edge net décroissant, puis id de marché; à capital limité,
+// le meilleur edge est exécuté, quel que soit l'ordre de génération des signaux
+mod common;
+
+use common::clob_mock::{order_terms, real_bot, token_id, ClobMock};
+
+#[tokio::test]
+async fn fixture_signals_are_sent_in_net_edge_order() {
+    let work_dir = common::WorkDir::new("funding-order");
+    common::isolate_env();
+    let clob = ClobMock::filling();
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+    let report = bot.run_cycle().await;
+
+    // Ordre attendu: signaux tradés par edge net décroissant
+    let mut expected: Vec<_> = report.signals.iter().filter(|s| s.filled_price.is_some()).collect();
+    expected.sort_by(|a, b| b.net_edge.total_cmp(&a.net_edge).then_with(|| a.market_id.cmp(&b.market_id)));
+    let expected: Vec<&str> = expected.iter().map(|s| token_id(&s.market_id, s.token_side == "YES")).collect();
+    let sent: Vec<String> = clob.orders().iter().map(|order| order_terms(order).0).collect();
+    assert!(expected.len() >= 2, "{:?}", report.signals);
+    assert_eq!(sent, expected, "{:?}", report.signals);
+}
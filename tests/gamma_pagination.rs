@@ -0,0 +1,109 @@
+// Pagination de la liste Gamma: les pages sont lues par limit/offset jusqu'à une page vide
+// ou incomplète, ou jusqu'au plafond de marchés; un marché répété d'une page à l'autre est gardé une fois
+mod common;
+
+use polymarket_bot::Bot;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+
+// Marché Gamma ouvert, tel que /markets le renvoie; createdAt récent pour les ids "new-*"
+fn gamma_market(id: &str) -> serde_json::Value {
+    let created_at = if id.starts_with("new-") { chrono::Utc::now().to_rfc3339() } else { "2024-01-01T00:00:00.000Z".to_string() };
+    serde_json::json!({"id": id, "question": format!("Will the Fed cut rates ({})?", id),
+                       "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "[\"0.4\", \"0.6\"]",
+                       "active": true, "closed": false, "createdAt": created_at, "liquidity": "1000"})
+}
+
+// Sert la liste des marchés, découpée selon limit/offset; renvoie l'URL et les lignes de requête reçues
+fn serve_markets(markets: Vec<serde_json::Value>, requests: usize) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("port libre");
+    let url = format!("http://{}/markets", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for _ in 0..requests {
+            let Ok((mut stream, _)) = listener.accept() else { return };
+            let mut request = [0u8; 4096];
+            let read = stream.read(&mut request).unwrap_or(0);
+            let request_line = String::from_utf8_lossy(&request[..read]).lines().next().unwrap_or_default().to_string();
+            let param = |name: &str| request_line.split(['?', '&', ' '])
+                .find_map(|pair| pair.strip_prefix(&format!("{}=", name)))
+                .and_then(|value| value.parse::<usize>().ok())
+                .unwrap_or(0);
+            let (limit, offset) = (param("limit"), param("offset"));
+            let page: Vec<_> = markets.iter().skip(offset).take(limit).cloned().collect();
+            let _ = sender.send(request_line);
+            let body = serde_json::Value::Array(page).to_string();
+            let head = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len());
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(body.as_bytes());
+        }
+    });
+    (url, receiver)
+}
+
+#[tokio::test]
+async fn markets_past_the_first_page_are_fetched_and_deduped() {
+    let work_dir = common::WorkDir::new("gamma-pagination");
+
+    // Cinq marchés sur trois pages de deux; "m-2" réapparaît en page 2 (liste décalée entre deux requêtes)
+    let listing = vec![
+        gamma_market("m-1"), gamma_market("m-2"),
+        gamma_market("m-2"), gamma_market("new-3"),
+        gamma_market("new-4"),
+    ];
+    let (url, requests) = serve_markets(listing.clone(), 3);
+    let mut bot = Bot::builder().work_dir(&work_dir).real_mode(true).markets_endpoint(&url).build().expect("configuration");
+    let added = bot.fetch_markets_paginated(2, 100).await;
+    let request_lines: Vec<String> = requests.try_iter().collect();
+
+    // Page incomplète (1 marché sur 2): dernière page, sans requête supplémentaire
+    assert_eq!(request_lines.len(), 3, "{:?}", request_lines);
+    for (line, offset) in request_lines.iter().zip([0, 2, 4]) {
+        assert!(line.starts_with("GET /markets?") && line.contains("limit=2") && line.contains(&format!("offset={}", offset)), "{}", line);
+    }
+    assert_eq!(added, 4);
+    let ids: Vec<&str> = bot.markets().iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, vec!["m-1", "m-2", "new-3", "new-4"]);
+    // Ancienneté et domaine calculés pour chaque marché, quelle que soit sa page
+    let new_ids: Vec<&str> = bot.markets().iter().filter(|m| m.is_new).map(|m| m.id.as_str()).collect();
+    assert_eq!(new_ids, vec!["new-3", "new-4"]);
+    assert!(bot.markets().iter().all(|m| m.domain == bot.markets()[0].domain && !m.domain.is_empty()));
+
+    // Plafond de marchés: la dernière page est réduite au reste, les marchés déjà connus ne sont pas doublés
+    let (url, requests) = serve_markets(listing, 2);
+    let mut capped = Bot::builder().work_dir(&work_dir).real_mode(true).markets_endpoint(&url).build().expect("configuration");
+    assert_eq!(capped.fetch_markets_paginated(2, 3).await, 2);
+    let request_lines: Vec<String> = requests.try_iter().collect();
+    assert_eq!(request_lines.len(), 2, "{:?}", request_lines);
+    assert!(request_lines[1].contains("limit=1") && request_lines[1].contains("offset=2"), "{}", request_lines[1]);
+    assert_eq!(capped.markets().iter().map(|m| m.id.as_str()).collect::<Vec<_>>(), vec!["m-1", "m-2"]);
+}
+
+#[tokio::test]
+async fn a_real_gamma_page_keeps_active_open_markets_with_their_gamma_fields() {
+    let work_dir = common::WorkDir::new("gamma-real-shape");
+    common::isolate_env();
+
+    // Réponse au format réel de Gamma: active / closed, createdAt, resolutionSource, listes encodées
+    let listing: Vec<serde_json::Value> = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path("tests/fixtures/gamma_markets_array.json")).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    let mut inactive = listing[1].clone();
+    inactive["id"] = serde_json::json!("gm-inactive");
+    inactive["active"] = serde_json::json!(false);
+    let mut markets = listing.clone();
+    markets.push(inactive);
+    let (url, _) = serve_markets(markets, 1);
+    let mut bot = Bot::builder().work_dir(&work_dir).real_mode(true).markets_endpoint(&url).build().expect("configuration");
+
+    // Marché fermé (gm-cpi-dec) et marché inactif écartés, les autres gardés
+    assert_eq!(bot.fetch_markets_paginated(10, 100).await, 2);
+    let fed = &bot.markets()[0];
+    assert_eq!(bot.markets().iter().map(|m| m.id.as_str()).collect::<Vec<_>>(), vec!["gm-fed-cut", "gm-eth-etf"]);
+    assert_eq!(fed.resolution_source, "https://www.federalreserve.gov");
+    assert_eq!(fed.created_at, "2025-01-10T12:00:00+00:00");
+    assert!(!fed.is_new);
+    assert_eq!((fed.probability, fed.domain.as_str()), (0.42, "economy"));
+    assert_eq!(fed.outcomes.iter().map(|o| o.token_id.as_str()).collect::<Vec<_>>(), vec!["gm-fed-yes", "gm-fed-no"]);
+}
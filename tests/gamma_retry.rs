@@ -0,0 +1,52 @@
+// Récupération des marchés Gamma réessayée: une page en 503 puis en 429 (Retry-After) est
+// redemandée jusqu'à la réponse valide, au lieu de perdre le cycle
+mod common;
+
+use polymarket_bot::Bot;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+
+// Répond dans l'ordre avec chaque (statut, en-têtes, corps); renvoie l'URL et les instants de requête
+fn serve(responses: Vec<(&'static str, &'static str, String)>) -> (String, mpsc::Receiver<std::time::Instant>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("port libre");
+    let url = format!("http://{}/markets", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for (status, headers, body) in responses {
+            let Ok((mut stream, _)) = listener.accept() else { return };
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+            let _ = sender.send(std::time::Instant::now());
+            let head = format!("HTTP/1.1 {}\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                               status, headers, body.len());
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(body.as_bytes());
+        }
+    });
+    (url, receiver)
+}
+
+#[tokio::test]
+async fn a_market_page_is_retried_through_5xx_and_rate_limits() {
+    let work_dir = common::WorkDir::new("gamma-retry");
+    common::isolate_env();
+
+    let page = serde_json::json!([{"id": "m-1", "question": "Will the Fed cut rates?",
+                                   "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "[\"0.4\", \"0.6\"]",
+                                   "active": true, "closed": false, "createdAt": "2024-01-01T00:00:00.000Z", "liquidity": "1000"}]);
+    let (url, requests) = serve(vec![
+        ("503 Service Unavailable", "", String::new()),
+        ("429 Too Many Requests", "Retry-After: 1\r\n", String::new()),
+        ("200 OK", "", page.to_string()),
+    ]);
+    let mut bot = Bot::builder().work_dir(&work_dir).real_mode(true).markets_endpoint(&url).build().expect("configuration");
+    let added = bot.fetch_markets_paginated(10, 100).await;
+    let instants: Vec<_> = requests.try_iter().collect();
+
+    assert_eq!(instants.len(), 3, "une requête par tentative");
+    assert_eq!(added, 1);
+    assert_eq!(bot.markets()[0].id, "m-1");
+    // Le 429 a attendu sa seconde de Retry-After, pas le délai exponentiel de 400ms
+    assert!(instants[2] - instants[1] >= std::time::Duration::from_millis(950), "{:?}", instants[2] - instants[1]);
+}
@@ -0,0 +1,35 @@
+// Test de non-régression: un cycle de simulation complet rejoué depuis une fixture
+// (marchés, sources, orderbooks) avec une graine fixe, comparé au fichier golden.
+//
+// Régénérer le golden après un changement de comportement voulu:
+//     cargo test --test golden_simulation -- --bless
+mod common;
+
+use common::FIXTURE;
+
+const GOLDEN: &str = "tests/golden/simulation_signals.json";
+
+fn main() {
+    let bless = std::env::args().any(|arg| arg == "--bless");
+
+    // Répertoire de travail isolé, variables d'environnement du cycle neutralisées
+    let work_dir = common::WorkDir::new("golden");
+    common::fixture_run(&work_dir, FIXTURE).run();
+    let actual = std::fs::read_to_string(common::signals_path(&work_dir)).expect("lecture des signaux générés");
+    let golden_path = common::manifest_path(GOLDEN);
+
+    if bless {
+        std::fs::write(&golden_path, &actual).expect("écriture du golden");
+        println!("golden_simulation: {} régénéré", GOLDEN);
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&golden_path)
+        .unwrap_or_else(|_| panic!("{} absent: lancer `cargo test --test golden_simulation -- --bless`", GOLDEN));
+    if actual != expected {
+        eprintln!("--- attendu ({})\n{}", GOLDEN, expected);
+        eprintln!("+++ obtenu\n{}", actual);
+        panic!("les signaux diffèrent du golden; si le changement est voulu, relancer avec -- --bless");
+    }
+    println!("golden_simulation: ok");
+}
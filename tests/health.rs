@@ -0,0 +1,72 @@
+// Endpoints --health-port: /healthz répond dès le démarrage,
+// /readyz reste en 503 jusqu'au premier cycle réussi puis passe à 200.
+// La fixture retarde la récupération des marchés pour observer l'état intermédiaire
+mod common;
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Stdio};
+use std::time::{Duration, Instant};
+
+const FIXTURE: &str = "tests/fixtures/health.json";
+
+// Tue le bot même si une assertion échoue
+struct Bot(Child);
+
+impl Drop for Bot {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0").expect("port libre").local_addr().unwrap().port()
+}
+
+// Code HTTP de GET path, None si le serveur ne répond pas encore
+fn status(port: u16, path: &str) -> Option<u16> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).ok()?;
+    stream.set_read_timeout(Some(Duration::from_secs(2))).ok()?;
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).ok()?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+    response.split_whitespace().nth(1)?.parse().ok()
+}
+
+fn wait_for_status(port: u16, path: &str, expected: u16, timeout: Duration) -> Option<u16> {
+    let started = Instant::now();
+    let mut last = None;
+    while started.elapsed() < timeout {
+        last = status(port, path);
+        if last == Some(expected) {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    last
+}
+
+#[test]
+fn readyz_flips_to_ready_after_first_successful_cycle() {
+    let manifest_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let work_dir = common::WorkDir::new("health");
+    let port = free_port();
+
+    let _bot = Bot(common::bot_command(&work_dir)
+        .arg("--fixture").arg(manifest_dir.join(FIXTURE))
+        .arg("--seed").arg("42")
+        .arg("--health-port").arg(port.to_string())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("lancement du bot"));
+
+    assert_eq!(wait_for_status(port, "/healthz", 200, Duration::from_secs(10)), Some(200),
+               "/healthz doit répondre 200 dès le démarrage");
+    assert_eq!(status(port, "/readyz"), Some(503),
+               "/readyz doit répondre 503 avant le premier cycle réussi");
+    assert_eq!(wait_for_status(port, "/readyz", 200, Duration::from_secs(30)), Some(200),
+               "/readyz doit passer à 200 après le premier cycle réussi");
+    assert_eq!(status(port, "/unknown"), Some(404));
+}
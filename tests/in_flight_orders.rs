@@ -0,0 +1,41 @@
+// Mode réel: l'exécution réclame le créneau (marché, côté) avant d'envoyer un ordre. Un autre
+// exécuteur qui le tient fait attendre l'ordre du cycle, qui part à la libération et ajoute
+// son remplissage à l'état engagé laissé par le premier
+mod common;
+
+use common::clob_mock::{order_terms, real_bot, token_id, ClobMock};
+use std::time::Duration;
+
+const WAIT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn real_orders_wait_for_the_order_in_flight_on_their_market() {
+    let work_dir = common::WorkDir::new("in-flight-orders");
+    common::isolate_env();
+    let clob = ClobMock::filling();
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+
+    // Un autre exécuteur a un ordre en vol sur fx-crypto-1 (BUY YES) et y a engagé 10 USDC
+    let registry = bot.in_flight_orders();
+    let mut held = registry.claim("fx-crypto-1", "YES-buy", WAIT).await.expect("créneau libre");
+    *held += 10.0;
+    let observer = clob.clone();
+    let release = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        // Tant que le créneau est tenu, aucun ordre fx-crypto-1 n'est parti
+        let posted_while_held = observer.orders().iter().map(order_terms).filter(|(token, ..)| token == token_id("fx-crypto-1", true)).count();
+        drop(held);
+        posted_while_held
+    });
+
+    let report = bot.run_cycle().await;
+    assert_eq!(release.await.unwrap(), 0);
+    let crypto: Vec<_> = clob.orders().iter().map(order_terms).filter(|(token, ..)| token == token_id("fx-crypto-1", true)).collect();
+    assert_eq!(crypto.len(), 1, "{:?}", clob.orders());
+    assert_eq!(report.trades, clob.orders().len());
+
+    // Le créneau libéré porte les 10 USDC du premier ordre plus le remplissage du cycle
+    let (_, _, tokens, price) = crypto[0].clone();
+    let committed = *registry.claim("fx-crypto-1", "YES-buy", Duration::ZERO).await.expect("créneau libéré");
+    assert!((committed - (10.0 + tokens * price)).abs() < 1e-6, "{} vs 10 + {}", committed, tokens * price);
+}
@@ -0,0 +1,28 @@
+// Force de l'information: confirmation forte (source officielle, nombreux mots-clés),
+// indice faible (un seul mot-clé d'un agrégateur peu fiable), aucune preuve (pas de signal)
+mod common;
+
+use common::Signal;
+
+const FIXTURE: &str = "tests/fixtures/information_signal.json";
+
+fn strength_of(signals: &[Signal], market_id: &str) -> Option<f64> {
+    signals.iter().find(|signal| signal.market_id == market_id).map(|signal| signal.information_strength)
+}
+
+#[test]
+fn strong_weak_and_missing_evidence() {
+    let work_dir = common::WorkDir::new("information");
+    let run = common::fixture_run(&work_dir, FIXTURE).run();
+    let signals = &run.signals;
+
+    let strong = strength_of(signals, "is-strong").expect("signal de confirmation forte absent");
+    assert!(strong > 0.8, "communiqué SEC avec de nombreux mots-clés: force {} trop faible", strong);
+
+    let weak = strength_of(signals, "is-weak").expect("signal d'indice faible absent");
+    assert!(weak > 0.0 && weak < 0.3, "un mot-clé d'agrégateur doit rester un indice faible: force {}", weak);
+
+    // Marché sans preuve: opportunité détectée, jugée neutre, pas de signal
+    assert!(strength_of(signals, "is-none").is_none(), "aucun signal attendu sans preuve: {:?}", signals);
+    assert_eq!(run.report().opportunities, 3);
+}
@@ -0,0 +1,37 @@
+// Kill-file: tant que le fichier existe, aucun trade et toutes les décisions en MONITOR;
+// le trading reprend dès qu'il est supprimé, sans redémarrer le bot
+mod common;
+
+use common::FIXTURE;
+use polymarket_bot::{Bot, Fixture};
+
+#[tokio::test]
+async fn creating_the_kill_file_halts_trading_and_removing_it_resumes() {
+    let work_dir = common::WorkDir::new("kill-file");
+    common::isolate_env();
+    let kill_path = work_dir.join("KILL");
+
+    let fixture = Fixture::load(common::manifest_path(FIXTURE).to_str().unwrap()).expect("fixture");
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(fixture).seed(42).kill_file(kill_path.to_str().unwrap()).build().expect("configuration");
+    let trading = |report: &polymarket_bot::CycleReport| report.signals.iter().filter(|s| s.action != "MONITOR").count();
+
+    // Sans fichier: le cycle trade normalement
+    let before = bot.run_cycle().await;
+    assert!(before.success, "{:?}", before.errors);
+    assert!(trading(&before) > 0 && before.trades > 0, "{:?}", before.signals);
+
+    // Fichier créé: toutes les décisions en MONITOR, aucune exécution, cycle en échec
+    std::fs::write(&kill_path, "incident en cours\n").expect("création du kill-file");
+    let halted = bot.run_cycle().await;
+    assert!(!halted.signals.is_empty());
+    assert_eq!(trading(&halted), 0, "{:?}", halted.signals);
+    assert_eq!(halted.trades, 0);
+    assert!(!halted.success);
+    assert!(halted.errors.iter().any(|e| e.contains("kill-file")), "{:?}", halted.errors);
+
+    // Fichier supprimé: reprise au cycle suivant
+    std::fs::remove_file(&kill_path).expect("suppression du kill-file");
+    let resumed = bot.run_cycle().await;
+    assert!(resumed.success, "{:?}", resumed.errors);
+    assert!(trading(&resumed) > 0 && resumed.trades > 0, "{:?}", resumed.signals);
+}
@@ -0,0 +1,37 @@
+// API bibliothèque: un Bot construit sans le binaire rejoue le cycle de la fixture
+// et produit exactement les signaux du golden du binaire
+mod common;
+
+use common::FIXTURE;
+use polymarket_bot::{Bot, Fixture};
+use std::path::Path;
+
+const GOLDEN: &str = "tests/golden/simulation_signals.json";
+
+#[tokio::test]
+async fn builder_runs_a_simulated_cycle_like_the_binary() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let work_dir = common::WorkDir::new("library");
+    common::isolate_env();
+
+    let fixture = Fixture::load(manifest_dir.join(FIXTURE).to_str().unwrap()).expect("fixture");
+    let mut bot = Bot::builder().work_dir(&work_dir).log_dir(work_dir.join("logs")).fixture(fixture).seed(42).build().expect("configuration");
+    assert_eq!(bot.cycle(), 0);
+
+    let report = bot.run_cycle().await;
+    // Journaux sous log_dir, pas dans le répertoire de travail
+    assert!(work_dir.join("logs/polymarket.log").exists());
+    assert!(!work_dir.join("polymarket.log").exists());
+
+    assert_eq!(bot.cycle(), 1);
+    assert!(report.success, "{:?}", report.errors);
+    assert_eq!(report.mode, "simulation");
+    assert_eq!(report.markets_analyzed, bot.markets().len());
+    assert_eq!(report.signals.len(), 4);
+    assert!(report.signals.iter().all(|s| !s.market_id.is_empty() && !s.source.is_empty()));
+
+    let golden: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(manifest_dir.join(GOLDEN)).expect("lecture du golden"),
+    ).expect("golden JSON");
+    assert_eq!(bot.signals_snapshot(), golden);
+}
@@ -0,0 +1,36 @@
+// Mode réel: la taille d'un ordre est bornée par la liquidité du livre au prix limite avant
+// l'envoi au CLOB. Fixture: fx-economy-1 mise ~41 USDC à 0.59 (~70 tokens), le livre n'offre
+// que 50 tokens à 0.59; fx-crypto-1 tient dans son meilleur niveau (150 tokens à 0.36)
+mod common;
+
+use common::clob_mock::{order_terms, real_bot, token_id, ClobMock};
+
+#[tokio::test]
+async fn real_orders_are_capped_to_the_book_at_the_limit_price() {
+    let work_dir = common::WorkDir::new("liquidity-cap");
+    common::isolate_env();
+    let clob = ClobMock::filling();
+
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+    let report = bot.run_cycle().await;
+    let orders: Vec<_> = clob.orders().iter().map(order_terms).collect();
+
+    assert_eq!(report.mode, "real");
+    assert_eq!(report.trades, orders.len(), "{:?}", orders);
+    let order = |market: &str| orders.iter().find(|(token, ..)| token == token_id(market, true))
+        .unwrap_or_else(|| panic!("aucun ordre {}: {:?}", market, orders)).clone();
+
+    // Réduit aux 50 tokens du meilleur ask, au lieu de la mise convertie au prix
+    let (_, side, tokens, price) = order("fx-economy-1");
+    let stake = report.signals.iter().find(|s| s.market_id == "fx-economy-1").map(|s| s.stake_amount).unwrap();
+    assert_eq!(side, "buy");
+    assert!((price - 0.59).abs() < 1e-9, "{}", price);
+    assert!(stake / price > 50.0, "mise {} déjà sous la liquidité", stake);
+    assert!((tokens - 50.0).abs() < 1e-9, "{}", tokens);
+
+    // Ordre qui tient dans le livre: taille inchangée (tokens tronqués au centième)
+    let (_, _, tokens, price) = order("fx-crypto-1");
+    let stake = report.signals.iter().find(|s| s.market_id == "fx-crypto-1").map(|s| s.stake_amount).unwrap();
+    assert!((price - 0.36).abs() < 1e-9, "{}", price);
+    assert!((tokens - stake / price).abs() < 0.01, "{} vs {}", tokens, stake / price);
+}
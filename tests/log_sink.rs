@@ -0,0 +1,78 @@
+// --log-sink: journaux copiés vers un object store compatible S3, sans bloquer le cycle
+mod common;
+
+use common::FIXTURE;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+struct ReceivedPut {
+    request_line: String,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+// Endpoint S3 minimal: enregistre chaque requête et répond 200
+fn mock_s3_endpoint() -> (String, Arc<Mutex<Vec<ReceivedPut>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("port local");
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 || line.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.trim().split_once(':') {
+                    headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+                }
+            }
+            let length = headers.iter().find(|(name, _)| name == "content-length")
+                .and_then(|(_, value)| value.parse::<usize>().ok()).unwrap_or(0);
+            let mut body = vec![0u8; length];
+            let _ = reader.read_exact(&mut body);
+            log.lock().unwrap().push(ReceivedPut {
+                request_line: request_line.trim().to_string(),
+                headers,
+                body: String::from_utf8_lossy(&body).to_string(),
+            });
+            let _ = reader.get_mut().write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        }
+    });
+    (endpoint, received)
+}
+
+#[test]
+fn bot_uploads_its_log_under_the_run_prefix() {
+    let work_dir = common::WorkDir::new("log-sink");
+    let (endpoint, received) = mock_s3_endpoint();
+
+    common::fixture_run(&work_dir, FIXTURE)
+        .args(["--log-sink", "s3://bot-logs/prod"])
+        .env("LOG_SINK_ENDPOINT", &endpoint)
+        .env("AWS_ACCESS_KEY_ID", "AKIDTEST")
+        .env("AWS_SECRET_ACCESS_KEY", "secret")
+        .env("AWS_REGION", "eu-west-1")
+        .run();
+
+    // Journal envoyé sous prod/<run>/, le run étant le préfixe du cycle_id de ses lignes
+    let received = received.lock().unwrap();
+    let (upload, run_id) = received.iter().rev()
+        .find_map(|put| {
+            let path = put.request_line.strip_prefix("PUT /bot-logs/prod/")?.strip_suffix("/polymarket.log HTTP/1.1")?;
+            Some((put, path.to_string()))
+        })
+        .unwrap_or_else(|| panic!("envoi du journal attendu, reçu: {:?}", received.iter().map(|p| &p.request_line).collect::<Vec<_>>()));
+    let authorization = upload.headers.iter().find(|(name, _)| name == "authorization").map(|(_, v)| v.as_str()).unwrap_or("");
+    assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDTEST/"), "requête signée: {}", authorization);
+    assert!(authorization.contains("/eu-west-1/s3/aws4_request"), "{}", authorization);
+    assert!(upload.body.contains(&format!("cycle={}-1", run_id)), "contenu du journal du cycle envoyé: {}", upload.body);
+}
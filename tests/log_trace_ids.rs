@@ -0,0 +1,55 @@
+// Identifiants de corrélation: chaque ligne de log porte le cycle_id de son cycle,
+// et les lignes d'une opportunité portent en plus market=<id> et opp=<id> (stable)
+mod common;
+
+use common::FIXTURE;
+use std::collections::BTreeSet;
+
+fn field<'a>(record: &'a str, name: &str) -> Option<&'a str> {
+    let prefix = format!("{}=", name);
+    record.split_whitespace().find_map(|token| token.strip_prefix(prefix.as_str()))
+}
+
+#[test]
+fn all_records_of_a_cycle_share_its_cycle_id() {
+    let work_dir = common::WorkDir::new("trace");
+    common::fixture_run(&work_dir, FIXTURE).cycles(2).run();
+    let log = std::fs::read_to_string(work_dir.join("polymarket.log")).expect("lecture de polymarket.log");
+
+    // Lignes regroupées par cycle, dans l'ordre d'écriture
+    let mut cycles: Vec<(String, Vec<&str>)> = Vec::new();
+    for record in log.lines() {
+        let cycle_id = field(record, "cycle").unwrap_or_else(|| panic!("ligne sans cycle_id: {}", record));
+        if cycle_id == "-" {
+            assert!(cycles.is_empty(), "ligne hors cycle après le démarrage: {}", record);
+            continue;
+        }
+        match cycles.last_mut() {
+            Some((current, records)) if current == cycle_id => records.push(record),
+            _ => {
+                assert!(cycles.iter().all(|(id, _)| id != cycle_id), "cycle_id {} réapparu plus tard", cycle_id);
+                cycles.push((cycle_id.to_string(), vec![record]));
+            }
+        }
+    }
+    assert_eq!(cycles.len(), 2, "{}", log);
+
+    assert!(cycles[0].0.ends_with("-1") && cycles[1].0.ends_with("-2"), "{:?}", cycles.iter().map(|(id, _)| id).collect::<Vec<_>>());
+
+    // Un marché se suit d'un cycle à l'autre avec le même opp=<id>
+    let opportunities = |records: &[&str]| -> BTreeSet<(String, String)> {
+        records.iter()
+            .filter_map(|r| Some((field(r, "opp")?.to_string(), field(r, "market")?.to_string())))
+            .filter(|(opp, _)| opp != "-")
+            .collect()
+    };
+    let (first, second) = (opportunities(&cycles[0].1), opportunities(&cycles[1].1));
+    assert!(first.iter().any(|(_, market)| market == "fx-crypto-1"), "{:?}", first);
+    assert!(first.is_subset(&second), "opp=<id> du cycle 1 repris au cycle 2: {:?} / {:?}", first, second);
+
+    // Chaque signal (trade_timing.log) porte l'opp=<id> et le marché de son opportunité
+    let timing = std::fs::read_to_string(work_dir.join("trade_timing.log")).expect("lecture de trade_timing.log");
+    let signalled = opportunities(&timing.lines().filter(|r| field(r, "cycle") == Some(cycles[0].0.as_str())).collect::<Vec<_>>());
+    assert!(!signalled.is_empty(), "{}", timing);
+    assert!(signalled.is_subset(&first), "signaux {:?} hors des opportunités {:?}", signalled, first);
+}
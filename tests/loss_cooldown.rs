@@ -0,0 +1,59 @@
+// Cooldown après perte: une clôture perdante bloque les nouvelles entrées sur le marché
+// jusqu'à expiration. L'horloge est simulée par l'ancienneté de la clôture (closed_secs_ago);
+// cooldown par défaut: 600s × (1 + perte / 10 USDC), plafonné à 6h
+mod common;
+
+const MARKET: &str = "lc-sec-etf";
+const SEC_FEED: &str = "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>SEC Press Releases</title><item><title>SEC approves spot Ethereum ETF applications</title><description>The Commission announced its decision on the pending ETF filing.</description></item></channel></rss>";
+
+// (opportunités détectées, le marché a-t-il produit un signal) après une clôture (pnl, ancienneté)
+fn run_cycle(closes: &[(f64, f64)], tag: &str) -> (usize, bool) {
+    let work_dir = common::WorkDir::new(&format!("loss-cooldown-{}", tag));
+
+    let fixture = serde_json::json!({
+        "markets": [{
+            "id": MARKET,
+            "question": "Will a spot Ethereum ETF be approved by the SEC?",
+            "description": "Resolves YES on an SEC approval order published on sec.gov",
+            "domain": "crypto",
+            "probability": 0.35,
+            "resolution_source": "sec.gov",
+        }],
+        "sources": {
+            "https://www.sec.gov/news/pressreleases.rss": { "content_type": "application/rss+xml", "body": SEC_FEED },
+        },
+        "closed_positions": closes.iter()
+            .map(|(pnl, ago)| serde_json::json!({ "market_id": MARKET, "pnl": pnl, "closed_secs_ago": ago }))
+            .collect::<Vec<_>>(),
+    });
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    let run = common::fixture_run(&work_dir, &fixture_path).run();
+    (run.report().opportunities, !run.signals_for(MARKET).is_empty())
+}
+
+#[test]
+fn losing_close_blocks_reentry_until_expiry() {
+    let (_, has_signal) = run_cycle(&[], "none");
+    assert!(has_signal, "sans clôture, la fixture doit produire un signal");
+
+    // Perte de 1 USDC il y a 10s: cooldown de 660s encore actif, l'opportunité est bloquée
+    let (opportunities, has_signal) = run_cycle(&[(-1.0, 10.0)], "recent");
+    assert!(!has_signal, "le marché doit être bloqué juste après la perte");
+    assert_eq!(opportunities, 1, "opportunité détectée puis bloquée");
+
+    // Même perte il y a 700s: cooldown expiré, nouvelle entrée autorisée
+    let (_, has_signal) = run_cycle(&[(-1.0, 700.0)], "expired");
+    assert!(has_signal, "le cooldown expiré ne doit plus bloquer le marché");
+}
+
+#[test]
+fn cooldown_scales_with_loss_and_ignores_gains() {
+    // Perte de 30 USDC il y a 700s: cooldown de 2400s, toujours actif
+    let (_, has_signal) = run_cycle(&[(-30.0, 700.0)], "large");
+    assert!(!has_signal, "une grosse perte doit bloquer plus longtemps");
+
+    let (_, has_signal) = run_cycle(&[(25.0, 10.0)], "gain");
+    assert!(has_signal, "une clôture gagnante ne déclenche pas de cooldown");
+}
@@ -0,0 +1,33 @@
+// Marchés en double: une même question listée sous plusieurs ids n'est analysée qu'une fois,
+// sous le marché le plus liquide
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn a_near_identical_market_is_deduped_from_the_cycle() {
+    let work_dir = common::WorkDir::new("market-dedupe");
+
+    // Fixture du cycle de simulation, avec fx-politics-1 recréé sous un autre id, moins liquide
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    let markets = fixture["markets"].as_array_mut().unwrap();
+    markets[0]["liquidity"] = serde_json::json!(5000.0);
+    let mut duplicate = markets[0].clone();
+    duplicate["id"] = serde_json::json!("fx-politics-1-recreated");
+    duplicate["question"] = serde_json::json!("Will the incumbent win the Election");
+    duplicate["liquidity"] = serde_json::json!(1200.0);
+    duplicate["clob_token_ids"] = serde_json::json!(["fx-pol2-yes", "fx-pol2-no"]);
+    markets.insert(0, duplicate);
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+    let run = common::fixture_run(&work_dir, &fixture_path).run();
+
+    // Doublon retiré avant l'analyse: ni analysé, ni compté parmi les marchés sans opportunité
+    let report = run.report();
+    assert_eq!(report.markets_analyzed, 3, "{:?}", report);
+    assert!(!report.skipped.markets.contains_key("fx-politics-1-recreated"), "{:?}", report.skipped);
+    assert!(!run.signals_for("fx-politics-1").is_empty(), "{:?}", run.signals);
+    assert!(run.signals_for("fx-politics-1-recreated").is_empty(), "{:?}", run.signals);
+}
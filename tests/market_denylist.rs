@@ -0,0 +1,83 @@
+// Denylist de marchés: --ignore-markets et fichier persistant excluent un marché de tout le
+// pipeline; un marché qui déclenche N fois le garde-fou de prix y est ajouté automatiquement
+mod common;
+
+use common::{RunOutput, FIXTURE};
+use std::path::Path;
+
+// Cycles enchaînés avec un instantané par cycle sous <work_dir>/snapshots
+fn run_cycles(work_dir: &Path, fixture: &Path, cycles: u64, args: &[&str], trip_limit: u32) -> RunOutput {
+    common::fixture_run(work_dir, fixture)
+        .cycles(cycles)
+        .args(["--snapshot-dir", "snapshots"])
+        .args(args)
+        .env("GUARD_TRIP_LIMIT", trip_limit.to_string())
+        .run()
+}
+
+fn snapshot(work_dir: &Path, cycle: u64) -> serde_json::Value {
+    let path = work_dir.join(format!("snapshots/cycle-{}.json", cycle));
+    serde_json::from_str(&std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))).expect("instantané JSON")
+}
+
+// Marchés dont le prix a été lu au cycle, et marchés ayant reçu un signal
+fn analyzed(snapshot: &serde_json::Value) -> Vec<String> {
+    snapshot["markets"].as_object().unwrap().keys().cloned().collect()
+}
+
+fn signalled(snapshot: &serde_json::Value) -> Vec<String> {
+    snapshot["signals"].as_object().unwrap().keys().map(|key| key.split(" <- ").next().unwrap().to_string()).collect()
+}
+
+#[test]
+fn denylisted_markets_never_produce_an_opportunity() {
+    let work_dir = common::WorkDir::new("denylist");
+    let denylist_path = work_dir.join("denylist.txt");
+    let denylist_content = "# marchés piégés\nfx-economy-1 # manipulé\n";
+    std::fs::write(&denylist_path, denylist_content).expect("écriture de la denylist");
+
+    let run = run_cycles(&work_dir, &common::manifest_path(FIXTURE), 2, &[
+        "--denylist", denylist_path.to_str().unwrap(),
+        "--ignore-markets", "fx-crypto-1",
+    ], 5);
+    let persisted = std::fs::read_to_string(&denylist_path).expect("lecture de la denylist");
+
+    // Les autres marchés sont traités normalement
+    assert!(!run.signals.is_empty(), "signaux attendus pour les autres marchés");
+    assert!(run.signals.iter().all(|s| s.market_id == "fx-politics-1"), "aucun signal pour un marché refusé: {:?}", run.signals);
+    for cycle in 1..=2 {
+        assert_eq!(analyzed(&snapshot(&work_dir, cycle)), ["fx-politics-1"], "cycle {}: marché refusé analysé", cycle);
+    }
+    assert_eq!(persisted, denylist_content, "--ignore-markets n'est jamais écrit dans le fichier");
+}
+
+#[test]
+fn market_tripping_the_sanity_guard_is_auto_denylisted() {
+    let work_dir = common::WorkDir::new("auto-denylist");
+
+    // Livre aberrant: le YES de fx-economy-1 n'est offert qu'à 1.0
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    fixture["orderbooks"]["fx-economy-1"]["asks"] = serde_json::json!([[1.0, 500.0]]);
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    run_cycles(&work_dir, &fixture_path, 3, &[], 2);
+    let persisted = std::fs::read_to_string(work_dir.join("market_denylist.txt")).expect("denylist persistée");
+
+    // Cycles 1 et 2: analysé, garde-fou déclenché, aucun signal; cycle 3: plus analysé du tout
+    for cycle in 1..=2 {
+        let snapshot = snapshot(&work_dir, cycle);
+        assert!(analyzed(&snapshot).contains(&"fx-economy-1".to_string()), "cycle {}: {}", cycle, snapshot);
+        assert!(!signalled(&snapshot).contains(&"fx-economy-1".to_string()), "cycle {}: {}", cycle, snapshot);
+    }
+    let third = snapshot(&work_dir, 3);
+    assert!(!analyzed(&third).contains(&"fx-economy-1".to_string()), "plus analysé après l'ajout: {}", third);
+    assert!(analyzed(&third).contains(&"fx-politics-1".to_string()));
+
+    // Une ligne "id # raison" par marché exclu
+    let entries: Vec<&str> = persisted.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#')).collect();
+    assert_eq!(entries.len(), 1, "{}", persisted);
+    assert!(entries[0].starts_with("fx-economy-1 #") && entries[0].contains("garde-fou prix"), "{}", persisted);
+}
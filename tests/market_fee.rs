@@ -0,0 +1,66 @@
+// Frais propres à un marché (taker_base_fee des métadonnées de la venue): le ROI et l'edge
+// du marché sont calculés à ses frais, les autres marchés gardent le barème global
+mod common;
+
+use common::FIXTURE;
+use std::path::Path;
+
+const MARKET: &str = "fx-economy-1";
+const CONDITION_ID: &str = "0xfx-economy";
+
+// (ROI attendu, edge net) du signal d'achat de chaque marché, frais du marché en bps (None = barème)
+fn run_cycle(work_dir: &Path, fee_bps: Option<f64>) -> Vec<(String, f64, f64)> {
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    if let Some(fee_bps) = fee_bps {
+        let market = fixture["markets"].as_array_mut().unwrap().iter_mut()
+            .find(|m| m["id"] == MARKET).expect("marché economy");
+        market["condition_id"] = serde_json::json!(CONDITION_ID);
+        fixture["market_metadata"] = serde_json::json!({
+            CONDITION_ID: {
+                "minimum_tick_size": "0.01",
+                "minimum_order_size": 1,
+                "taker_base_fee": fee_bps
+            }
+        });
+    }
+    let name = fee_bps.map_or("global".to_string(), |bps| format!("{}bps", bps));
+    let fixture_path = work_dir.join(format!("{}.json", name));
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    common::run_fixture(work_dir, &fixture_path, &[]).into_iter()
+        .filter(|s| s.action == "BUY")
+        .map(|s| (s.market_id, s.expected_roi, s.net_edge))
+        .collect()
+}
+
+fn of(buys: &[(String, f64, f64)], market_id: &str) -> (f64, f64) {
+    let matching: Vec<_> = buys.iter().filter(|(id, _, _)| id == market_id).collect();
+    assert_eq!(matching.len(), 1, "{:?}", buys);
+    (matching[0].1, matching[0].2)
+}
+
+#[test]
+fn an_elevated_market_fee_lowers_its_net_roi() {
+    let work_dir = common::WorkDir::new("market-fee");
+    let global = run_cycle(&work_dir, None);
+    let standard = run_cycle(&work_dir, Some(200.0));
+    let elevated = run_cycle(&work_dir, Some(1000.0));
+
+    // Frais du marché égaux au barème standard: mêmes ROI et edge que sans frais propres
+    let (global_roi, global_edge) = of(&global, MARKET);
+    let (standard_roi, standard_edge) = of(&standard, MARKET);
+    assert!((standard_roi - global_roi).abs() < 1e-6, "{} / {}", standard_roi, global_roi);
+    assert!((standard_edge - global_edge).abs() < 1e-6, "{} / {}", standard_edge, global_edge);
+
+    // 10% de frais au lieu de 2%: ROI et edge net plus faibles
+    let (elevated_roi, elevated_edge) = of(&elevated, MARKET);
+    assert!(elevated_roi < global_roi, "{} >= {}", elevated_roi, global_roi);
+    assert!(elevated_edge < global_edge, "{} >= {}", elevated_edge, global_edge);
+
+    // Les autres marchés restent au barème global
+    for market_id in ["fx-politics-1", "fx-crypto-1"] {
+        assert_eq!(of(&elevated, market_id), of(&global, market_id), "{}", market_id);
+    }
+}
@@ -0,0 +1,28 @@
+// Impact de marché: le livre YES n'offre qu'un dixième de token au meilleur prix,
+// la mise complète traverserait jusqu'au niveau suivant (0.60) et consommerait
+// l'essentiel de l'edge. MAX_IMPACT_FRACTION plafonne la mise.
+mod common;
+
+const FIXTURE: &str = "tests/fixtures/market_impact.json";
+const MARKET: &str = "mi-sec-etf";
+
+// (mise, prix moyen payé) du signal de la fixture
+fn run_cycle(max_impact_fraction: &str) -> (f64, f64) {
+    let work_dir = common::WorkDir::new(&format!("impact-{}", max_impact_fraction));
+    let run = common::fixture_run(&work_dir, FIXTURE).env("MAX_IMPACT_FRACTION", max_impact_fraction).run();
+    let signal = run.signals.iter().find(|signal| signal.market_id == MARKET)
+        .unwrap_or_else(|| panic!("aucun signal pour {}: {:?}", MARKET, run.signals));
+    (signal.stake_amount, signal.spent_price)
+}
+
+#[test]
+fn stake_causing_excessive_impact_is_reduced() {
+    let (uncapped_stake, uncapped_price) = run_cycle("100");
+    let (capped_stake, capped_price) = run_cycle("0.3");
+
+    assert!(capped_stake < uncapped_stake,
+            "la mise doit être réduite par le plafond d'impact: {} vs {}", capped_stake, uncapped_stake);
+    assert!(capped_stake > 0.0, "la mise plafonnée reste positive");
+    assert!(capped_price < uncapped_price,
+            "la mise réduite doit payer un meilleur prix moyen: {} vs {}", capped_price, uncapped_price);
+}
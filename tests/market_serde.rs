@@ -0,0 +1,81 @@
+// Market désérialisé directement depuis la réponse Gamma /markets: noms de champs Gamma,
+// valeurs en nombre ou en chaîne, listes en tableau ou en chaîne JSON encodée
+use polymarket_bot::Market;
+
+fn gamma_market() -> serde_json::Value {
+    serde_json::json!({
+        "id": "512340",
+        "question": "Will the Fed cut rates at the next FOMC meeting?",
+        "description": "Resolves on the FOMC statement",
+        "resolutionSource": "https://www.federalreserve.gov",
+        "createdAt": "2025-03-01 12:00:00+00",
+        "endDate": "2025-06-18T18:00:00Z",
+        "conditionId": "0xabc",
+        "clobTokenIds": "[\"111\", \"222\"]",
+        "outcomes": "[\"Yes\", \"No\"]",
+        "outcomePrices": "[\"0.62\", \"0.38\"]",
+        "liquidity": "15000.5",
+        "enableOrderBook": true,
+        "events": [{"id": 9001, "title": "Fed decision"}],
+        "volume": "250000"
+    })
+}
+
+#[test]
+fn a_gamma_market_deserializes_with_its_gamma_field_names() {
+    let market: Market = serde_json::from_value(gamma_market()).expect("marché Gamma");
+    assert_eq!(market.id, "512340");
+    assert_eq!(market.resolution_source, "https://www.federalreserve.gov");
+    assert_eq!(market.condition_id, "0xabc");
+    assert_eq!(market.event_id, "9001");
+    assert_eq!(market.end_date.as_deref(), Some("2025-06-18T18:00:00Z"));
+    assert_eq!(market.clob_token_ids, vec!["111", "222"]);
+    assert_eq!(market.liquidity, 15000.5);
+    assert!(market.order_book_enabled);
+    // Date "AAAA-MM-JJ HH:MM:SS+00" ramenée en RFC 3339 pour le calcul de l'ancienneté
+    assert_eq!(market.created_at, "2025-03-01T12:00:00+00:00");
+    let outcomes: Vec<(&str, f64)> = market.outcomes.iter().map(|o| (o.name.as_str(), o.price)).collect();
+    assert_eq!(outcomes, vec![("Yes", 0.62), ("No", 0.38)]);
+    // Sans champ probability, le prix vient ensuite de la première issue
+    assert!(market.probability.is_nan());
+}
+
+#[test]
+fn legacy_field_names_and_scales_are_accepted() {
+    let market: Market = serde_json::from_value(serde_json::json!({
+        "id": "legacy-1",
+        "question": "Will the incumbent win?",
+        "probability": "42",
+        "resolution_source": "whitehouse.gov",
+        "created_at": 1_700_000_000,
+        "eventId": "77",
+    })).expect("marché aux anciens noms");
+    assert!((market.probability - 0.42).abs() < 1e-12, "pourcentage ramené à [0, 1]");
+    assert_eq!(market.resolution_source, "whitehouse.gov");
+    assert_eq!(market.created_at, "2023-11-14T22:13:20+00:00");
+    assert_eq!(market.event_id, "77");
+    // Champs absents: livre supposé actif, aucune issue cotée, pas encore de domaine
+    assert!(market.order_book_enabled);
+    assert!(market.outcomes.is_empty() && market.domain.is_empty() && market.clob_token_ids.is_empty());
+
+    for invalid in [
+        serde_json::json!({"id": "x", "question": "q", "probability": 150}),
+        serde_json::json!({"id": "x", "question": "q", "outcomes": ["A", "B", "C"], "outcomePrices": ["0.5", "0.5"]}),
+        serde_json::json!({"question": "q", "probability": 0.5}),
+    ] {
+        assert!(serde_json::from_value::<Market>(invalid.clone()).is_err(), "{} accepté", invalid);
+    }
+}
+
+#[test]
+fn a_serialized_market_reads_back_identically() {
+    let market: Market = serde_json::from_value(gamma_market()).expect("marché Gamma");
+    let serialized = serde_json::to_value(&market).expect("sérialisation");
+    assert_eq!(serialized["conditionId"], "0xabc");
+    assert_eq!(serialized["eventId"], "9001");
+    let read_back: Market = serde_json::from_value(serialized.clone()).unwrap_or_else(|e| panic!("{}: {}", e, serialized));
+    assert_eq!(read_back.event_id, market.event_id);
+    assert_eq!(read_back.created_at, market.created_at);
+    assert_eq!(read_back.clob_token_ids, market.clob_token_ids);
+    assert_eq!(read_back.outcomes, market.outcomes);
+}
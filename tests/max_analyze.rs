@@ -0,0 +1,41 @@
+// --max-analyze: sur 100 marchés, seuls les 10 au meilleur pré-score passent à l'analyse.
+// Marchés identiques hormis la liquidité, le pré-score les classe donc par liquidité
+mod common;
+
+use std::collections::HashSet;
+
+const MARKET_COUNT: usize = 100;
+const MAX_ANALYZE: usize = 10;
+
+// Liquidité distincte par marché, dans un ordre mélangé (37 premier avec 100)
+fn liquidity(index: usize) -> f64 {
+    ((index * 37) % MARKET_COUNT) as f64 * 1000.0 + 500.0
+}
+
+#[test]
+fn only_top_pre_scored_markets_are_analyzed() {
+    let work_dir = common::WorkDir::new("max-analyze");
+
+    let markets: Vec<serde_json::Value> = (0..MARKET_COUNT).map(|i| serde_json::json!({
+        "id": format!("ma-{}", i),
+        "question": format!("Will the Fed cut rates at meeting {}?", i),
+        "domain": "economy",
+        "probability": 0.5,
+        "liquidity": liquidity(i),
+    })).collect();
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, serde_json::json!({ "markets": markets }).to_string()).expect("écriture de la fixture");
+
+    // Fixture sans source: le cycle est en échec (aucune source joignable), le rapport est écrit
+    let run = common::fixture_run(&work_dir, &fixture_path).args(["--max-analyze", &MAX_ANALYZE.to_string()]).output();
+
+    let mut by_liquidity: Vec<usize> = (0..MARKET_COUNT).collect();
+    by_liquidity.sort_by(|a, b| liquidity(*b).total_cmp(&liquidity(*a)));
+    let expected: HashSet<String> = by_liquidity[..MAX_ANALYZE].iter().map(|i| format!("ma-{}", i)).collect();
+
+    // Seuls les marchés priorisés sont analysés: aucun autre n'apparaît parmi les marchés écartés
+    let report = run.report();
+    assert_eq!(report.markets_analyzed, MAX_ANALYZE, "{:?}", report);
+    assert!(!report.skipped.markets.is_empty());
+    assert!(report.skipped.markets.keys().all(|id| expected.contains(id)), "marchés analysés hors priorité: {:?}", report.skipped.markets);
+}
@@ -0,0 +1,26 @@
+// --max-runtime: arrêt propre une fois la durée écoulée, après le cycle en cours
+mod common;
+
+use common::FIXTURE;
+use std::time::{Duration, Instant};
+
+#[test]
+fn bot_stops_cleanly_instead_of_sleeping_past_the_runtime() {
+    let work_dir = common::WorkDir::new("max-runtime");
+
+    // Pause d'une minute entre cycles: seule la réduction au temps restant permet de finir vite
+    let started = Instant::now();
+    let run = common::fixture_run(&work_dir, FIXTURE)
+        .unbounded()
+        .args(["--max-runtime", "1s", "--snapshot-dir", "snapshots"])
+        .env("CYCLE_PAUSE_MIN_SECS", "60")
+        .env("CYCLE_PAUSE_MAX_SECS", "60")
+        .run();
+    let elapsed = started.elapsed();
+    assert!(elapsed < Duration::from_secs(30), "arrêt après {:?}, sans attendre la pause complète", elapsed);
+
+    // Le cycle en cours s'est terminé (signaux écrits), aucun nouveau cycle après l'échéance
+    assert!(!run.signals.is_empty());
+    let snapshots = std::fs::read_dir(work_dir.join("snapshots")).expect("instantanés").count();
+    assert_eq!(snapshots, 1, "un seul cycle attendu");
+}
@@ -0,0 +1,39 @@
+// --min-sources: un signal exige plusieurs sources distinctes indiquant la même direction
+mod common;
+
+use common::{RunOutput, Signal, FIXTURE};
+use std::path::Path;
+
+const CORROBORATED: &str = "fx-politics-1"; // Deux sources (BBC, NewsAPI) affirment YES
+const SINGLE_SOURCE: &str = "fx-crypto-1";  // Seul le flux de la SEC affirme YES
+
+fn run_cycle(work_dir: &Path, min_sources: Option<&str>) -> RunOutput {
+    let mut run = common::fixture_run(work_dir, FIXTURE);
+    if let Some(min_sources) = min_sources {
+        run = run.args(["--min-sources", min_sources]);
+    }
+    run.run()
+}
+
+fn actions(signals: &[Signal], market_id: &str) -> Vec<String> {
+    common::signals_for(signals, market_id).iter().map(|s| s.action.clone()).collect()
+}
+
+#[test]
+fn a_single_affirming_source_is_not_enough_with_two_required() {
+    let work_dir = common::WorkDir::new("min-sources");
+    let default = run_cycle(&work_dir, None);
+    let corroborated = run_cycle(&work_dir, Some("2"));
+    let (default_signals, signals) = (&default.signals, &corroborated.signals);
+
+    // Par défaut (une source suffit), les deux marchés donnent un signal d'achat
+    assert!(actions(default_signals, SINGLE_SOURCE).contains(&"BUY".to_string()), "{:?}", default_signals);
+    assert!(actions(default_signals, CORROBORATED).contains(&"BUY".to_string()), "{:?}", default_signals);
+
+    // Deux sources requises: la source isolée ne déclenche plus rien, son opportunité reste détectée
+    assert!(actions(signals, SINGLE_SOURCE).is_empty(), "{:?}", signals);
+    assert_eq!(corroborated.report().opportunities, default.report().opportunities);
+
+    // Les deux sources d'accord suffisent: signaux inchangés
+    assert_eq!(actions(signals, CORROBORATED), actions(default_signals, CORROBORATED));
+}
@@ -0,0 +1,61 @@
+// --missing-source: marchés sans source de résolution identifiable (require, allow, flag)
+mod common;
+
+use common::{RunOutput, FIXTURE};
+use std::path::Path;
+
+const UNSOURCED: &str = "fx-crypto-1";
+
+// Fixture dont le marché crypto n'a ni champ resolution_source ni passage "resolution source"
+fn unsourced_fixture(work_dir: &Path) -> std::path::PathBuf {
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    let market = fixture["markets"].as_array_mut().unwrap().iter_mut()
+        .find(|m| m["id"] == UNSOURCED).expect("marché crypto");
+    market["resolution_source"] = serde_json::json!("");
+    market["description"] = serde_json::json!("");
+    let path = work_dir.join("fixture.json");
+    std::fs::write(&path, fixture.to_string()).expect("écriture de la fixture");
+    path
+}
+
+fn run_cycle(work_dir: &Path, policy: Option<&str>) -> RunOutput {
+    let mut run = common::fixture_run(work_dir, unsourced_fixture(work_dir));
+    if let Some(policy) = policy {
+        run = run.args(["--missing-source", policy]);
+    }
+    run.run()
+}
+
+fn confidences(cycle: &RunOutput, market_id: &str) -> Vec<String> {
+    cycle.signals_for(market_id).iter().map(|s| s.confidence.clone()).collect()
+}
+
+#[test]
+fn each_policy_treats_an_unsourced_market_differently() {
+    let work_dir = common::WorkDir::new("missing-source");
+    let allow = run_cycle(&work_dir, None);
+    let explicit_allow = run_cycle(&work_dir, Some("allow"));
+    let flag = run_cycle(&work_dir, Some("flag"));
+    let require = run_cycle(&work_dir, Some("require"));
+
+    // allow (défaut): comportement inchangé
+    assert_eq!(confidences(&allow, UNSOURCED), vec!["medium"], "{:?}", allow.signals);
+    assert_eq!(allow.signals, explicit_allow.signals);
+
+    // flag: le signal est produit, confiance abaissée d'un cran
+    assert_eq!(confidences(&flag, UNSOURCED), vec!["low"], "{:?}", flag.signals);
+
+    // require: marché ignoré dès la détection
+    assert!(confidences(&require, UNSOURCED).is_empty(), "{:?}", require.signals);
+    assert_eq!(require.report().skipped.markets[UNSOURCED], "no_resolution_source");
+    assert!(!allow.report().skipped.markets.contains_key(UNSOURCED));
+
+    // Les marchés sourcés ne sont jamais touchés
+    for cycle in [&flag, &require] {
+        for market_id in ["fx-politics-1", "fx-economy-1"] {
+            assert_eq!(confidences(cycle, market_id), confidences(&allow, market_id), "{}", market_id);
+        }
+    }
+}
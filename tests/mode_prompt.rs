@@ -0,0 +1,12 @@
+// Invite du mode: stdin fermé, absent ou muet ne bloque jamais le démarrage,
+// le mode par défaut (simulation) est retenu
+mod common;
+
+#[test]
+fn the_bot_starts_in_simulation_with_stdin_closed() {
+    let work_dir = common::WorkDir::new("mode-prompt");
+    // Sans --mode ni fixture: l'invite est atteinte, stdin fermé (nohup, docker). Le cycle peut
+    // échouer faute de réseau, le rapport est écrit quand même
+    let output = common::bot_run(&work_dir).args(["--max-analyze", "1"]).output();
+    assert_eq!(output.report().mode, "simulation");
+}
@@ -0,0 +1,44 @@
+// Marchés à plus de deux issues: chaque issue garde son nom, son token et son prix, et le ROI se
+// calcule issue par issue; un marché binaire reste lu comme deux issues Yes / No
+mod common;
+
+use common::FIXTURE;
+use polymarket_bot::{Bot, Fixture};
+
+const CATEGORICAL: &str = "fx-politics-1";
+
+#[tokio::test]
+async fn a_categorical_market_is_priced_per_outcome_and_never_traded_as_yes_no() {
+    let work_dir = common::WorkDir::new("multi-outcome");
+    common::isolate_env();
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    let market = fixture["markets"].as_array_mut().unwrap().iter_mut()
+        .find(|m| m["id"] == CATEGORICAL).expect("marché politique");
+    // Premier candidat coté sur le livre fx-pol-yes, les deux autres sans livre
+    market["outcomes"] = serde_json::json!([
+        {"name": "Incumbent", "price": 0.42, "token_id": "fx-pol-yes"},
+        {"name": "Challenger", "price": 0.38},
+        {"name": "Independent", "price": 0.20},
+    ]);
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    let fixture = Fixture::load(fixture_path.to_str().unwrap()).expect("fixture");
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(fixture).seed(42).build().expect("configuration");
+    let report = bot.run_cycle().await;
+    assert!(report.success, "{:?}", report.errors);
+
+    // Chaque issue à son propre prix, jamais au complément d'une autre
+    let market = bot.markets().iter().find(|m| m.id == CATEGORICAL).expect("marché catégoriel");
+    assert!(market.is_categorical());
+    let outcomes: Vec<(&str, f64, &str)> = market.outcomes.iter().map(|o| (o.name.as_str(), o.price, o.token_id.as_str())).collect();
+    assert_eq!(outcomes, [("Incumbent", 0.42, "fx-pol-yes"), ("Challenger", 0.38, ""), ("Independent", 0.20, "")]);
+
+    // Aucune vue YES/NO ne désigne une issue: pas de signal
+    let signalled = |market_id: &str| report.signals.iter().any(|s| s.market_id == market_id);
+    assert!(!signalled(CATEGORICAL), "{:?}", report.signals);
+    // Les marchés binaires de la fixture sont tradés comme avant
+    assert!(signalled("fx-economy-1"), "{:?}", report.signals);
+}
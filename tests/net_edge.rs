@@ -0,0 +1,24 @@
+// Edge après coûts: chaque signal porte net_edge = ROI brut - frais - gas (mode réel) - slippage
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn every_reported_signal_carries_its_net_edge() {
+    let work_dir = common::WorkDir::new("net-edge");
+    let run = common::fixture_run(&work_dir, FIXTURE)
+        .args(["--fee-tier", "standard"])
+        .env("GAS_COST_USDC", "0.5")
+        .run();
+
+    let signals = &run.report().signals;
+    assert!(signals.iter().any(|s| s.stake_amount > 0.0), "signaux misés attendus: {:?}", signals);
+    for signal in signals {
+        let gross = signal.expected_roi;
+        assert!((signal.fee_drag - gross.max(0.0) * 0.02).abs() < 1e-9, "frais taker standard: {:?}", signal);
+        assert_eq!(signal.gas_drag, 0.0, "pas de gas en simulation: {:?}", signal);
+        assert!(signal.slippage_estimate >= 0.0);
+        let costs = signal.fee_drag + signal.gas_drag + signal.slippage_estimate;
+        assert!((signal.net_edge - (gross - costs)).abs() < 1e-9, "net_edge = ROI brut - coûts: {:?}", signal);
+    }
+}
@@ -0,0 +1,59 @@
+// --news-max-age: un élément publié avant la fenêtre de fraîcheur est ignoré avant la détection,
+// un élément récent déclenche le signal, qui porte l'âge du plus récent élément correspondant
+mod common;
+
+use common::RunOutput;
+
+const MARKET: &str = "recency-eth-etf";
+const HEADLINE: &str = "SEC approves spot Ethereum ETF applications";
+
+// Flux SEC avec un seul communiqué, publié il y a `age_secs` secondes
+fn fixture(age_secs: i64) -> serde_json::Value {
+    let published = (chrono::Utc::now() - chrono::Duration::seconds(age_secs)).to_rfc2822();
+    serde_json::json!({
+        "markets": [{
+            "id": MARKET,
+            "question": "Will a spot Ethereum ETF be approved by the SEC?",
+            "description": "Resolves YES on an SEC approval order published on sec.gov",
+            "domain": "crypto",
+            "probability": 0.35,
+            "resolution_source": "sec.gov",
+            "clob_token_ids": ["recency-eth-yes", "recency-eth-no"]
+        }],
+        "sources": {
+            "https://www.sec.gov/news/pressreleases.rss": {
+                "content_type": "application/rss+xml",
+                "body": format!("<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>Press Releases</title><item><title>{}</title><pubDate>{}</pubDate><description>The Commission approved the pending ETF filing.</description></item></channel></rss>", HEADLINE, published)
+            }
+        },
+        "orderbooks": {
+            "recency-eth-yes": { "bids": [[0.34, 200.0]], "asks": [[0.36, 200.0], [0.37, 400.0]] },
+            "recency-eth-no": { "bids": [[0.63, 200.0]], "asks": [[0.65, 200.0]] }
+        }
+    })
+}
+
+fn run_cycle(name: &str, age_secs: i64) -> RunOutput {
+    let work_dir = common::WorkDir::new(&format!("recency-{}", name));
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture(age_secs).to_string()).expect("écriture de la fixture");
+    common::fixture_run(&work_dir, &fixture_path).args(["--news-max-age", "900"]).run()
+}
+
+#[test]
+fn fresh_item_triggers_a_signal_with_its_age() {
+    let run = run_cycle("fresh", 60);
+    let signals = run.signals_for(MARKET);
+    let buy = signals.iter().find(|s| s.action == "BUY").unwrap_or_else(|| panic!("aucun BUY: {:#?}", signals));
+    assert_eq!(buy.trigger_title.as_deref(), Some(HEADLINE), "{:?}", buy);
+    let age = buy.news_age_secs.expect("âge de l'élément");
+    assert!((60.0..120.0).contains(&age), "{:?}", buy);
+}
+
+#[test]
+fn item_older_than_the_window_is_ignored() {
+    // Élément écarté avant la détection: aucun signal déclenché par lui
+    let run = run_cycle("stale", 2 * 3600);
+    let signals = run.signals_for(MARKET);
+    assert!(signals.iter().all(|s| s.action != "BUY" && s.trigger_title.is_none()), "{:#?}", signals);
+}
@@ -0,0 +1,45 @@
+// Prix côté NO: une information négative s'exprime en achetant le token NO au best ask
+// de son propre livre, pas au complément 1 - probabilité YES.
+// Fixture: YES à 0.40 (1 - p = 0.60), livre NO coté 0.61 / 0.64; l'ordre réellement posté au
+// CLOB par le mode réel est vérifié (tokens renumérotés, un ordre signé porte un token id)
+mod common;
+
+use common::clob_mock::{credentials, order_terms, ClobMock, PRIVATE_KEY};
+use polymarket_bot::{Bot, Fixture};
+
+const FIXTURE: &str = "tests/fixtures/no_side_price.json";
+const YES_TOKEN: &str = "2101";
+const NO_TOKEN: &str = "2102";
+const YES_PROBABILITY: f64 = 0.4;
+const NO_BEST_ASK: f64 = 0.64;
+
+fn numeric_tokens(work_dir: &std::path::Path) -> Fixture {
+    let raw = std::fs::read_to_string(common::manifest_path(FIXTURE)).unwrap()
+        .replace("ns-fed-yes", YES_TOKEN)
+        .replace("ns-fed-no", NO_TOKEN);
+    let path = work_dir.join("fixture.json");
+    std::fs::write(&path, raw).unwrap();
+    Fixture::load(path.to_str().unwrap()).expect("fixture")
+}
+
+#[tokio::test]
+async fn no_side_price_is_the_no_book_best_ask() {
+    let work_dir = common::WorkDir::new("no-side");
+    common::isolate_env();
+    let clob = ClobMock::filling();
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(numeric_tokens(&work_dir)).seed(42)
+        .real_mode(true).clob_endpoint(clob.url())
+        .private_key(PRIVATE_KEY).api_credentials(credentials())
+        .build().expect("configuration");
+    let report = bot.run_cycle().await;
+    let orders: Vec<_> = clob.orders().iter().map(order_terms).collect();
+
+    // Information négative: achat du token NO, au best ask de son livre
+    assert_eq!(report.trades, 1, "{:?}", orders);
+    let (token, side, _, price) = orders[0].clone();
+    assert_eq!((token.as_str(), side.as_str()), (NO_TOKEN, "buy"), "{:?}", orders);
+    assert!((price - NO_BEST_ASK).abs() < 1e-9,
+            "prix NO attendu au best ask du livre NO ({}), obtenu {}", NO_BEST_ASK, price);
+    assert!((price - (1.0 - YES_PROBABILITY)).abs() > 1e-6,
+            "le prix NO ne doit pas être le complément de la probabilité YES: {}", price);
+}
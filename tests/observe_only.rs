@@ -0,0 +1,39 @@
+// --observe-only: détection et signaux journalisés avec leur ROI, sans mise ni exécution
+mod common;
+
+use common::FIXTURE;
+
+const INITIAL_BALANCE: f64 = 4000.0;
+
+#[test]
+fn observer_mode_produces_signals_but_never_trades() {
+    let work_dir = common::WorkDir::new("observe");
+    let run = common::fixture_run(&work_dir, FIXTURE)
+        .arg("--observe-only")
+        .env("SIMULATED_BALANCE", INITIAL_BALANCE.to_string())
+        .run();
+    let report = run.report();
+
+    // Détection complète: opportunités et signaux, dont des BUY/SELL qui auraient été tradés
+    assert_eq!(report.mode, "observe");
+    assert!(report.opportunities > 0, "opportunités attendues: {:?}", report);
+    assert!(report.signals.iter().any(|s| s.action == "BUY" || s.action == "SELL"), "signaux BUY/SELL attendus: {:?}", report.signals);
+    assert!(report.signals.iter().all(|s| s.expected_roi.is_finite()), "ROI de chaque signal: {:?}", report.signals);
+
+    // Ni mise, ni exécution, ni variation du solde
+    assert_eq!(report.trades, 0);
+    assert_eq!(report.balance, INITIAL_BALANCE);
+    for signal in &run.signals {
+        assert_eq!(signal.stake_amount, 0.0, "aucune mise: {:?}", signal);
+        assert!(signal.filled_price.is_none(), "rien d'exécuté: {:?}", signal);
+        assert_eq!(signal.filled_size, 0.0);
+    }
+}
+
+#[test]
+fn observer_mode_refuses_real_trading() {
+    // Options incompatibles refusées avant tout cycle: ni rapport, ni signaux
+    let work_dir = common::WorkDir::new("observe-real");
+    let run = common::fixture_run(&work_dir, FIXTURE).args(["--observe-only", "--mode", "real"]).output();
+    assert!(run.report.is_none() && run.signals.is_empty(), "aucun cycle lancé:\n{}", run.stdout);
+}
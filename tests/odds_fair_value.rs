@@ -0,0 +1,48 @@
+// Cotes de bookmakers: probabilité implicite de chaque format de cote, marge retirée sur un
+// marché à deux issues, et fair value d'un marché Polymarket qui nomme une issue cotée
+mod common;
+
+use common::FIXTURE;
+
+const ODDS_SOURCE: &str = "https://api.the-odds-api.com/v4/sports/upcoming/odds?regions=us&oddsFormat=american&apiKey=test-key";
+
+fn odds_api_body() -> String {
+    // Deux bookmakers: prix américains numériques, puis cotes fractionnaires écrites
+    serde_json::json!([{
+        "id": "evt-1", "sport_key": "politics", "sport_title": "Election",
+        "bookmakers": [
+            {"key": "book-a", "markets": [{"key": "h2h", "outcomes": [
+                {"name": "Jane Doe", "price": 150}, {"name": "John Roe", "price": -180}]}]},
+            {"key": "book-b", "markets": [{"key": "h2h", "outcomes": [
+                {"name": "Jane Doe", "price": "6/4"}, {"name": "John Roe", "price": "8/13"}]}]}
+        ]
+    }]).to_string()
+}
+
+#[test]
+fn a_polled_odds_source_prices_the_named_market() {
+    let work_dir = common::WorkDir::new("odds");
+
+    // Fixture du cycle de simulation: le marché politique nomme une candidate cotée
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    fixture["markets"][0]["question"] = serde_json::json!("Will Jane Doe win the election?");
+    fixture["sources"][ODDS_SOURCE] = serde_json::json!({"content_type": "application/json", "body": odds_api_body()});
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    let without_key = common::fixture_run(&work_dir, &fixture_path).run();
+    let with_key = common::fixture_run(&work_dir, &fixture_path).env("ODDS_API_KEY", "test-key").run();
+
+    // Source de cotes interrogée seulement avec une clé
+    assert_eq!(with_key.report().sources_reachable, without_key.report().sources_reachable + 1);
+
+    // Jane Doe à +150 et 6/4, marge retirée: 0.388 devient le prix de référence du marché (coté 0.42)
+    let politics = with_key.signals_for("fx-politics-1");
+    assert!(!politics.is_empty(), "{:?}", with_key.signals);
+    for signal in politics {
+        assert!((signal.reference_price - 0.388043).abs() < 1e-6, "{:?}", signal);
+    }
+    assert!(without_key.signals_for("fx-politics-1").iter().all(|s| (s.reference_price - 0.388043).abs() > 1e-3));
+}
@@ -0,0 +1,53 @@
+// Marchés Gamma enableOrderBook=false: aucun livre interrogé, aucun signal exécutable
+mod common;
+
+use common::{Signal, FIXTURE};
+use std::path::Path;
+
+const NO_BOOK: &str = "fx-crypto-1"; // Ses livres fx-eth-yes / fx-eth-no restent dans la fixture
+
+fn run_cycle(work_dir: &Path, enable_order_book: Option<bool>) -> Vec<Signal> {
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    if let Some(enabled) = enable_order_book {
+        let market = fixture["markets"].as_array_mut().unwrap().iter_mut()
+            .find(|m| m["id"] == NO_BOOK).expect("marché crypto");
+        // Nom du champ Gamma, accepté tel quel dans la fixture
+        market["enableOrderBook"] = serde_json::json!(enabled);
+    }
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    common::run_fixture(work_dir, &fixture_path, &[])
+}
+
+#[test]
+fn a_market_without_order_book_is_never_priced_on_the_clob() {
+    let work_dir = common::WorkDir::new("no-book");
+    let default_signals = run_cycle(&work_dir, None);
+    let signals = run_cycle(&work_dir, Some(false));
+
+    let signal_of = |signals: &[Signal]| -> Signal {
+        let matching = common::signals_for(signals, NO_BOOK);
+        assert_eq!(matching.len(), 1, "{:?}", signals);
+        matching[0].clone()
+    };
+
+    // Par défaut (champ absent): livre interrogé et signal exécutable
+    let booked = signal_of(&default_signals);
+    assert_eq!(booked.action, "BUY");
+    assert_ne!(booked.executable_price, booked.reference_price, "{:?}", booked);
+
+    // enableOrderBook=false: aucun livre consulté, prix de référence, surveillance seulement
+    let unbooked = signal_of(&signals);
+    assert_eq!(unbooked.action, "MONITOR", "{:?}", unbooked);
+    assert_eq!(unbooked.executable_price, unbooked.reference_price, "{:?}", unbooked);
+    assert_ne!(unbooked.executable_price, booked.executable_price);
+
+    // Les autres marchés gardent leurs signaux
+    let others = |signals: &[Signal]| -> Vec<String> {
+        signals.iter().filter(|s| s.market_id != NO_BOOK).map(|s| s.action.clone()).collect()
+    };
+    assert_eq!(others(&signals), others(&default_signals));
+}
@@ -0,0 +1,20 @@
+// Capital épuisé: avec un solde sous l'ordre minimum du CLOB, le cycle de la fixture
+// ne doit produire aucun ordre (signaux forcés en MONITOR, rien de rempli)
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn depleted_balance_stops_trading() {
+    let work_dir = common::WorkDir::new("capital");
+    let run = common::fixture_run(&work_dir, FIXTURE).env("SIMULATED_BALANCE", "0.5").run();
+
+    assert!(!run.signals.is_empty(), "la fixture doit produire des signaux");
+    for signal in &run.signals {
+        assert_eq!(signal.action, "MONITOR", "ordre émis sans capital: {:?}", signal);
+        assert_eq!(signal.filled_size, 0.0, "ordre rempli sans capital: {:?}", signal);
+    }
+    let report = run.report();
+    assert_eq!(report.trades, 0, "{:?}", report);
+    assert_eq!(report.balance, 0.5, "{:?}", report);
+}
@@ -0,0 +1,32 @@
+// Mode réel: chaque remplissage du CLOB est comparé au remplissage que le modèle papier aurait
+// obtenu sur le livre du signal; un écart au-delà des seuils est signalé dans polymarket.log
+mod common;
+
+use common::clob_mock::{filled_response, real_bot, ClobMock};
+
+// Lignes de polymarket.log qui signalent une divergence papier/réel
+async fn divergences(name: &str, clob: ClobMock) -> (usize, Vec<String>) {
+    let work_dir = common::WorkDir::new(name);
+    common::isolate_env();
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+    let report = bot.run_cycle().await;
+    assert_eq!(report.trades, clob.orders().len());
+    let log = std::fs::read_to_string(work_dir.join("polymarket.log")).unwrap_or_default();
+    (report.trades, log.lines().filter(|line| line.contains("DIVERGENCE papier/réel")).map(str::to_string).collect())
+}
+
+#[tokio::test]
+async fn partial_real_fills_are_flagged_against_the_paper_fill() {
+    // Le CLOB ne remplit que 40% de chaque ordre, le papier l'aurait rempli en entier
+    let (trades, flagged) = divergences("paper-divergence-partial", ClobMock::start(|_, order| (200, filled_response(order, 0.4).to_string()))).await;
+    assert!(trades > 0);
+    assert_eq!(flagged.len(), trades, "{:?}", flagged);
+    assert!(flagged.iter().all(|line| line.contains("taille")), "{:?}", flagged);
+}
+
+#[tokio::test]
+async fn real_fills_matching_the_paper_model_are_not_flagged() {
+    let (trades, flagged) = divergences("paper-divergence-matching", ClobMock::filling()).await;
+    assert!(trades > 0);
+    assert!(flagged.is_empty(), "{:?}", flagged);
+}
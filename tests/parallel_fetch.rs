@@ -0,0 +1,18 @@
+// Phases 1 (marchés) et 2 (sources) lancées en parallèle: avec une latence simulée
+// de 600ms pour les marchés et 2 x 300ms pour les sources, le cycle doit attendre
+// la plus lente des deux phases et non leur somme
+mod common;
+
+const FIXTURE: &str = "tests/fixtures/parallel_fetch.json";
+
+#[test]
+fn market_and_source_phases_overlap() {
+    let work_dir = common::WorkDir::new("parallel");
+    let run = common::fixture_run(&work_dir, FIXTURE).args(["--only-domain", "crypto"]).run();
+
+    // Durée des phases 1+2 du rapport (traitement des réponses compris): au moins la latence
+    // d'une phase, moins que leur somme
+    let fetch_ms = run.report().timings_ms.fetch_ms;
+    assert!(fetch_ms >= 600.0, "latences simulées non appliquées: {}ms", fetch_ms);
+    assert!(fetch_ms < 1200.0, "phases séquentielles: {}ms pour 600ms + 600ms", fetch_ms);
+}
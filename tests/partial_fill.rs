@@ -0,0 +1,28 @@
+// Mode réel: un ordre partiellement rempli par le CLOB est enregistré sur son signal avec la
+// taille et le prix réellement obtenus (makingAmount / takingAmount), pas la mise demandée
+mod common;
+
+use common::clob_mock::{filled_response, order_terms, real_bot, token_id, ClobMock};
+
+#[tokio::test]
+async fn a_partial_fill_records_the_filled_size_on_the_signal() {
+    let work_dir = common::WorkDir::new("partial-fill");
+    common::isolate_env();
+    let clob = ClobMock::start(|_, order| (200, filled_response(order, 0.4).to_string()));
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+    let report = bot.run_cycle().await;
+    let orders: Vec<_> = clob.orders().iter().map(order_terms).collect();
+
+    assert!(report.trades > 0);
+    assert_eq!(report.trades, orders.len());
+    let traded: Vec<_> = report.signals.iter().filter(|s| s.filled_price.is_some()).collect();
+    assert_eq!(traded.len(), orders.len(), "{:?}", report.signals);
+    for signal in traded {
+        let token = token_id(&signal.market_id, signal.token_side == "YES");
+        let (_, _, tokens, price) = orders.iter().find(|(t, ..)| t == token)
+            .unwrap_or_else(|| panic!("aucun ordre {} pour {}: {:?}", token, signal.market_id, orders));
+        let posted_usdc = tokens * price;
+        assert!((signal.filled_size - 0.4 * posted_usdc).abs() < 1e-4, "{} vs {}", signal.filled_size, posted_usdc);
+        assert!((signal.filled_price.unwrap() - price).abs() < 1e-4, "{:?} vs {}", signal.filled_price, price);
+    }
+}
@@ -0,0 +1,25 @@
+// Échéance des ordres passifs (--passive-deadline): un ordre papier resté au bid sans être rempli
+// traverse le spread à l'échéance si l'edge de sa vue tient toujours, sinon il est annulé
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn unfilled_paper_orders_cross_or_cancel_on_the_next_cycle() {
+    let work_dir = common::WorkDir::new("passive-deadline");
+
+    // Sans flux d'échanges, aucun ordre passif n'est rempli au premier cycle; échéance immédiate,
+    // seul fx-crypto-1 garde un edge net au-dessus de 19% au cycle suivant (19.98%, fx-economy-1: 18.07%)
+    let run = common::fixture_run(&work_dir, FIXTURE)
+        .cycles(2)
+        .args(["--queue-model", "--passive-deadline", "0"])
+        .env("PASSIVE_MIN_EDGE", "0.19")
+        .run();
+
+    // fx-crypto-1 remplacé à l'ask du cycle courant (0.36 au lieu du bid 0.34) et rempli;
+    // fx-economy-1 annulé, les vues annulées ne sont pas tradées
+    let filled: Vec<(&str, f64)> = run.signals.iter()
+        .filter_map(|s| s.filled_price.map(|price| (s.market_id.as_str(), price)))
+        .collect();
+    assert_eq!(filled, vec![("fx-crypto-1", 0.36)], "{:?}", run.signals);
+}
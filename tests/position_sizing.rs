@@ -0,0 +1,19 @@
+// Dimensionnement unique: la mise d'un signal est calculée une seule fois (sizing::size_position)
+// et reprise telle quelle par l'exécution, simulée ou réelle
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn simulated_fill_uses_the_signal_stake() {
+    let work_dir = common::WorkDir::new("sizing");
+    let signals = common::run_fixture(&work_dir, FIXTURE, &[]);
+
+    let filled: Vec<_> = signals.iter().filter(|signal| signal.filled_price.is_some()).collect();
+    assert!(!filled.is_empty(), "la fixture doit produire au moins un remplissage: {:?}", signals);
+    for signal in filled {
+        assert!(signal.stake_amount > 0.0, "mise positive: {:?}", signal);
+        assert!((signal.filled_size - signal.stake_amount).abs() < 1e-5,
+                "la simulation doit remplir la mise du signal: {} vs {} ({})", signal.filled_size, signal.stake_amount, signal.market_id);
+    }
+}
@@ -0,0 +1,33 @@
+// Contrôle de bande de prix: un marché dont le mid du livre s'écarte de la probabilité Gamma
+// au-delà de PRICE_BAND est ignoré, les autres sont tradés normalement
+mod common;
+
+use common::FIXTURE;
+use polymarket_bot::{Bot, Fixture};
+use std::path::Path;
+
+#[tokio::test]
+async fn a_market_whose_book_mid_is_far_from_its_reference_price_is_skipped() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let work_dir = common::WorkDir::new("price-band");
+    common::isolate_env();
+    let fixture = Fixture::load(manifest_dir.join(FIXTURE).to_str().unwrap()).expect("fixture");
+    let signalled = |report: &polymarket_bot::CycleReport, market_id: &str| report.signals.iter().any(|s| s.market_id == market_id);
+
+    // Livres alignés sur la référence: fx-economy-1 (0.58) produit un signal
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(fixture.clone()).seed(42).build().expect("configuration");
+    let aligned = bot.run_cycle().await;
+    assert!(signalled(&aligned, "fx-economy-1"), "{:?}", aligned.signals);
+
+    // Livre de fx-economy-1 autour de 0.85, à 0.27 de la référence: hors de la bande de 0.15
+    let mut diverging = fixture;
+    let book = diverging.orderbooks.get_mut("fx-economy-1").expect("livre du marché");
+    book.bids = vec![(0.84, 60.0), (0.83, 140.0)];
+    book.asks = vec![(0.86, 50.0), (0.87, 160.0)];
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(diverging).seed(42).build().expect("configuration");
+    let skipped = bot.run_cycle().await;
+
+    assert!(!signalled(&skipped, "fx-economy-1"), "{:?}", skipped.signals);
+    // Les marchés dont le livre reste dans la bande ne sont pas touchés
+    assert!(signalled(&skipped, "fx-politics-1"), "{:?}", skipped.signals);
+}
@@ -0,0 +1,35 @@
+// Prix Gamma en fraction (0-1) ou en pourcentage (0-100): ramenés à [0, 1] à la lecture,
+// un même marché coté des deux façons donne le même prix interne
+mod common;
+
+use common::Signal;
+use std::path::Path;
+
+fn load_json(relative: &str) -> serde_json::Value {
+    serde_json::from_str(&std::fs::read_to_string(common::manifest_path(relative)).expect("lecture de la fixture")).expect("fixture JSON")
+}
+
+fn run_cycle(work_dir: &Path, name: &str, fixture: &serde_json::Value) -> Vec<Signal> {
+    let fixture_path = work_dir.join(format!("{}.json", name));
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+    common::run_fixture(work_dir, &fixture_path, &[])
+}
+
+#[test]
+fn a_fixture_quoted_in_percent_yields_the_same_signals() {
+    let work_dir = common::WorkDir::new("price-scale");
+    let fraction = load_json("tests/fixtures/simulation_cycle.json");
+    // Deux marchés cotés en pourcentage, le troisième reste en fraction
+    let mut percent = fraction.clone();
+    percent["markets"][0]["probability"] = serde_json::json!(42);
+    percent["markets"][1]["probability"] = serde_json::json!(35);
+    let fraction_signals = run_cycle(&work_dir, "fraction", &fraction);
+    let percent_signals = run_cycle(&work_dir, "percent", &percent);
+
+    // Mêmes signaux et mêmes prix de référence (0.42, pas 42), aux horodatages et latences mesurées près
+    let comparable = |signals: &[Signal]| -> Vec<(String, String, f64)> {
+        signals.iter().map(|s| (s.market_id.clone(), s.action.clone(), s.reference_price)).collect()
+    };
+    assert!(!comparable(&fraction_signals).is_empty());
+    assert_eq!(comparable(&percent_signals), comparable(&fraction_signals));
+}
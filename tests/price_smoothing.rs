@@ -0,0 +1,15 @@
+// Lissage EMA du prix comparé à la fair value: un prix stable lissé reste ce prix,
+// les décisions du cycle sont celles du prix brut (amortissement testé dans src/smoothing.rs)
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn a_steady_price_smoothed_decides_like_the_raw_price() {
+    let work_dir = common::WorkDir::new("price-smoothing");
+    let raw = common::fixture_run(&work_dir, FIXTURE).cycles(2).run();
+    let smoothed = common::fixture_run(&work_dir, FIXTURE).cycles(2).env("PRICE_EMA_ALPHA", "0.2").run();
+
+    assert!(!smoothed.signals.is_empty());
+    assert_eq!(smoothed.signals, raw.signals);
+}
@@ -0,0 +1,36 @@
+// Modèle de file d'attente (--queue-model): un ordre passif n'est rempli qu'une fois consommée
+// la taille affichée devant lui, un marché actif remplit donc plus souvent qu'un marché figé
+mod common;
+
+use common::FIXTURE;
+
+const FAST_MARKET: &str = "fx-crypto-1";
+
+const STAGNANT_MARKET: &str = "fx-economy-1";
+
+#[test]
+fn queue_model_fills_only_where_trades_flow() {
+    let work_dir = common::WorkDir::new("queue");
+
+    // Fixture du cycle de simulation, avec un flux d'échanges soutenu sur un marché et nul sur l'autre
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    fixture["trade_flow"] = serde_json::json!({ FAST_MARKET: 1_000_000.0, STAGNANT_MARKET: 0.0 });
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    let signals = common::run_fixture(&work_dir, &fixture_path, &["--queue-model"]);
+    let signal = |market: &str| signals.iter().find(|signal| signal.market_id == market)
+        .unwrap_or_else(|| panic!("aucun signal pour {}: {:?}", market, signals));
+
+    // Marché actif: ordre rempli en entier au meilleur bid, pas à l'ask
+    let fast = signal(FAST_MARKET);
+    assert_eq!(fast.filled_price, Some(0.34), "remplissage passif au bid: {:?}", fast);
+    assert!((fast.filled_size - fast.stake_amount).abs() < 1e-5);
+
+    // Marché figé: l'ordre reste dans la file sans être rempli
+    let stagnant = signal(STAGNANT_MARKET);
+    assert!(stagnant.filled_price.is_none(), "aucun remplissage sans flux: {:?}", stagnant);
+    assert_eq!(stagnant.filled_size, 0.0);
+}
@@ -0,0 +1,48 @@
+// Mode réel: le capital est le solde USDC du compte, lu sur le CLOB (GET /balance-allowance,
+// en-têtes L2) avant la mise des signaux; sans solde lisible, aucun ordre n'est posté
+mod common;
+
+use common::clob_mock::{balance_response, filled_response, order_terms, real_bot, ClobMock};
+use std::sync::{Arc, Mutex};
+
+// CLOB qui remplit chaque ordre et répond `balance` aux GET, chemins des GET enregistrés
+fn clob_with_balance(balance: (u16, String)) -> (ClobMock, Arc<Mutex<Vec<String>>>) {
+    let paths = Arc::new(Mutex::new(Vec::new()));
+    let seen = paths.clone();
+    let clob = ClobMock::start_with_get(|_, order| (200, filled_response(order, 1.0).to_string()), move |path| {
+        seen.lock().unwrap().push(path.to_string());
+        balance.clone()
+    });
+    (clob, paths)
+}
+
+#[tokio::test]
+async fn stakes_are_sized_on_the_usdc_balance_of_the_account() {
+    let work_dir = common::WorkDir::new("real-balance");
+    common::isolate_env();
+    let (clob, paths) = clob_with_balance((200, balance_response(40.0)));
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+
+    let report = bot.run_cycle().await;
+    assert!(paths.lock().unwrap().iter().any(|path| path == "/balance-allowance?asset_type=COLLATERAL"), "{:?}", paths.lock().unwrap());
+
+    // Ordres postés dans la limite des 40 USDC du compte, solde débité des mises remplies
+    let spent: f64 = clob.orders().iter().map(order_terms).map(|(_, _, tokens, price)| tokens * price).sum();
+    assert!(report.trades > 0, "{:?}", report.signals);
+    assert!(spent > 0.0 && spent <= 40.0 + 1e-6, "{} USDC postés", spent);
+    assert!(report.balance < 40.0 && report.balance >= 0.0, "{:?}", report.balance);
+}
+
+#[tokio::test]
+async fn an_unreadable_balance_posts_no_order() {
+    let work_dir = common::WorkDir::new("real-balance-unreadable");
+    common::isolate_env();
+    let (clob, _) = clob_with_balance((401, r#"{"error":"Unauthorized/Invalid api key"}"#.to_string()));
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+
+    let report = bot.run_cycle().await;
+    assert_eq!(report.trades, 0, "{:?}", report.signals);
+    assert!(clob.orders().is_empty());
+    assert_eq!(report.balance, 0.0);
+    assert!(report.signals.iter().all(|signal| signal.action == "MONITOR"), "{:?}", report.signals);
+}
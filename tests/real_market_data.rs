@@ -0,0 +1,52 @@
+// Mode réel: un marché dont aucun livre CLOB n'est récupérable (ni mouvement calculable) est
+// ignoré, sans signal ni ordre, au lieu d'être tradé sur un livre de repli fabriqué.
+// Fixture signée sans les livres de fx-crypto-1; les autres marchés gardent les leurs
+mod common;
+
+use common::clob_mock::{credentials, order_terms, token_id, write_signed_fixture, ClobMock, PRIVATE_KEY};
+use polymarket_bot::{Bot, Fixture};
+
+#[tokio::test]
+async fn a_market_without_a_book_produces_no_real_mode_signal() {
+    let work_dir = common::WorkDir::new("real-market-data");
+    common::isolate_env();
+    let path = write_signed_fixture(&work_dir);
+    let mut fixture: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let books = fixture["orderbooks"].as_object_mut().unwrap();
+    books.remove(token_id("fx-crypto-1", true));
+    books.remove(token_id("fx-crypto-1", false));
+    std::fs::write(&path, fixture.to_string()).unwrap();
+
+    let clob = ClobMock::filling();
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(Fixture::load(path.to_str().unwrap()).expect("fixture")).seed(42)
+        .real_mode(true).clob_endpoint(clob.url())
+        .private_key(PRIVATE_KEY).api_credentials(credentials())
+        .build().expect("configuration");
+    let report = bot.run_cycle().await;
+    let orders: Vec<_> = clob.orders().iter().map(order_terms).collect();
+
+    assert!(report.signals.iter().all(|s| s.market_id != "fx-crypto-1"), "{:?}", report.signals);
+    assert!(orders.iter().all(|(token, ..)| token != token_id("fx-crypto-1", true) && token != token_id("fx-crypto-1", false)),
+            "{:?}", orders);
+    // Les marchés qui ont un livre sont toujours tradés
+    assert!(report.signals.iter().any(|s| s.market_id == "fx-economy-1"), "{:?}", report.signals);
+    assert_eq!(report.trades, orders.len());
+}
+
+#[tokio::test]
+async fn an_empty_market_list_skips_the_real_cycle_instead_of_simulating_markets() {
+    let work_dir = common::WorkDir::new("real-no-markets");
+    common::isolate_env();
+    let gamma = ClobMock::start_with_get(|_, _| (404, String::new()), |_| (200, "[]".to_string()));
+    let clob = ClobMock::filling();
+    let mut bot = Bot::builder().work_dir(&work_dir).real_mode(true)
+        .markets_endpoint(&format!("{}/markets", gamma.url())).clob_endpoint(clob.url())
+        .private_key(PRIVATE_KEY).api_credentials(credentials())
+        .build().expect("configuration");
+    let report = bot.run_cycle().await;
+
+    assert!(bot.markets().is_empty(), "marchés simulés en mode réel: {:?}", bot.markets().iter().map(|m| &m.id).collect::<Vec<_>>());
+    assert!(!report.success);
+    assert!(report.errors.iter().any(|e| e == "no markets fetched this cycle"), "{:?}", report.errors);
+    assert!(report.signals.is_empty() && clob.orders().is_empty());
+}
@@ -0,0 +1,42 @@
+// Garde-fou du mode réel: sans --mode real ET POLYBOT_CONFIRM_REAL=yes (ou la phrase
+// de confirmation tapée), le bot s'arrête avant toute récupération de marché ou tout ordre
+mod common;
+
+use common::RunOutput;
+
+fn run(args: &[&str], stdin: &str, tag: &str) -> RunOutput {
+    let work_dir = common::WorkDir::new(&format!("real-guard-{}", tag));
+    common::bot_run(&work_dir).args(args).stdin(stdin).output()
+}
+
+// Refus avant le premier cycle: code de sortie non nul, ni rapport ni signaux
+fn assert_aborted_before_trading(output: &RunOutput) {
+    assert!(!output.status.success(), "le mode réel non confirmé doit échouer:\n{}", output.stdout);
+    assert!(output.report.is_none(), "aucun cycle ne doit démarrer: {:?}", output.report);
+    assert!(output.signals.is_empty(), "aucun signal ne doit être produit: {:?}", output.signals);
+}
+
+#[test]
+fn real_mode_without_confirmation_aborts() {
+    assert_aborted_before_trading(&run(&["--mode", "real"], "", "no-env"));
+}
+
+#[test]
+fn wrong_confirmation_phrase_aborts() {
+    assert_aborted_before_trading(&run(&["--mode", "real"], "yes\n", "wrong-phrase"));
+}
+
+#[test]
+fn interactive_choice_alone_cannot_enable_real_mode() {
+    // stdin en tube n'est pas un terminal: l'invite n'est pas lue, simulation par défaut. Le cycle
+    // peut échouer faute de réseau, le rapport est écrit quand même
+    let output = run(&["--max-analyze", "1"], "2\n", "prompt");
+    assert_eq!(output.report().mode, "simulation");
+}
+
+#[test]
+fn the_confirmation_phrase_is_not_read_from_a_non_terminal_stdin() {
+    // Sous nohup ou docker, la phrase n'est jamais lue (et la lecture ne peut pas bloquer):
+    // même exacte, elle ne confirme pas le mode réel
+    assert_aborted_before_trading(&run(&["--mode", "real"], "I UNDERSTAND THIS TRADES REAL MONEY\n", "piped-phrase"));
+}
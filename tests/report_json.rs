@@ -0,0 +1,67 @@
+// --once --report-json: un cycle, un rapport JSON complet, et un code de sortie
+// qui reflète la réussite du cycle (marchés récupérés, au moins une source joignable)
+mod common;
+
+const TOP_LEVEL_KEYS: &[&str] = &[
+    "success", "mode", "markets_analyzed", "sources_reachable", "opportunities",
+    "signals", "trades", "balance", "expected_pnl", "timings_ms", "errors",
+];
+
+// (succès du processus, rapport tel qu'écrit, pour vérifier le schéma JSON lui-même)
+fn run_report(fixture: &std::path::Path, tag: &str) -> (bool, serde_json::Value) {
+    let work_dir = common::WorkDir::new(&format!("report-{}", tag));
+    let output = common::fixture_run(&work_dir, fixture).output();
+    let report: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::report_path(&work_dir)).unwrap_or_else(|_| panic!("rapport absent:\n{}", output.stdout)),
+    ).expect("rapport JSON");
+    (output.status.success(), report)
+}
+
+#[test]
+fn report_contains_all_top_level_keys_after_a_simulated_cycle() {
+    let (exited_ok, report) = run_report(std::path::Path::new(common::FIXTURE), "ok");
+
+    for key in TOP_LEVEL_KEYS {
+        assert!(report.get(key).is_some(), "clé '{}' absente du rapport: {}", key, report);
+    }
+    for key in ["fetch_ms", "detection_ms", "signals_ms", "execution_ms", "total_ms"] {
+        assert!(report["timings_ms"][key].is_number(), "durée '{}' absente: {}", key, report["timings_ms"]);
+    }
+
+    assert!(exited_ok, "cycle complet: code de sortie 0 attendu");
+    assert_eq!(report["success"], true);
+    assert_eq!(report["mode"], "simulation");
+    assert_eq!(report["errors"], serde_json::json!([]));
+    assert!(report["markets_analyzed"].as_u64() > Some(0));
+
+    let signals = report["signals"].as_array().expect("signaux");
+    assert!(!signals.is_empty(), "la fixture produit des signaux");
+    for signal in signals {
+        for key in ["market_id", "action", "expected_roi", "stake_amount"] {
+            assert!(signal.get(key).is_some(), "champ '{}' absent du signal: {}", key, signal);
+        }
+    }
+}
+
+#[test]
+fn failed_cycle_is_reported_and_exits_non_zero() {
+    // Aucune source dans la fixture: aucune source joignable
+    let work_dir = common::WorkDir::new("report-fixture");
+    let fixture = work_dir.join("no_sources.json");
+    std::fs::write(&fixture, serde_json::json!({
+        "markets": [{
+            "id": "report-fed",
+            "question": "Will the Fed cut rates at the next FOMC meeting?",
+            "domain": "economy",
+            "probability": 0.5
+        }],
+        "sources": {}
+    }).to_string()).expect("écriture de la fixture");
+
+    let (exited_ok, report) = run_report(&fixture, "failed");
+
+    assert!(!exited_ok, "cycle incomplet: code de sortie non nul attendu");
+    assert_eq!(report["success"], false);
+    assert_eq!(report["sources_reachable"], 0);
+    assert!(report["errors"].as_array().is_some_and(|errors| !errors.is_empty()), "{}", report);
+}
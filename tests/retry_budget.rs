@@ -0,0 +1,57 @@
+// Budget de réessais du cycle: toutes les requêtes en échec puisent dans un même budget,
+// les sources les plus prometteuses sont réessayées d'abord, le reste est accepté en échec
+mod common;
+
+const SEC_SOURCE: &str = "https://www.sec.gov/news/pressreleases.rss";
+
+// Seule la source SEC (oracle du marché) est servie, après un échec transitoire: toutes les
+// autres sources échouent à chaque tentative et réclameraient bien plus que le budget
+fn fixture() -> serde_json::Value {
+    serde_json::json!({
+        "markets": [{
+            "id": "retry-eth-etf",
+            "question": "Will a spot Ethereum ETF be approved by the SEC?",
+            "description": "Resolves YES on an SEC approval order published on sec.gov",
+            "domain": "crypto",
+            "probability": 0.35,
+            "resolution_source": "sec.gov"
+        }],
+        "sources": {
+            SEC_SOURCE: {
+                "content_type": "application/rss+xml",
+                "body": "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><item><title>Commission holds open meeting</title></item></channel></rss>"
+            }
+        },
+        "source_failures": { SEC_SOURCE: 1 }
+    })
+}
+
+// Deux cycles avec RETRY_BUDGET=`budget`: état de la source SEC consigné par l'instantané de chaque cycle
+fn sec_status_per_cycle(budget: u32) -> Vec<String> {
+    let work_dir = common::WorkDir::new(&format!("retry-{}", budget));
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture().to_string()).expect("écriture de la fixture");
+
+    common::fixture_run(&work_dir, &fixture_path)
+        .cycles(2)
+        .args(["--snapshot-dir", "snapshots"])
+        .env("RETRY_BUDGET", budget.to_string())
+        .env("SOURCE_POLL_INTERVALS", "sec.gov=0,federalreserve.gov=0,stlouisfed.org=0,newsapi.org=0,bbci.co.uk=0,coindesk.com=0")
+        .run();
+    (1..=2).map(|cycle| {
+        let snapshot: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(work_dir.join(format!("snapshots/cycle-{}.json", cycle))).expect("instantané du cycle"),
+        ).expect("instantané JSON");
+        snapshot["sources"][SEC_SOURCE].as_str().unwrap_or_default().to_string()
+    }).collect()
+}
+
+#[test]
+fn retries_across_a_cycle_stay_within_the_budget() {
+    // Un seul réessai par cycle, alors que six sources échouent: il revient à l'oracle du marché,
+    // qui réussit à sa deuxième tentative; le budget est remis à zéro au cycle suivant
+    assert_eq!(sec_status_per_cycle(1), ["success", "success"]);
+
+    // Budget nul: l'échec transitoire est accepté tel quel
+    assert_eq!(sec_status_per_cycle(0), ["error", "error"]);
+}
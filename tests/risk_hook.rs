@@ -0,0 +1,110 @@
+// Contrôle de risque fourni par l'utilisateur: chaque trade lui est soumis avant exécution, et
+// un trade refusé n'est ni rempli ni compté, sans toucher aux trades approuvés; en mode réel
+// et en shadow-real, un trade refusé ne devient pas un ordre
+mod common;
+
+use common::clob_mock::{order_terms, real_bot, token_id, ClobMock};
+use common::FIXTURE;
+use futures::future::BoxFuture;
+use polymarket_bot::{Bot, Fixture, ProposedTrade, RiskDecision, RiskHook};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+// Refuse toute mise au-delà de max_size; garde chaque trade proposé
+struct MaxSize {
+    max_size: f64,
+    proposed: Arc<Mutex<Vec<ProposedTrade>>>,
+}
+
+impl RiskHook for MaxSize {
+    fn approve<'a>(&'a self, trade: &'a ProposedTrade) -> BoxFuture<'a, RiskDecision> {
+        Box::pin(async move {
+            self.proposed.lock().unwrap().push(trade.clone());
+            if trade.size > self.max_size {
+                RiskDecision::Veto(format!("mise {:.2} > {:.2}", trade.size, self.max_size))
+            } else {
+                RiskDecision::Approve
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn trades_vetoed_by_the_risk_hook_are_not_executed() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let work_dir = common::WorkDir::new("risk-hook");
+    common::isolate_env();
+    let fixture = Fixture::load(manifest_dir.join(FIXTURE).to_str().unwrap()).expect("fixture");
+
+    // Sans plafond: tous les trades passent, chacun proposé une fois avec sa mise et son prix
+    let proposed = Arc::new(Mutex::new(Vec::new()));
+    let hook = MaxSize { max_size: f64::INFINITY, proposed: proposed.clone() };
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(fixture.clone()).seed(42).risk_hook(hook).build().expect("configuration");
+    let approved = bot.run_cycle().await;
+    let trades = proposed.lock().unwrap().clone();
+    let filled: Vec<_> = approved.signals.iter().filter(|s| s.filled_price.is_some()).collect();
+    assert!(trades.len() >= 2 && trades.len() == filled.len(), "{:?}", trades);
+    for trade in &trades {
+        let signal = filled.iter().find(|s| s.market_id == trade.market_id && s.action == trade.action).expect("trade rempli");
+        assert_eq!(trade.size, signal.stake_amount);
+        assert!(trade.price > 0.0 && trade.price < 1.0, "{:?}", trade);
+    }
+    // Exposition cumulée au fil des trades du cycle
+    assert_eq!(trades[0].exposure, 0.0);
+    assert!((trades[1].exposure - trades[0].size).abs() < 1e-9, "{:?}", trades);
+
+    // Plafond sous la plus grosse mise: ce trade est refusé, les autres passent
+    let largest = trades.iter().map(|t| t.size).fold(0.0, f64::max);
+    let smallest = trades.iter().map(|t| t.size).fold(f64::INFINITY, f64::min);
+    assert!(smallest < largest, "{:?}", trades);
+    let hook = MaxSize { max_size: (smallest + largest) / 2.0, proposed: Arc::new(Mutex::new(Vec::new())) };
+    let mut bot = Bot::builder().work_dir(&work_dir).fixture(fixture).seed(42).risk_hook(hook).build().expect("configuration");
+    let vetoed = bot.run_cycle().await;
+    let log = std::fs::read_to_string(work_dir.join("polymarket.log")).unwrap_or_default();
+
+    for trade in &trades {
+        let signal = vetoed.signals.iter().find(|s| s.market_id == trade.market_id && s.action == trade.action).expect("signal");
+        assert_eq!(signal.filled_price.is_some(), trade.size <= (smallest + largest) / 2.0, "{:?}", signal);
+    }
+    assert!(log.contains("Trade refusé par le contrôle de risque"), "{}", log);
+}
+
+// Refuse tout trade sur un marché donné
+struct BlockMarket(&'static str);
+
+impl RiskHook for BlockMarket {
+    fn approve<'a>(&'a self, trade: &'a ProposedTrade) -> BoxFuture<'a, RiskDecision> {
+        Box::pin(async move {
+            if trade.market_id == self.0 {
+                RiskDecision::Veto(format!("{} bloqué", self.0))
+            } else {
+                RiskDecision::Approve
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn vetoed_trades_are_neither_posted_nor_shadowed() {
+    let work_dir = common::WorkDir::new("risk-hook-real");
+    common::isolate_env();
+    let blocked = token_id("fx-crypto-1", true);
+
+    // Mode réel: aucun ordre posté sur le marché refusé, les autres partent
+    let clob = ClobMock::filling();
+    let mut bot = real_bot(&work_dir, &clob).risk_hook(BlockMarket("fx-crypto-1")).build().expect("configuration");
+    let report = bot.run_cycle().await;
+    let posted: Vec<String> = clob.orders().iter().map(|order| order_terms(order).0).collect();
+    assert!(report.trades > 0 && report.trades == posted.len(), "{:?}", posted);
+    assert!(!posted.iter().any(|token| token == blocked), "{:?}", posted);
+
+    // Shadow-real: même chaîne, l'ordre refusé n'est pas non plus signé ni journalisé
+    let shadow_dir = common::WorkDir::new("risk-hook-shadow");
+    let mut bot = real_bot(&shadow_dir, &clob).shadow_real(true).risk_hook(BlockMarket("fx-crypto-1")).build().expect("configuration");
+    bot.run_cycle().await;
+    let shadowed: Vec<serde_json::Value> = std::fs::read_to_string(shadow_dir.join("shadow_orders.jsonl")).unwrap_or_default()
+        .lines().map(|line| serde_json::from_str(line).expect("ligne JSON")).collect();
+    let shadowed: Vec<String> = shadowed.iter().map(|request| order_terms(&request["body"]).0).collect();
+    assert_eq!(shadowed, posted);
+    assert_eq!(clob.orders().len(), posted.len());
+}
@@ -0,0 +1,16 @@
+// --verify-roi: le ROI du core C++ est recalculé par son équivalent Rust, un écart est signalé
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn verifying_the_roi_leaves_the_fixture_cycle_unchanged() {
+    let work_dir = common::WorkDir::new("verify-roi");
+    let plain = common::fixture_run(&work_dir, FIXTURE).run();
+    let verified = common::fixture_run(&work_dir, FIXTURE).arg("--verify-roi").run();
+
+    // Vérification en lecture seule: mêmes signaux, aucune erreur relevée
+    assert!(!verified.signals.is_empty());
+    assert_eq!(verified.signals, plain.signals);
+    assert!(verified.report().errors.is_empty(), "{:?}", verified.report().errors);
+}
@@ -0,0 +1,56 @@
+// Règlement à la résolution: une position sur un marché résolu est close à 1.0 / 0.0 par token,
+// le PnL (valeur de règlement - coût d'entrée) est crédité au solde
+mod common;
+
+const MARKET: &str = "settle-btc-100k";
+
+#[test]
+fn market_flagged_resolved_yes_closes_the_position_at_full_value() {
+    let work_dir = common::WorkDir::new("settlement");
+    let fixture = serde_json::json!({
+        "markets": [{
+            "id": "settle-other",
+            "question": "Will the Fed cut rates at the next FOMC meeting?",
+            "domain": "economy",
+            "probability": 0.5
+        }],
+        "sources": {},
+        "open_positions": [{ "market_id": MARKET, "token_side": "YES", "shares": 100.0, "entry_price": 0.40 }],
+        "market_resolutions": {
+            MARKET: { "closed": true, "umaResolutionStatus": "resolved", "outcomePrices": "[\"1\", \"0\"]" }
+        }
+    });
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    // Aucune source servie: le cycle est en échec, le règlement a lieu avant la collecte
+    common::fixture_run(&work_dir, &fixture_path).output();
+    let journal = std::fs::read_to_string(work_dir.join("trade_timing.log")).unwrap_or_default();
+
+    // 100 tokens YES à 0.40 réglés à 1.0: 100€ crédités, PnL +60€
+    assert!(journal.lines().any(|l| l.contains("SETTLED | YES | settle-btc-100k | outcome=YES") && l.contains("pnl=60.00€")), "{}", journal);
+}
+
+#[tokio::test]
+async fn each_closed_position_counts_one_realized_return() {
+    let work_dir = common::WorkDir::new("settlement-returns");
+    common::isolate_env();
+    // Cycle de la fixture (nouveaux trades, marchés non résolus) plus une position sur un marché résolu YES
+    let mut fixture: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(common::manifest_path(common::FIXTURE)).unwrap()).unwrap();
+    fixture["open_positions"] = serde_json::json!([{ "market_id": MARKET, "token_side": "YES", "shares": 100.0, "entry_price": 0.40 }]);
+    fixture["market_resolutions"] = serde_json::json!({
+        MARKET: { "closed": true, "umaResolutionStatus": "resolved", "outcomePrices": "[\"1\", \"0\"]" }
+    });
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+    let fixture = polymarket_bot::Fixture::load(fixture_path.to_str().unwrap()).expect("fixture");
+
+    let mut bot = polymarket_bot::Bot::builder().work_dir(&work_dir).fixture(fixture).seed(42).build().expect("configuration");
+    let report = bot.run_cycle().await;
+    assert!(report.trades > 0);
+
+    // Trades du cycle encore ouverts: aucun rendement; la position réglée: PnL 60 / coût 40
+    let stats = bot.backtest_stats();
+    assert_eq!(stats.returns.len(), 1, "{:?}", stats.returns);
+    assert!((stats.returns[0] - 1.5).abs() < 1e-9, "{:?}", stats.returns);
+}
@@ -0,0 +1,82 @@
+// --shadow-real: la chaîne du mode réel produit l'ordre signé (EIP-712) et la requête authentifiée
+// complète, journalisée dans shadow_orders.jsonl au lieu d'être postée au CLOB; les garde-fous
+// d'avant l'envoi s'appliquent (fx-economy-1: 50 tokens au meilleur ask, comme en réel)
+mod common;
+
+use alloy_primitives::{Address, B256};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use common::clob_mock::{token_id, write_signed_fixture, ADDRESS, API_SECRET, PRIVATE_KEY};
+use polymarket_bot::{ClobOrder, ClobSide, SignatureType};
+
+// Adresse qui a signé `digest`, d'après une signature r ‖ s ‖ v en hexadécimal
+fn recover(digest: B256, signature: &str) -> Address {
+    let raw = signature.strip_prefix("0x").expect("signature préfixée 0x");
+    assert_eq!(raw.len(), 130, "65 octets attendus: {}", signature);
+    let bytes: Vec<u8> = (0..raw.len()).step_by(2).map(|i| u8::from_str_radix(&raw[i..i + 2], 16).unwrap()).collect();
+    assert!(bytes[64] == 27 || bytes[64] == 28, "v = {}", bytes[64]);
+    let signature = Signature::from_slice(&bytes[..64]).expect("r ‖ s");
+    let recovery_id = RecoveryId::from_byte(bytes[64] - 27).unwrap();
+    let key = VerifyingKey::recover_from_prehash(digest.as_slice(), &signature, recovery_id).expect("récupération");
+    Address::from_raw_public_key(&key.to_encoded_point(false).as_bytes()[1..])
+}
+
+#[test]
+fn shadow_real_logs_signed_requests_without_posting() {
+    let work_dir = common::WorkDir::new("shadow-real");
+
+    let run = common::fixture_run(&work_dir, write_signed_fixture(&work_dir))
+        .arg("--shadow-real")
+        .env("PRIVATE_KEY", PRIVATE_KEY)
+        .env("WALLET_ADDRESS", ADDRESS)
+        .env("RPC_URL", "http://127.0.0.1:9")
+        .env("POLY_API_KEY", "api-key")
+        .env("POLY_API_SECRET", API_SECRET)
+        .env("POLY_PASSPHRASE", "pass")
+        .env_remove("POLYBOT_CONFIRM_REAL")
+        .run();
+    let shadow_orders = std::fs::read_to_string(work_dir.join("shadow_orders.jsonl")).unwrap_or_default();
+
+    // Aucune confirmation du mode réel demandée (le cycle a tourné), aucun trade exécuté
+    assert_eq!(run.report().mode, "shadow-real");
+    assert_eq!(run.report().trades, 0, "{:?}", run.report());
+
+    // Une requête complète par signal BUY: URL, en-têtes L2, corps signé par la clé configurée
+    let requests: Vec<serde_json::Value> = shadow_orders.lines().map(|line| serde_json::from_str(line).expect("ligne JSON")).collect();
+    assert_eq!(requests.len(), run.signals.iter().filter(|s| s.action == "BUY").count(), "{}", shadow_orders);
+    assert!(requests.iter().any(|r| r["body"]["order"]["tokenId"] == token_id("fx-economy-1", true)), "{}", shadow_orders);
+    for request in &requests {
+        assert_eq!(request["method"], "POST");
+        assert_eq!(request["url"], "https://clob.polymarket.com/order");
+        assert_eq!(request["headers"]["POLY_ADDRESS"], ADDRESS);
+        assert_eq!(request["headers"]["POLY_API_KEY"], "api-key");
+        assert_eq!(request["headers"]["POLY_PASSPHRASE"], "<redacted>");
+        assert!(request["headers"]["POLY_SIGNATURE"].as_str().is_some_and(|s| !s.is_empty()), "{}", request);
+
+        let order = &request["body"]["order"];
+        assert_eq!(request["body"]["owner"], "api-key");
+        assert_eq!(order["maker"], ADDRESS);
+        assert_eq!(order["signer"], ADDRESS);
+        assert!(["fx-politics-1", "fx-crypto-1", "fx-economy-1"].iter().any(|market| [token_id(market, true), token_id(market, false)].contains(&order["tokenId"].as_str().unwrap())), "{}", order);
+        let amount = |field: &str| order[field].as_str().unwrap().parse::<u64>().unwrap();
+        let rebuilt = ClobOrder {
+            salt: order["salt"].as_u64().unwrap(),
+            maker: ADDRESS.parse().unwrap(),
+            signer: ADDRESS.parse().unwrap(),
+            taker: Address::ZERO,
+            token_id: order["tokenId"].as_str().unwrap().parse().unwrap(),
+            maker_amount: amount("makerAmount"),
+            taker_amount: amount("takerAmount"),
+            expiration: amount("expiration"),
+            nonce: amount("nonce"),
+            fee_rate_bps: amount("feeRateBps"),
+            side: if order["side"] == "BUY" { ClobSide::Buy } else { ClobSide::Sell },
+            signature_type: SignatureType::Eoa,
+            neg_risk: false,
+        };
+        assert!(rebuilt.maker_amount > 0 && rebuilt.taker_amount > 0, "{}", order);
+        if order["tokenId"] == token_id("fx-economy-1", true) {
+            assert_eq!((rebuilt.maker_amount, rebuilt.taker_amount), (29_500_000, 50_000_000), "{}", order);
+        }
+        assert_eq!(recover(rebuilt.signing_digest(), order["signature"].as_str().unwrap()).to_checksum(None), ADDRESS);
+    }
+}
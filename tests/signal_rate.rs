@@ -0,0 +1,24 @@
+// Garde-fou de débit de signaux (MAX_SIGNALS_PER_MINUTE): au-delà du plafond, moteur suspendu
+mod common;
+
+use common::FIXTURE;
+
+#[test]
+fn a_runaway_cycle_forces_monitor_and_fails_the_report() {
+    let work_dir = common::WorkDir::new("signal-rate");
+
+    // La fixture produit 4 signaux par cycle: un plafond de 2 est dépassé au troisième,
+    // qui passe en MONITOR comme tous les suivants
+    let run = common::fixture_run(&work_dir, FIXTURE).env("MAX_SIGNALS_PER_MINUTE", "2").output();
+    let report = run.report();
+
+    assert!(!run.status.success(), "halte signalée par le code de sortie:\n{}", run.stdout);
+    // Ordre de décision: fx-politics-1 (x2), puis fx-crypto-1 et fx-economy-1 après la halte
+    let actions = |market_id: &str| -> Vec<&str> { run.signals_for(market_id).iter().map(|s| s.action.as_str()).collect() };
+    assert_eq!(actions("fx-politics-1"), vec!["MONITOR", "BUY"], "{:?}", run.signals);
+    assert_eq!(actions("fx-crypto-1"), vec!["MONITOR"], "{:?}", run.signals);
+    assert_eq!(actions("fx-economy-1"), vec!["MONITOR"], "{:?}", run.signals);
+    let halts: Vec<_> = report.errors.iter().filter(|e| e.starts_with("signal rate halt")).collect();
+    assert_eq!(halts.len(), 1, "{:?}", report.errors);
+    assert!(!report.success);
+}
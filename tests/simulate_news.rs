@@ -0,0 +1,65 @@
+// --simulate-news: un titre synthétique injecté dans une source au cycle indiqué
+// parcourt tout le pipeline détection → signal → trade, de façon déterministe
+mod common;
+
+const MARKET: &str = "news-eth-etf";
+const HEADLINE: &str = "SEC approves ETF";
+
+// Source SEC joignable mais sans annonce: seule l'actualité injectée porte l'information
+fn fixture() -> serde_json::Value {
+    serde_json::json!({
+        "markets": [{
+            "id": MARKET,
+            "question": "Will a spot Ethereum ETF be approved by the SEC?",
+            "description": "Resolves YES on an SEC approval order published on sec.gov",
+            "domain": "crypto",
+            "probability": 0.35,
+            "resolution_source": "sec.gov",
+            "clob_token_ids": ["news-eth-yes", "news-eth-no"]
+        }],
+        "sources": {
+            "https://www.sec.gov/news/pressreleases.rss": {
+                "content_type": "application/rss+xml",
+                "body": "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>Press Releases</title><item><title>Commission holds open meeting</title><description>Agenda published.</description></item></channel></rss>"
+            }
+        },
+        "orderbooks": {
+            "news-eth-yes": { "bids": [[0.34, 200.0]], "asks": [[0.36, 200.0], [0.37, 400.0]] },
+            "news-eth-no": { "bids": [[0.63, 200.0]], "asks": [[0.65, 200.0]] }
+        }
+    })
+}
+
+fn run(name: &str, cycles: u64) -> Vec<common::Signal> {
+    let work_dir = common::WorkDir::new(&format!("news-{}", name));
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture().to_string()).expect("écriture de la fixture");
+
+    common::fixture_run(&work_dir, &fixture_path)
+        .cycles(cycles)
+        .args(["--news-max-age", "0"]) // titre daté de janvier: pas de fenêtre de fraîcheur
+        .arg("--simulate-news").arg(common::manifest_path("tests/fixtures/simulated_news.jsonl"))
+        .run()
+        .signals
+}
+
+fn buys(signals: &[common::Signal]) -> Vec<&common::Signal> {
+    signals.iter().filter(|s| s.market_id == MARKET && s.action == "BUY").collect()
+}
+
+#[test]
+fn injected_headline_at_cycle_two_produces_a_buy() {
+    let signals = run("cycle-2", 2);
+    let buys = buys(&signals);
+    assert_eq!(buys.len(), 1, "{:#?}", signals);
+    assert_eq!(buys[0].source, "https://www.sec.gov/news/pressreleases.rss", "{:?}", buys[0]);
+    assert_eq!(buys[0].token_side, "YES", "{:?}", buys[0]);
+    assert_eq!(buys[0].trigger_title.as_deref(), Some(HEADLINE), "{:?}", buys[0]);
+    assert_eq!(buys[0].trigger_published_at.as_deref(), Some("2026-01-10T14:00:00Z"), "{:?}", buys[0]);
+}
+
+#[test]
+fn nothing_is_injected_before_its_cycle() {
+    let signals = run("cycle-1", 1);
+    assert!(buys(&signals).is_empty(), "{:#?}", signals);
+}
@@ -0,0 +1,50 @@
+// Raison de l'absence d'opportunité par marché: décompte de la phase 3 repris dans --report-json
+mod common;
+
+use common::FIXTURE;
+use std::collections::BTreeMap;
+
+#[test]
+fn markets_skipped_for_different_reasons_are_tallied_in_the_report() {
+    let work_dir = common::WorkDir::new("skip-reasons");
+
+    let mut fixture: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(common::manifest_path(FIXTURE)).expect("lecture de la fixture"),
+    ).expect("fixture JSON");
+    // Sources crypto (et Gamma, commune à tous les domaines) injoignables
+    fixture["sources"].as_object_mut().unwrap()
+        .retain(|url, _| !["sec.gov", "coindesk.com", "gamma-api"].iter().any(|host| url.contains(host)));
+    let markets = fixture["markets"].as_array_mut().unwrap();
+    // Aucun domaine de sources pour le sport
+    markets.push(serde_json::json!({
+        "id": "fx-sports-1", "question": "Will the home team win the final?", "description": "",
+        "domain": "sports", "probability": 0.5, "resolution_source": "", "clob_token_ids": []
+    }));
+    // Carnet trop mince pour MIN_MARKET_LIQUIDITY
+    markets.push(serde_json::json!({
+        "id": "fx-thin-1", "question": "Will the senate pass the bill?", "description": "",
+        "domain": "politics", "probability": 0.3, "resolution_source": "", "clob_token_ids": [], "liquidity": 50.0
+    }));
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    // Seuil de pertinence inatteignable: les marchés dont les sources ont répondu restent sous le seuil
+    let run = common::fixture_run(&work_dir, &fixture_path)
+        .env("MIN_RELEVANCE_SCORE", "0.99")
+        .env("MIN_MARKET_LIQUIDITY", "1000")
+        .output();
+    let report = run.report();
+
+    assert_eq!(report.opportunities, 0, "{:?}", report);
+    let reasons = |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<BTreeMap<_, _>>();
+    assert_eq!(report.skipped.markets, reasons(&[
+        ("fx-politics-1", "below_threshold"),
+        ("fx-economy-1", "below_threshold"),
+        ("fx-crypto-1", "source_unavailable"),
+        ("fx-sports-1", "no_relevant_source"),
+        ("fx-thin-1", "low_liquidity"),
+    ]));
+    let counts: BTreeMap<String, usize> = [("below_threshold", 2), ("source_unavailable", 1), ("no_relevant_source", 1), ("low_liquidity", 1)]
+        .into_iter().map(|(reason, count)| (reason.to_string(), count)).collect();
+    assert_eq!(report.skipped.counts, counts);
+}
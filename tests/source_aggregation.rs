@@ -0,0 +1,38 @@
+// --aggregate-sources: les opportunités d'un marché se combinent en une décision nette,
+// au plus un signal par marché, dans le sens de la majorité pondérée
+mod common;
+
+use common::FIXTURE;
+use common::Signal;
+use std::path::Path;
+
+const MARKET: &str = "fx-politics-1";
+
+fn run_cycle(work_dir: &Path, aggregate_sources: bool) -> Vec<Signal> {
+    let args: &[&str] = if aggregate_sources { &["--aggregate-sources"] } else { &[] };
+    common::run_fixture(work_dir, FIXTURE, args)
+}
+
+#[test]
+fn a_market_with_several_sources_yields_one_signal() {
+    let work_dir = common::WorkDir::new("source-aggregation");
+    let per_source = run_cycle(&work_dir, false);
+    let aggregated = run_cycle(&work_dir, true);
+
+    // Par défaut, un signal par source non neutre du marché, toutes deux côté YES
+    let sources = common::signals_for(&per_source, MARKET);
+    assert_eq!(sources.len(), 2, "{:?}", per_source);
+    assert!(sources.iter().all(|s| s.token_side == "YES"), "{:?}", sources);
+
+    // Agrégé: un seul signal, dans le sens des deux sources
+    let signals = common::signals_for(&aggregated, MARKET);
+    assert_eq!(signals.len(), 1, "{:?}", aggregated);
+    assert_eq!(signals[0].token_side, "YES");
+
+    // Au plus un signal par marché
+    let mut markets: Vec<&str> = aggregated.iter().map(|s| s.market_id.as_str()).collect();
+    let count = markets.len();
+    markets.sort_unstable();
+    markets.dedup();
+    assert_eq!(markets.len(), count, "{:?}", aggregated);
+}
@@ -0,0 +1,59 @@
+// Fraîcheur des sources dans le rapport: une source sans succès depuis plus de
+// SOURCE_STALE_SECS (1h par défaut) est signalée STALE, distincte d'une source
+// simplement en échec pendant ce cycle
+mod common;
+
+const FED_FEED: &str = "https://www.federalreserve.gov/feeds/press_all.xml";
+const FRED_API: &str = "https://api.stlouisfed.org/fred/series/observations?series_id=FEDFUNDS&api_key=";
+const GAMMA_API: &str = "https://gamma-api.polymarket.com/markets";
+
+// Sources STALE du rapport JSON d'un cycle sur le domaine economy (rapport écrit même si le
+// cycle échoue, par exemple sans marché)
+fn stale_sources(name: &str, fixture: serde_json::Value) -> Vec<String> {
+    let work_dir = common::WorkDir::new(&format!("stale-{}", name));
+    let fixture_path = work_dir.join("fixture.json");
+    std::fs::write(&fixture_path, fixture.to_string()).expect("écriture de la fixture");
+
+    common::fixture_run(&work_dir, &fixture_path)
+        .args(["--only-domain", "economy"])
+        .output()
+        .report()
+        .stale_sources
+        .clone()
+}
+
+#[test]
+fn source_without_success_within_the_threshold_is_reported_stale() {
+    let stale = stale_sources("fred", serde_json::json!({
+        "markets": [{
+            "id": "stale-fed",
+            "question": "Will the Fed cut rates at the next FOMC meeting?",
+            "domain": "economy",
+            "probability": 0.5
+        }],
+        "sources": {
+            FED_FEED: { "content_type": "application/rss+xml", "body": "<rss><channel><item><title>FOMC statement</title></item></channel></rss>" }
+        },
+        // FRED a répondu pour la dernière fois il y a 2h, Gamma n'a jamais répondu
+        "source_last_success_secs_ago": { FRED_API: 7200.0 }
+    }));
+
+    // Gamma échoue ce cycle, mais la session vient de démarrer: pas encore STALE; la Fed est à jour
+    assert_eq!(stale, [FRED_API]);
+    assert!(!stale.iter().any(|url| url == GAMMA_API || url == FED_FEED), "{:?}", stale);
+}
+
+#[test]
+fn up_to_date_sources_are_not_listed() {
+    let stale = stale_sources("fresh", serde_json::json!({
+        "markets": [],
+        "sources": {
+            FED_FEED: { "body": "<rss><channel></channel></rss>" },
+            FRED_API: { "content_type": "application/json", "body": "{\"observations\": []}" },
+            GAMMA_API: { "content_type": "application/json", "body": "[]" }
+        },
+        // Un ancien succès n'importe plus dès que la source répond à nouveau
+        "source_last_success_secs_ago": { FRED_API: 7200.0 }
+    }));
+    assert!(stale.is_empty(), "{:?}", stale);
+}
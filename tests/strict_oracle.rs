@@ -0,0 +1,36 @@
+// Mode --strict-oracle: seule la source de résolution nommée par le marché déclenche un signal.
+// Fixture: un marché résolu sur sec.gov reçoit un titre Reuters (via NewsAPI),
+// un autre marché résolu sur sec.gov reçoit un communiqué de la SEC
+mod common;
+
+const FIXTURE: &str = "tests/fixtures/strict_oracle.json";
+const REUTERS_SOURCE: &str = "newsapi.org";
+const SEC_SOURCE: &str = "https://www.sec.gov/news/pressreleases.rss";
+
+// Sources (market_id, source) des signaux générés par un cycle de la fixture
+fn signal_sources(extra_args: &[&str], tag: &str) -> Vec<(String, String)> {
+    let work_dir = common::WorkDir::new(&format!("oracle-{}", tag));
+    common::run_fixture(&work_dir, FIXTURE, extra_args).into_iter()
+        .map(|signal| (signal.market_id, signal.source))
+        .collect()
+}
+
+#[test]
+fn reuters_headline_triggers_only_without_strict_oracle() {
+    let sources = signal_sources(&[], "default");
+    assert!(sources.iter().any(|(market, source)| market == "so-sec-chair" && source.contains(REUTERS_SOURCE)),
+            "sans --strict-oracle, le titre Reuters doit déclencher un signal: {:?}", sources);
+
+    let sources = signal_sources(&["--strict-oracle"], "strict");
+    assert!(!sources.iter().any(|(_, source)| source.contains(REUTERS_SOURCE)),
+            "le titre Reuters ne doit pas déclencher un marché résolu sur sec.gov: {:?}", sources);
+}
+
+#[test]
+fn sec_release_triggers_under_strict_oracle() {
+    let sources = signal_sources(&["--strict-oracle"], "sec");
+    assert!(sources.iter().any(|(market, source)| market == "so-sec-etf" && source == SEC_SOURCE),
+            "le communiqué SEC doit déclencher le marché résolu sur sec.gov: {:?}", sources);
+    assert!(sources.iter().all(|(_, source)| source == SEC_SOURCE),
+            "seule la source de résolution peut déclencher un signal: {:?}", sources);
+}
@@ -0,0 +1,26 @@
+// Profils de timeouts: le profil choisi configure le client CLOB construit par Bot::new
+mod common;
+
+use common::FIXTURE;
+
+// Ligne "[INFO] HTTP timeout profile: ..." affichée au démarrage
+fn profile_line(args: &[&str]) -> String {
+    let work_dir = common::WorkDir::new(&format!("timeout-{}", args.join("_").replace('-', "")));
+    let stdout = common::fixture_run(&work_dir, FIXTURE).args(args).run().stdout;
+    stdout.lines()
+        .find(|line| line.starts_with("[INFO] HTTP timeout profile:"))
+        .unwrap_or_else(|| panic!("profil de timeouts non affiché:\n{}", stdout))
+        .to_string()
+}
+
+#[test]
+fn balanced_is_the_default_profile() {
+    assert_eq!(profile_line(&[]), "[INFO] HTTP timeout profile: balanced (CLOB timeout 2s, connect 500ms)");
+}
+
+#[test]
+fn selected_profile_sets_client_timeouts() {
+    assert_eq!(profile_line(&["--aggressive-timeout"]), "[INFO] HTTP timeout profile: aggressive (CLOB timeout 100ms, connect 50ms)");
+    assert_eq!(profile_line(&["--safe-timeout"]), "[INFO] HTTP timeout profile: safe (CLOB timeout 10s, connect 3s)");
+    assert_eq!(profile_line(&["--timeout-profile", "balanced"]), "[INFO] HTTP timeout profile: balanced (CLOB timeout 2s, connect 500ms)");
+}
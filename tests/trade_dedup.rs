@@ -0,0 +1,54 @@
+// Mode réel: le même trade économique (marché, côté, prix arrondi) n'est soumis qu'une fois par
+// session pendant le cooldown. Le second cycle de la fixture reproduit les mêmes signaux BUY:
+// leurs ordres sont refusés avant l'envoi. Un ordre que le CLOB n'a pas accepté ne compte pas
+mod common;
+
+use common::clob_mock::{filled_response, order_terms, real_bot, ClobMock};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn identical_trades_in_one_session_are_submitted_once() {
+    let work_dir = common::WorkDir::new("trade-dedup");
+    common::isolate_env();
+    let clob = ClobMock::filling();
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+
+    let first = bot.run_cycle().await;
+    let submitted: Vec<_> = clob.orders().iter().map(order_terms).collect();
+    assert!(first.trades > 0);
+    assert_eq!(submitted.len(), first.trades);
+
+    let second = bot.run_cycle().await;
+    let repeated: Vec<_> = second.signals.iter().filter(|s| s.action == "BUY").map(|s| s.market_id.clone()).collect();
+    assert_eq!(repeated.len(), first.trades, "{:?}", repeated);
+    assert_eq!(second.trades, 0);
+    assert_eq!(clob.orders().len(), submitted.len(), "{:?}", clob.orders().iter().map(order_terms).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn a_trade_the_clob_rejected_is_submitted_again_on_the_next_cycle() {
+    let work_dir = common::WorkDir::new("trade-dedup-rejected");
+    common::isolate_env();
+    // Premier cycle: ordres refusés par le CLOB; ensuite remplis
+    let accepting = Arc::new(AtomicBool::new(false));
+    let accepts = accepting.clone();
+    let clob = ClobMock::start(move |_, order| if accepts.load(Ordering::SeqCst) {
+        (200, filled_response(order, 1.0).to_string())
+    } else {
+        (400, r#"{"error":"invalid order: order size"}"#.to_string())
+    });
+    let mut bot = real_bot(&work_dir, &clob).build().expect("configuration");
+
+    let first = bot.run_cycle().await;
+    let rejected: Vec<_> = clob.orders().iter().map(order_terms).collect();
+    assert_eq!(first.trades, 0);
+    assert!(!rejected.is_empty());
+
+    accepting.store(true, Ordering::SeqCst);
+    let second = bot.run_cycle().await;
+    assert_eq!(second.trades, rejected.len());
+    let resubmitted: Vec<_> = clob.orders().iter().skip(rejected.len()).map(order_terms).map(|(token, side, _, _)| (token, side)).collect();
+    let expected: Vec<_> = rejected.into_iter().map(|(token, side, _, _)| (token, side)).collect();
+    assert_eq!(resubmitted, expected);
+}
@@ -0,0 +1,24 @@
+// Provenance des signaux: le flux SEC contient trois communiqués, seul celui sur
+// l'ETF Ethereum correspond au marché. Le signal doit porter son titre, sa date et son lien
+mod common;
+
+const FIXTURE: &str = "tests/fixtures/trigger_item.json";
+const MARKET: &str = "ti-sec-etf";
+
+#[test]
+fn signal_carries_the_matching_item() {
+    let work_dir = common::WorkDir::new("trigger");
+
+    // Communiqués de 2024: pas de fenêtre de fraîcheur
+    let signals = common::run_fixture(&work_dir, FIXTURE, &["--news-max-age", "0"]);
+    let log = std::fs::read_to_string(work_dir.join("polymarket.log")).unwrap_or_default();
+
+    let signal = signals.iter().find(|signal| signal.market_id == MARKET)
+        .unwrap_or_else(|| panic!("aucun signal pour {}: {:?}", MARKET, signals));
+    assert_eq!(signal.trigger_title.as_deref(), Some("SEC approves spot Ethereum ETF applications"));
+    assert_eq!(signal.trigger_published_at.as_deref(), Some("Thu, 23 May 2024 20:15:00 GMT"));
+    assert_eq!(signal.trigger_link.as_deref(), Some("https://www.sec.gov/news/press-release/2024-102"));
+
+    assert!(log.lines().any(|line| line.contains("SEC approves spot Ethereum ETF applications")),
+            "élément absent du journal des signaux:\n{}", log);
+}
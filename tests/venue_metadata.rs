@@ -0,0 +1,16 @@
+// Métadonnées CLOB d'un marché (neg_risk, tick, ordre minimum, ordres acceptés):
+// une réponse /markets/{condition_id} enrichit le marché, et une mise sous le minimum
+// de la venue reste en surveillance au lieu de produire un ordre refusé
+mod common;
+
+#[test]
+fn stake_below_the_venue_minimum_is_not_traded() {
+    // Même marché que no_side_price (BUY de ~78 tokens NO), avec un minimum CLOB de 100 tokens
+    let work_dir = common::WorkDir::new("venue");
+    let signals = common::run_fixture(&work_dir, "tests/fixtures/venue_constraints.json", &[]);
+
+    let signal = signals.iter().find(|s| s.market_id == "venue-fed-raise")
+        .unwrap_or_else(|| panic!("aucun signal: {:?}", signals));
+    assert_eq!(signal.action, "MONITOR", "mise sous le minimum de la venue: {:?}", signal);
+    assert_eq!(signal.filled_size, 0.0, "{:?}", signal);
+}
@@ -0,0 +1,88 @@
+// Reprise des positions détenues à la venue au démarrage: la data-api /positions du wallet
+// alimente les positions ouvertes du bot, tailles et prix d'entrée compris
+mod common;
+
+use polymarket_bot::Bot;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+
+const WALLET: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+// Sert une seule réponse JSON sur un port local; renvoie son URL et la ligne de requête reçue
+fn serve_once(body: &'static str) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("port libre");
+    let url = format!("http://{}/positions", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("connexion");
+        let mut request = [0u8; 4096];
+        let read = stream.read(&mut request).unwrap_or(0);
+        let request_line = String::from_utf8_lossy(&request[..read]).lines().next().unwrap_or_default().to_string();
+        let _ = sender.send(request_line);
+        let head = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len());
+        let _ = stream.write_all(head.as_bytes());
+        let _ = stream.write_all(body.as_bytes());
+    });
+    (url, receiver)
+}
+
+#[tokio::test]
+async fn venue_positions_populate_the_open_positions() {
+    let work_dir = common::WorkDir::new("venue-positions");
+    common::isolate_env();
+
+    // Format de la data-api: tailles et prix en nombres ou en chaînes, issue par index ou libellé
+    let (url, request) = serve_once(r#"[
+        {"proxyWallet": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266", "asset": "1101", "conditionId": "0xcond-a",
+         "size": 120.5, "avgPrice": 0.42, "curPrice": 0.47, "outcome": "Yes", "outcomeIndex": 0, "title": "Market A"},
+        {"asset": "2202", "conditionId": "0xcond-b", "size": "35", "avgPrice": "0.61", "outcome": "No", "outcomeIndex": 1},
+        {"asset": "3301", "conditionId": "0xcond-c", "size": 0, "avgPrice": 0.5, "outcome": "Yes", "outcomeIndex": 0},
+        {"asset": "4401", "conditionId": "0xcond-d", "size": 10, "avgPrice": 1.7, "outcome": "Yes", "outcomeIndex": 0}
+    ]"#);
+
+    let mut bot = Bot::builder().work_dir(&work_dir).wallet_address(WALLET).real_mode(true).positions_endpoint(&url).build().expect("configuration");
+    assert!(bot.open_positions().is_empty());
+    let loaded = bot.load_positions_from_venue().await;
+    let request_line = request.recv().expect("requête reçue");
+
+    assert!(request_line.starts_with("GET /positions?"), "{}", request_line);
+    assert!(request_line.contains(&format!("user={}", WALLET)), "{}", request_line);
+
+    // Position vide ignorée, prix d'entrée hors [0, 1] rejeté
+    assert_eq!(loaded, Ok(2));
+    let positions = bot.open_positions();
+    assert_eq!(positions.len(), 2, "{:?}", positions);
+
+    // Aucun marché chargé: les positions sont désignées par leur condition id
+    assert_eq!(positions[0].market_id, "0xcond-a");
+    assert!(positions[0].holds_yes);
+    assert_eq!(positions[0].shares, 120.5);
+    assert_eq!(positions[0].entry_price, 0.42);
+
+    assert_eq!(positions[1].market_id, "0xcond-b");
+    assert!(!positions[1].holds_yes);
+    assert_eq!(positions[1].shares, 35.0);
+    assert_eq!(positions[1].entry_price, 0.61);
+    assert!((positions[1].cost() - 21.35).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn a_position_on_an_unknown_outcome_is_skipped_rather_than_held_as_yes() {
+    let work_dir = common::WorkDir::new("venue-positions-unknown-outcome");
+    common::isolate_env();
+
+    // Issue d'un marché à plusieurs issues: ni YES ni NO, sans index
+    let (url, _request) = serve_once(r#"[
+        {"asset": "5501", "conditionId": "0xcond-e", "size": 12, "avgPrice": 0.2, "outcome": "Other"},
+        {"asset": "6601", "conditionId": "0xcond-f", "size": 8, "avgPrice": 0.3, "outcome": "No"}
+    ]"#);
+
+    let mut bot = Bot::builder().work_dir(&work_dir).wallet_address(WALLET).real_mode(true).positions_endpoint(&url).build().expect("configuration");
+    assert_eq!(bot.load_positions_from_venue().await, Ok(1));
+
+    let positions = bot.open_positions();
+    assert_eq!(positions.len(), 1, "{:?}", positions);
+    assert_eq!(positions[0].market_id, "0xcond-f");
+    assert!(!positions[0].holds_yes);
+}
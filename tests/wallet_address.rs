@@ -0,0 +1,28 @@
+// WALLET_ADDRESS invalide (ici un checksum EIP-55 faux): le démarrage échoue au lieu de
+// continuer sans wallet, en binaire comme en bibliothèque
+mod common;
+
+use polymarket_bot::Bot;
+
+const VALID: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+// Même adresse, casse d'une lettre inversée: checksum invalide
+const BAD_CHECKSUM: &str = "0xf39fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+#[test]
+fn a_bad_checksum_stops_the_binary_before_any_cycle() {
+    let work_dir = common::WorkDir::new("wallet-address");
+    let run = common::fixture_run(&work_dir, common::FIXTURE)
+        .env("WALLET_ADDRESS", BAD_CHECKSUM)
+        .output();
+    assert!(!run.status.success(), "{}", run.stdout);
+    assert!(run.report.is_none(), "aucun cycle ne doit tourner: {:?}", run.report);
+}
+
+#[test]
+fn the_builder_rejects_a_bad_checksum() {
+    let work_dir = common::WorkDir::new("wallet-address-builder");
+    common::isolate_env();
+    let error = Bot::builder().work_dir(&work_dir).wallet_address(BAD_CHECKSUM).build().err().expect("adresse refusée");
+    assert!(error.contains("checksum"), "{}", error);
+    assert!(Bot::builder().work_dir(&work_dir).wallet_address(VALID).build().is_ok());
+}
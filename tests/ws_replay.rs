@@ -0,0 +1,113 @@
+// Rejeu d'un flux WS orderbook enregistré: snapshot puis deltas doivent reconstruire
+// exactement les livres attendus, à la cadence enregistrée ou sans attente.
+// --record-ws écrit les trames d'un canal market dans ce même format
+mod common;
+
+use futures::{SinkExt, StreamExt};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tokio_tungstenite::tungstenite::Message;
+
+const RECORDING: &str = "tests/fixtures/ws_recording.jsonl";
+const RECORDING_SPAN: Duration = Duration::from_millis(200);
+
+// Livres affichés par le rejeu: asset id -> (bids, asks)
+type Books = HashMap<String, (Vec<(f64, f64)>, Vec<(f64, f64)>)>;
+
+fn replay(speed: &str) -> Books {
+    replay_file(&common::manifest_path(RECORDING), speed)
+}
+
+fn replay_file(recording: &Path, speed: &str) -> Books {
+    let output = Command::new(env!("CARGO_BIN_EXE_polymarket-bot"))
+        .current_dir(std::env::temp_dir())
+        .arg("--replay-ws").arg(recording)
+        .arg("--replay-speed").arg(speed)
+        .stdin(Stdio::null())
+        .output()
+        .expect("lancement du rejeu");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "le rejeu a échoué: {}\n{}", output.status, stdout);
+
+    let levels = |value: &serde_json::Value| -> Vec<(f64, f64)> {
+        value.as_array().expect("niveaux").iter()
+            .map(|level| (level[0].as_f64().expect("prix"), level[1].as_f64().expect("taille")))
+            .collect()
+    };
+    stdout.lines()
+        .filter_map(|line| line.strip_prefix("[BOOK] "))
+        .map(|json| {
+            let book: serde_json::Value = serde_json::from_str(json).expect("livre JSON");
+            (book["asset_id"].as_str().expect("asset id").to_string(), (levels(&book["bids"]), levels(&book["asks"])))
+        })
+        .collect()
+}
+
+// Livres reconstruits par l'enregistrement de référence
+fn expected_books() -> Books {
+    HashMap::from([
+        // Snapshot, nouveau meilleur bid 0.49, ask 0.52 retiré, ask 0.53 réduit, bid 0.48 retiré
+        ("yes-1".to_string(), (vec![(0.49, 50.0), (0.47, 200.0)], vec![(0.53, 250.0)])),
+        // Snapshot reçu en texte brut, puis nouvel ask 0.50 devant 0.51
+        ("no-1".to_string(), (vec![(0.47, 80.0)], vec![(0.50, 40.0), (0.51, 60.0)])),
+    ])
+}
+
+#[test]
+fn snapshot_and_deltas_rebuild_the_book() {
+    assert_eq!(replay("0"), expected_books());
+}
+
+#[test]
+fn replay_follows_recorded_timing() {
+    let started = Instant::now();
+    let books = replay("1");
+    assert!(started.elapsed() >= RECORDING_SPAN, "rejeu plus rapide que l'enregistrement: {:?}", started.elapsed());
+    assert_eq!(books.len(), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn a_recorded_market_channel_replays_into_the_same_books() {
+    let work_dir = common::WorkDir::new("ws-record");
+    let capture = work_dir.join("capture.jsonl");
+
+    // Faux canal market: reçoit l'abonnement, envoie les trames de l'enregistrement de référence
+    // (JSON ou texte brut tel quel), un PONG, puis ferme le flux
+    let frames: Vec<String> = std::fs::read_to_string(common::manifest_path(RECORDING)).unwrap().lines()
+        .map(|line| match serde_json::from_str::<serde_json::Value>(line).unwrap()["msg"].clone() {
+            serde_json::Value::String(raw) => raw,
+            message => message.to_string(),
+        })
+        .collect();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.expect("port libre");
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("connexion");
+        let mut ws = tokio_tungstenite::accept_async(stream).await.expect("handshake WS");
+        let subscription = ws.next().await.expect("abonnement").expect("trame").into_text().unwrap();
+        for frame in frames.into_iter().chain(["PONG".to_string()]) {
+            ws.send(Message::Text(frame)).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        ws.close(None).await.unwrap();
+        subscription
+    });
+
+    let run = common::fixture_run(&work_dir, common::FIXTURE)
+        .unbounded()
+        .arg("--record-ws").arg(&capture)
+        .env("CLOB_WS_URL", &url);
+    let output = tokio::task::spawn_blocking(move || run.output()).await.unwrap();
+    assert!(output.status.success(), "l'enregistrement a échoué: {}\n{}", output.status, output.stdout);
+
+    // Abonnement aux tokens des marchés de la fixture
+    let subscription: serde_json::Value = serde_json::from_str(&server.await.unwrap()).unwrap();
+    assert_eq!(subscription, serde_json::json!({"assets_ids": ["fx-pol-yes", "fx-pol-no", "fx-eth-yes", "fx-eth-no"], "type": "market"}));
+
+    // Une ligne {t_ms, msg} par trame JSON, le PONG exclu; le rejeu reconstruit les mêmes livres
+    let recorded = std::fs::read_to_string(&capture).expect("enregistrement écrit");
+    assert_eq!(recorded.lines().count(), 4, "{}", recorded);
+    assert_eq!(replay_file(&capture, "0"), expected_books());
+}